@@ -32,6 +32,16 @@ describe("electron app settings persistence", () => {
       version: 1,
       sourceControl: {
         fileTreeRenderMode: "list",
+        protectedPaths: ["dist"],
+        stageCommentedFilesOnCopy: true,
+        historyPageSize: 50,
+        defaultReviewRanges: {},
+        historyDateFormat: "",
+      },
+      diffView: {
+        maxHighlightBytes: 4096,
+        maxLineLength: 100,
+        collapsedPaths: ["**/Cargo.lock"],
       },
       lsp: {
         servers: {
@@ -49,6 +59,16 @@ describe("electron app settings persistence", () => {
       version: 1,
       sourceControl: {
         fileTreeRenderMode: "list",
+        protectedPaths: ["dist"],
+        stageCommentedFilesOnCopy: true,
+        historyPageSize: 50,
+        defaultReviewRanges: {},
+        historyDateFormat: "",
+      },
+      diffView: {
+        maxHighlightBytes: 4096,
+        maxLineLength: 100,
+        collapsedPaths: ["**/Cargo.lock"],
       },
       lsp: {
         servers: {
@@ -65,6 +85,16 @@ describe("electron app settings persistence", () => {
       version: 1,
       sourceControl: {
         fileTreeRenderMode: "list",
+        protectedPaths: ["dist"],
+        stageCommentedFilesOnCopy: true,
+        historyPageSize: 50,
+        defaultReviewRanges: {},
+        historyDateFormat: "",
+      },
+      diffView: {
+        maxHighlightBytes: 4096,
+        maxLineLength: 100,
+        collapsedPaths: ["**/Cargo.lock"],
       },
       lsp: {
         servers: {
@@ -88,6 +118,21 @@ describe("electron app settings persistence", () => {
       version: 1,
       sourceControl: {
         fileTreeRenderMode: "tree",
+        protectedPaths: [],
+        stageCommentedFilesOnCopy: false,
+        historyPageSize: 200,
+        defaultReviewRanges: {},
+        historyDateFormat: "",
+      },
+      diffView: {
+        maxHighlightBytes: 1_000_000,
+        maxLineLength: 0,
+        collapsedPaths: [
+          "**/Cargo.lock",
+          "**/package-lock.json",
+          "**/pnpm-lock.yaml",
+          "**/yarn.lock",
+        ],
       },
       lsp: {
         servers: {},
@@ -18,31 +18,66 @@ import {
   submitPullRequestReviewComments,
 } from "./hostedRepos";
 import {
+  addGitignorePattern,
+  checkoutFileFromRef,
   commitStaged,
   discardAll,
+  discardUnstaged,
+  formatCommitPatch,
+  blameFile,
+  getWorkingTreeDiffForPaths,
+  previewStageAll,
+  previewUnstageAll,
+  resolveRefInfo,
+  resolveStash,
+  findStashByMessage,
+  applyStash,
+  listStashes,
+  pushStash,
+  popStash,
   discardFile,
   discardFiles,
+  diffPathsBetweenRefs,
   getBranchFileVersions,
   getBranchFiles,
   getBranches,
+  getChangedFileHashes,
   getCommitFileVersions,
   getCommitFiles,
   getCommitHistory,
+  getCommitStats,
+  getParentSummary,
+  getDefaultBranch,
   getFileVersions,
+  getReflog,
   getRepoFiles,
   getRepoFile,
   getGitSnapshot,
+  getWorkingCopySummary,
+  isPathIgnored,
+  getRecentlyModifiedFiles,
+  rewordHead,
+  amendHead,
   stageAll,
+  stageDirectory,
   stageFile,
   unstageAll,
+  unstageDirectory,
   unstageFile,
   updateIndexFileContents,
   updateWorktreeFileContents,
 } from "./git";
+import { launchDifftool } from "./difftool";
+import { getLaunchBucket } from "./launchOptions";
 import { LspSessionManager } from "./lsp/sessionManager";
-import { checkAppExists, confirm, openPath, selectFolder } from "./system";
+import { checkAppExists, confirm, openPath, revealInFileManager, selectFolder } from "./system";
 import { loadWorkspaceSession, saveWorkspaceSession } from "./workspaceSession";
 
+async function loadProtectedPaths() {
+  const settings = await loadAppSettings();
+  return settings.sourceControl.protectedPaths;
+}
+
 let lspSessionManager = new LspSessionManager({
   onDiagnostics: () => {},
   loadAppSettings,
@@ -55,9 +90,11 @@ export const desktopApi: DesktopApi = {
   loadAppSettings,
   saveAppSettings,
   getAppSettingsPath,
+  getLaunchBucket,
   confirm,
   checkAppExists,
   openPath,
+  revealInFileManager,
   listProviderConnections,
   connectProvider,
   disconnectProvider,
@@ -74,24 +111,60 @@ export const desktopApi: DesktopApi = {
   preparePullRequestCompareRefs,
   preparePullRequestWorkspace,
   getGitSnapshot,
+  getWorkingCopySummary,
+  getChangedFileHashes,
   getRepoFiles,
+  isPathIgnored,
+  getRecentlyModifiedFiles,
   getCommitHistory,
+  getCommitStats,
+  getParentSummary,
+  getReflog,
+  formatCommitPatch,
+  blameFile,
+  getWorkingTreeDiffForPaths,
+  diffPathsBetweenRefs,
+  resolveRefInfo,
+  resolveStash,
+  findStashByMessage,
+  applyStash,
+  listStashes,
+  pushStash,
+  popStash,
+  addGitignorePattern,
   getBranches,
+  getDefaultBranch,
   getBranchFiles,
   getCommitFiles,
   getCommitFileVersions,
   getFileVersions,
   getBranchFileVersions,
-  stageFile,
+  stageFile: async (repoPath, relPath) =>
+    stageFile(repoPath, relPath, await loadProtectedPaths()),
   unstageFile,
-  updateIndexFileContents,
-  updateWorktreeFileContents,
-  stageAll,
+  updateIndexFileContents: async (repoPath, relPath, contents) =>
+    updateIndexFileContents(repoPath, relPath, contents, await loadProtectedPaths()),
+  updateWorktreeFileContents: async (repoPath, relPath, contents) =>
+    updateWorktreeFileContents(repoPath, relPath, contents, await loadProtectedPaths()),
+  stageAll: async (repoPath) => stageAll(repoPath, await loadProtectedPaths()),
   unstageAll,
-  discardFile,
-  discardFiles,
-  discardAll,
+  stageDirectory: async (repoPath, dirRelPath) =>
+    stageDirectory(repoPath, dirRelPath, await loadProtectedPaths()),
+  unstageDirectory,
+  discardFile: async (repoPath, relPath, bucket) =>
+    discardFile(repoPath, relPath, bucket, await loadProtectedPaths()),
+  discardFiles: async (repoPath, files) =>
+    discardFiles(repoPath, files, await loadProtectedPaths()),
+  discardAll: async (repoPath) => discardAll(repoPath, await loadProtectedPaths()),
+  discardUnstaged: async (repoPath) => discardUnstaged(repoPath, await loadProtectedPaths()),
+  previewStageAll: async (repoPath) => previewStageAll(repoPath, await loadProtectedPaths()),
+  previewUnstageAll,
+  checkoutFileFromRef: async (repoPath, relPath, ref, stage) =>
+    checkoutFileFromRef(repoPath, relPath, ref, stage, await loadProtectedPaths()),
   commitStaged,
+  rewordHead,
+  amendHead,
+  launchDifftool,
   getRepoFile,
   syncLspDocument: (input) => lspSessionManager.syncDocument(input),
   closeLspDocument: (input) => lspSessionManager.closeDocument(input),
@@ -0,0 +1,39 @@
+import { describe, expect, test } from "vitest";
+
+import { buildDifftoolArgv, DIFFTOOL_ENV_VAR, resolveDifftoolTemplate } from "./difftool";
+
+describe("external difftool", () => {
+  test("appends the old and new paths to a plain command", () => {
+    expect(buildDifftoolArgv("meld", "/tmp/old/a.png", "/tmp/new/a.png")).toEqual([
+      "meld",
+      "/tmp/old/a.png",
+      "/tmp/new/a.png",
+    ]);
+  });
+
+  test("substitutes $LOCAL and $REMOTE placeholders", () => {
+    expect(
+      buildDifftoolArgv(
+        `"/Applications/Beyond Compare.app/bcomp" --left=$LOCAL --right=$REMOTE -ro`,
+        "/tmp/old/a.bin",
+        "/tmp/new/a.bin",
+      ),
+    ).toEqual([
+      "/Applications/Beyond Compare.app/bcomp",
+      "--left=/tmp/old/a.bin",
+      "--right=/tmp/new/a.bin",
+      "-ro",
+    ]);
+  });
+
+  test("requires a graphical tool in the variable", () => {
+    expect(() => resolveDifftoolTemplate({})).toThrow(`set ${DIFFTOOL_ENV_VAR}`);
+    expect(() => resolveDifftoolTemplate({ [DIFFTOOL_ENV_VAR]: "  " })).toThrow(
+      `set ${DIFFTOOL_ENV_VAR}`,
+    );
+    expect(() => resolveDifftoolTemplate({ [DIFFTOOL_ENV_VAR]: "/usr/bin/vimdiff -R" })).toThrow(
+      "/usr/bin/vimdiff needs a terminal",
+    );
+    expect(resolveDifftoolTemplate({ [DIFFTOOL_ENV_VAR]: "meld" })).toBe("meld");
+  });
+});
@@ -0,0 +1,88 @@
+import { spawn } from "node:child_process";
+import { promises as fs } from "node:fs";
+import os from "node:os";
+import path from "node:path";
+
+import type { Bucket } from "../src/platform/desktop/contracts";
+import { getFileVersionBuffers } from "./git";
+
+export const DIFFTOOL_ENV_VAR = "OPEN_WARDEN_DIFFTOOL";
+// The tool runs detached with no terminal attached, so these would never show a window.
+const TERMINAL_DIFFTOOLS = new Set(["vimdiff", "nvimdiff", "vim", "nvim", "vi", "diff", "delta"]);
+
+function splitCommandTemplate(template: string): string[] {
+  const words: string[] = [];
+  const pattern = /"([^"]*)"|'([^']*)'|(\S+)/g;
+
+  for (const match of template.matchAll(pattern)) {
+    words.push(match[1] ?? match[2] ?? match[3] ?? "");
+  }
+
+  return words;
+}
+
+// Templates may place the files with `$LOCAL` and `$REMOTE`, as in git's difftool.cmd. Without
+// either placeholder the old and new paths are appended in that order.
+export function buildDifftoolArgv(template: string, oldPath: string, newPath: string): string[] {
+  const words = splitCommandTemplate(template.trim());
+  const hasPlaceholder = words.some((word) => word.includes("$LOCAL") || word.includes("$REMOTE"));
+
+  if (!hasPlaceholder) {
+    return [...words, oldPath, newPath];
+  }
+
+  return words.map((word) => word.replaceAll("$LOCAL", oldPath).replaceAll("$REMOTE", newPath));
+}
+
+export function resolveDifftoolTemplate(env: NodeJS.ProcessEnv = process.env) {
+  const template = env[DIFFTOOL_ENV_VAR]?.trim() ?? "";
+  if (!template) {
+    throw new Error(`set ${DIFFTOOL_ENV_VAR} to a graphical diff tool, e.g. meld`);
+  }
+
+  const [command = ""] = splitCommandTemplate(template);
+  if (TERMINAL_DIFFTOOLS.has(path.basename(command))) {
+    throw new Error(
+      `${command} needs a terminal; set ${DIFFTOOL_ENV_VAR} to a graphical diff tool`,
+    );
+  }
+
+  return template;
+}
+
+export async function launchDifftool(repoPath: string, relPath: string, bucket: Bucket) {
+  const template = resolveDifftoolTemplate();
+  const { oldContents, newContents } = await getFileVersionBuffers(repoPath, relPath, bucket);
+  const tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "open-warden-difftool-"));
+  const fileName = path.basename(relPath);
+  const oldPath = path.join(tempDir, "old", fileName);
+  const newPath = path.join(tempDir, "new", fileName);
+
+  const cleanup = () => fs.rm(tempDir, { recursive: true, force: true }).catch(() => undefined);
+
+  try {
+    await Promise.all([fs.mkdir(path.dirname(oldPath)), fs.mkdir(path.dirname(newPath))]);
+    await Promise.all([
+      fs.writeFile(oldPath, oldContents ?? Buffer.alloc(0)),
+      fs.writeFile(newPath, newContents ?? Buffer.alloc(0)),
+    ]);
+  } catch (error) {
+    await cleanup();
+    throw error;
+  }
+
+  const [command, ...args] = buildDifftoolArgv(template, oldPath, newPath);
+  // Resolve once the tool is running; it reads the temp files until it exits, so clean up then.
+  await new Promise<void>((resolve, reject) => {
+    const child = spawn(command!, args, { cwd: repoPath, detached: true, stdio: "ignore" });
+    child.once("error", (error) => {
+      void cleanup();
+      reject(error);
+    });
+    child.once("exit", () => void cleanup());
+    child.once("spawn", () => {
+      child.unref();
+      resolve();
+    });
+  });
+}
@@ -0,0 +1,38 @@
+import { describe, expect, test } from "vitest";
+
+import { fileManagerCommand } from "./fileManager";
+
+describe("file manager command", () => {
+  test("reveals files in Finder and opens folders directly on macOS", () => {
+    expect(fileManagerCommand("darwin", "/repo/src/main.ts", false)).toEqual({
+      command: "open",
+      args: ["-R", "/repo/src/main.ts"],
+    });
+    expect(fileManagerCommand("darwin", "/repo", true)).toEqual({
+      command: "open",
+      args: ["/repo"],
+    });
+  });
+
+  test("selects files in Explorer on Windows", () => {
+    expect(fileManagerCommand("win32", "C:/repo/src/main.ts", false)).toEqual({
+      command: "explorer",
+      args: ["/select,C:\\repo\\src\\main.ts"],
+    });
+    expect(fileManagerCommand("win32", "C:\\repo", true)).toEqual({
+      command: "explorer",
+      args: ["C:\\repo"],
+    });
+  });
+
+  test("opens the containing folder with xdg-open elsewhere", () => {
+    expect(fileManagerCommand("linux", "/repo/src/main.ts", false)).toEqual({
+      command: "xdg-open",
+      args: ["/repo/src"],
+    });
+    expect(fileManagerCommand("freebsd", "/repo", true)).toEqual({
+      command: "xdg-open",
+      args: ["/repo"],
+    });
+  });
+});
@@ -0,0 +1,28 @@
+import path from "node:path";
+
+export type FileManagerCommand = {
+  command: string;
+  args: string[];
+};
+
+// Picks the platform file manager invocation. Files are revealed (selected) where the platform
+// supports it; Linux has no portable "select" verb, so the containing folder is opened instead.
+export function fileManagerCommand(
+  platform: NodeJS.Platform,
+  targetPath: string,
+  isDirectory: boolean,
+): FileManagerCommand {
+  if (platform === "darwin") {
+    return { command: "open", args: isDirectory ? [targetPath] : ["-R", targetPath] };
+  }
+
+  if (platform === "win32") {
+    const windowsPath = path.win32.normalize(targetPath);
+    return {
+      command: "explorer",
+      args: isDirectory ? [windowsPath] : [`/select,${windowsPath}`],
+    };
+  }
+
+  return { command: "xdg-open", args: [isDirectory ? targetPath : path.dirname(targetPath)] };
+}
@@ -1,11 +1,14 @@
 import { execFileSync } from "node:child_process";
 import {
+  chmodSync,
   copyFileSync,
   existsSync,
+  mkdirSync,
   mkdtempSync,
   readFileSync,
   realpathSync,
   unlinkSync,
+  utimesSync,
   writeFileSync,
 } from "node:fs";
 import { rm } from "node:fs/promises";
@@ -15,23 +18,64 @@ import path from "node:path";
 import { afterEach, describe, expect, test } from "vitest";
 
 import {
+  addGitignorePattern,
+  applyStash,
+  blameFile,
+  checkoutFileFromRef,
   commitStaged,
   discardAll,
   discardFile,
   discardFiles,
+  discardUnstaged,
+  diffPathsBetweenRefs,
+  findStashByMessage,
+  formatCommitPatch,
   getBranches,
   getBranchFileVersions,
   getBranchFiles,
+  getChangedFileHashes,
   getCommitFileVersions,
   getCommitFiles,
   getCommitHistory,
+  getDefaultBranch,
+  getRecentlyModifiedFiles,
+  getReflog,
+  getCommitStats,
+  getParentSummary,
   getFileVersions,
   getGitSnapshot,
+  getWorkingCopySummary,
+  GitError,
+  getWorkingTreeDiffForPaths,
+  getRepoFile,
+  getRepoFiles,
+  isPathIgnored,
+  listStashes,
+  popStash,
+  previewStageAll,
+  pushStash,
+  resolveRefInfo,
+  resolveStash,
+  previewUnstageAll,
+  rewordHead,
+  amendHead,
   stageAll,
+  stageDirectory,
   stageFile,
   unstageAll,
+  unstageDirectory,
   unstageFile,
+  updateWorktreeFileContents,
 } from "./git";
+import * as gitBackend from "./git";
+import { type LogEvent, setLogLevel, setLogSink } from "./logger";
+import {
+  buildFixRequest,
+  createReviewComment,
+  loadReviewDiff,
+} from "../src/features/comments/review";
+import { parseUnifiedDiff } from "../src/features/diff-view/services/unifiedDiff";
+import { WORKTREE_REF } from "../src/platform/desktop/gitRefs";
 
 const tempDirs: string[] = [];
 
@@ -93,6 +137,51 @@ describe("electron git backend", () => {
     expect(snapshot.untracked.map((file) => file.path)).toEqual(["untracked.txt"]);
   });
 
+  test("summarizes the branch and dirty file count in one call", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\n");
+    writeFileSync(path.join(repo, "renamed.txt"), "same\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    git(repo, ["checkout", "-b", "feature/summary"]);
+
+    writeFileSync(path.join(repo, "tracked.txt"), "two\n");
+    git(repo, ["mv", "renamed.txt", "moved.txt"]);
+    writeFileSync(path.join(repo, "scratch.txt"), "new\n");
+
+    expect(await getWorkingCopySummary(repo)).toEqual({
+      branch: "feature/summary",
+      upstream: null,
+      ahead: 0,
+      behind: 0,
+      dirtyCount: 3,
+    });
+  });
+
+  test("hashes changed worktree files so later edits can be detected", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "v1\n");
+    writeFileSync(path.join(repo, "deleted.txt"), "gone soon\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    writeFileSync(path.join(repo, "tracked.txt"), "v2\n");
+    writeFileSync(path.join(repo, "untracked.txt"), "untracked\n");
+    unlinkSync(path.join(repo, "deleted.txt"));
+
+    const first = await getChangedFileHashes(repo);
+    expect(Object.keys(first).sort()).toEqual(["deleted.txt", "tracked.txt", "untracked.txt"]);
+    expect(first["deleted.txt"]).toEqual("deleted");
+    expect(first["tracked.txt"]).toEqual(git(repo, ["hash-object", "tracked.txt"]));
+
+    writeFileSync(path.join(repo, "tracked.txt"), "v3\n");
+    const second = await getChangedFileHashes(repo);
+    expect(second["tracked.txt"]).not.toEqual(first["tracked.txt"]);
+    expect(second["untracked.txt"]).toEqual(first["untracked.txt"]);
+  });
+
   test("loads commit history and file versions", async () => {
     const repo = makeRepo();
 
@@ -110,6 +199,10 @@ describe("electron git backend", () => {
     const versions = await getCommitFileVersions(repo, history[0]!.commitId, "notes.md");
 
     expect(history[0]!.summary).toEqual("update notes");
+    expect(await getCommitHistory(repo, 1, 1)).toEqual([history[1]]);
+    expect(history.at(-1)!.parentIds).toEqual([]);
+    expect(history[0]!.parentIds).toEqual([history[1]!.commitId]);
+    expect(history[0]!.timestamp).toBe(Number(git(repo, ["log", "-1", "--format=%at"])));
     expect(files).toEqual([
       {
         path: "notes.md",
@@ -128,6 +221,522 @@ describe("electron git backend", () => {
     expect(versions.newFile?.contents.trim()).toEqual("v2");
   });
 
+  test("keeps commit subjects that mention a relative time intact", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "notes.md"), "v1\n");
+    git(repo, ["add", "notes.md"]);
+    git(repo, ["commit", "-m", "Revert what landed 2 days ago"]);
+
+    const [commit] = await getCommitHistory(repo);
+
+    expect(commit!.summary).toEqual("Revert what landed 2 days ago");
+    expect(commit!.relativeTime).toEqual(git(repo, ["log", "-1", "--format=%ar"]));
+  });
+
+  test("diffs merge commits against the selected parent", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "base.txt"), "base\n");
+    git(repo, ["add", "base.txt"]);
+    git(repo, ["commit", "-m", "base"]);
+
+    git(repo, ["checkout", "-b", "feature"]);
+    writeFileSync(path.join(repo, "feature.txt"), "feature\n");
+    git(repo, ["add", "feature.txt"]);
+    git(repo, ["commit", "-m", "feature work"]);
+
+    git(repo, ["checkout", "-"]);
+    writeFileSync(path.join(repo, "mainline.txt"), "mainline\n");
+    git(repo, ["add", "mainline.txt"]);
+    git(repo, ["commit", "-m", "mainline work"]);
+    git(repo, ["merge", "--no-ff", "feature", "-m", "merge feature"]);
+
+    const history = await getCommitHistory(repo);
+    const merge = history[0]!;
+    const againstFirstParent = await getCommitFiles(repo, merge.commitId);
+    const againstSecondParent = await getCommitFiles(repo, merge.commitId, 1);
+    const versions = await getCommitFileVersions(
+      repo,
+      merge.commitId,
+      "mainline.txt",
+      undefined,
+      1,
+    );
+
+    expect(merge.parentIds).toHaveLength(2);
+    expect(againstFirstParent.map((file) => file.path)).toEqual(["feature.txt"]);
+    expect(againstSecondParent.map((file) => file.path)).toEqual(["mainline.txt"]);
+    expect(versions.oldFile).toBeNull();
+    expect(versions.newFile?.contents).toEqual("mainline\n");
+    await expect(getCommitFiles(repo, merge.commitId, 2)).rejects.toThrow("has no parent 3");
+  });
+
+  test("reports the branch origin/HEAD points at as the default branch", async () => {
+    const origin = makeRepo();
+    writeFileSync(path.join(origin, "README.md"), "hello\n");
+    git(origin, ["add", "README.md"]);
+    git(origin, ["commit", "-m", "init"]);
+    git(origin, ["branch", "-M", "main"]);
+    git(origin, ["branch", "develop"]);
+
+    const clone = mkdtempSync(path.join(os.tmpdir(), "open-warden-electron-"));
+    tempDirs.push(clone);
+    git(clone, ["clone", origin, "."]);
+    git(clone, ["checkout", "develop"]);
+
+    expect(await getDefaultBranch(clone)).toEqual("main");
+  });
+
+  test("falls back to common default branch names without a remote", async () => {
+    const repo = makeRepo();
+    writeFileSync(path.join(repo, "README.md"), "hello\n");
+    git(repo, ["add", "README.md"]);
+    git(repo, ["commit", "-m", "init"]);
+    git(repo, ["branch", "-M", "trunk"]);
+
+    expect(await getDefaultBranch(repo)).toEqual("trunk");
+
+    git(repo, ["branch", "-M", "feature"]);
+    expect(await getDefaultBranch(repo)).toBeNull();
+  });
+
+  test("lists HEAD reflog entries newest first", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "one\n");
+    git(repo, ["add", "a.txt"]);
+    git(repo, ["commit", "-m", "first"]);
+    const first = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(path.join(repo, "a.txt"), "two\n");
+    git(repo, ["commit", "-am", "second"]);
+    const second = git(repo, ["rev-parse", "HEAD"]);
+
+    const reflog = await getReflog(repo);
+
+    expect(reflog.length).toBeGreaterThanOrEqual(2);
+    expect(reflog[0]).toMatchObject({
+      selector: "HEAD@{0}",
+      oldId: first,
+      newId: second,
+      message: "commit: second",
+      committer: "OpenWarden Test",
+    });
+    expect(reflog[1]).toMatchObject({
+      selector: "HEAD@{1}",
+      oldId: null,
+      newId: first,
+      message: "commit (initial): first",
+    });
+    expect(await getReflog(repo, "HEAD", 1)).toEqual([
+      expect.objectContaining({ oldId: first, newId: second }),
+    ]);
+  });
+
+  test("finds, resolves, and applies stashes by message", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "one\n");
+    git(repo, ["add", "a.txt"]);
+    git(repo, ["commit", "-m", "first"]);
+    const head = git(repo, ["rev-parse", "HEAD"]);
+
+    expect(await findStashByMessage(repo, "parser")).toBeNull();
+
+    writeFileSync(path.join(repo, "a.txt"), "parser\n");
+    git(repo, ["stash", "push", "-m", "wip parser fix"]);
+    writeFileSync(path.join(repo, "a.txt"), "layout\n");
+    git(repo, ["stash", "push", "-m", "experiment layout"]);
+
+    expect(await findStashByMessage(repo, "PARSER")).toBe(1);
+    expect(await findStashByMessage(repo, "layout")).toBe(0);
+    expect(await findStashByMessage(repo, "missing")).toBeNull();
+
+    expect(await resolveStash(repo, "parser")).toEqual({
+      index: 1,
+      oid: git(repo, ["rev-parse", "stash@{1}"]),
+      baseId: head,
+      message: expect.stringContaining("wip parser fix"),
+      author: "OpenWarden Test",
+      relativeTime: expect.any(String),
+    });
+    await expect(resolveStash(repo, "missing")).rejects.toThrow('no stash matches "missing"');
+
+    await applyStash(repo, "parser fix");
+    expect(readFileSync(path.join(repo, "a.txt"), "utf8")).toBe("parser\n");
+    expect(git(repo, ["stash", "list"]).split("\n")).toHaveLength(2);
+  });
+
+  test("pushes, lists, and pops stashes", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "one\n");
+    git(repo, ["add", "a.txt"]);
+    git(repo, ["commit", "-m", "first"]);
+
+    expect(await listStashes(repo)).toEqual([]);
+    await expect(pushStash(repo)).rejects.toMatchObject({ kind: "nothing-to-stash" });
+
+    writeFileSync(path.join(repo, "a.txt"), "stashed\n");
+    writeFileSync(path.join(repo, "new.txt"), "untracked\n");
+    await pushStash(repo, { message: "before pull", includeUntracked: true });
+
+    expect(git(repo, ["status", "--porcelain"])).toBe("");
+    expect(await listStashes(repo)).toEqual([
+      {
+        index: 0,
+        oid: git(repo, ["rev-parse", "stash@{0}"]),
+        message: expect.stringContaining("before pull"),
+        author: "OpenWarden Test",
+        relativeTime: expect.any(String),
+      },
+    ]);
+
+    await popStash(repo, 0);
+    expect(readFileSync(path.join(repo, "new.txt"), "utf8")).toBe("untracked\n");
+    expect(await listStashes(repo)).toEqual([]);
+
+    await pushStash(repo, { message: "conflicting" });
+    writeFileSync(path.join(repo, "a.txt"), "committed\n");
+    git(repo, ["commit", "-am", "second"]);
+
+    await expect(popStash(repo, "conflicting")).rejects.toMatchObject({
+      kind: "merge-conflict",
+      message: "stash@{0} conflicts with a.txt; the stash was kept",
+    });
+    expect(await listStashes(repo)).toHaveLength(1);
+  });
+
+  test("lists tracked files by worktree mtime, most recent first", async () => {
+    const repo = makeRepo();
+
+    mkdirSync(path.join(repo, "src"));
+    writeFileSync(path.join(repo, ".gitignore"), "*.log\n");
+    for (const name of ["old.txt", "src/middle.ts", "new.txt", "gone.txt"]) {
+      writeFileSync(path.join(repo, name), `${name}\n`);
+    }
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    unlinkSync(path.join(repo, "gone.txt"));
+    writeFileSync(path.join(repo, "untracked.txt"), "untracked\n");
+    writeFileSync(path.join(repo, "debug.log"), "ignored\n");
+
+    const base = Date.now() / 1000 - 1000;
+    utimesSync(path.join(repo, ".gitignore"), base, base);
+    utimesSync(path.join(repo, "old.txt"), base + 100, base + 100);
+    utimesSync(path.join(repo, "src/middle.ts"), base + 200, base + 200);
+    utimesSync(path.join(repo, "new.txt"), base + 300, base + 300);
+
+    expect(await getRecentlyModifiedFiles(repo)).toEqual([
+      "new.txt",
+      "src/middle.ts",
+      "old.txt",
+      ".gitignore",
+    ]);
+    expect(await getRecentlyModifiedFiles(repo, 2)).toEqual(["new.txt", "src/middle.ts"]);
+    expect(await getRecentlyModifiedFiles(repo, 0)).toEqual([]);
+  });
+
+  test("rewords the last commit without touching its tree or author", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "one\n");
+    git(repo, ["add", "a.txt"]);
+    git(repo, ["commit", "-m", "frist", "--author", "Original Author <original@example.com>"]);
+    const before = git(repo, ["rev-parse", "HEAD"]);
+    const treeBefore = git(repo, ["rev-parse", "HEAD^{tree}"]);
+
+    writeFileSync(path.join(repo, "a.txt"), "staged\n");
+    git(repo, ["add", "a.txt"]);
+
+    const commitId = await rewordHead(repo, "first");
+
+    expect(commitId).not.toEqual(before);
+    expect(git(repo, ["rev-parse", "HEAD^{tree}"])).toEqual(treeBefore);
+    expect(git(repo, ["log", "-1", "--format=%s|%an"])).toEqual("first|Original Author");
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toEqual("a.txt");
+  });
+
+  test("amends the last commit with the staged changes", async () => {
+    const repo = makeRepo();
+
+    await expect(amendHead(repo)).rejects.toMatchObject({ kind: "no-commits" });
+
+    writeFileSync(path.join(repo, "a.txt"), "one\n");
+    git(repo, ["add", "a.txt"]);
+    git(repo, ["commit", "-m", "add a"]);
+    const before = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(path.join(repo, "b.txt"), "two\n");
+    git(repo, ["add", "b.txt"]);
+
+    const commitId = await amendHead(repo);
+
+    expect(commitId).not.toEqual(before);
+    expect(git(repo, ["log", "--format=%s"])).toEqual("add a");
+    expect(git(repo, ["show", "--name-only", "--format=", "HEAD"])).toEqual("a.txt\nb.txt");
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toEqual("");
+
+    await amendHead(repo, "add a and b");
+    expect(git(repo, ["log", "--format=%s"])).toEqual("add a and b");
+  });
+
+  test("counts insertions, deletions, and files per commit", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "one\ntwo\nthree\n");
+    git(repo, ["add", "a.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+    const rootCommit = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(path.join(repo, "a.txt"), "one\nthree\n");
+    writeFileSync(path.join(repo, "b.txt"), "1\n2\n3\n4\n5\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "second"]);
+    const secondCommit = git(repo, ["rev-parse", "HEAD"]);
+
+    expect(await getCommitStats(repo, rootCommit)).toEqual({
+      insertions: 3,
+      deletions: 0,
+      files: 1,
+    });
+    expect(await getCommitStats(repo, secondCommit)).toEqual({
+      insertions: 5,
+      deletions: 1,
+      files: 2,
+    });
+  });
+
+  test("appends a .gitignore pattern once and creates the file when missing", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "scratch.log"), "noise\n");
+
+    expect(await addGitignorePattern(repo, "/scratch.log")).toBe(true);
+    expect(await addGitignorePattern(repo, "/scratch.log")).toBe(false);
+    expect(readFileSync(path.join(repo, ".gitignore"), "utf8")).toEqual("/scratch.log\n");
+
+    writeFileSync(path.join(repo, ".gitignore"), "node_modules");
+    await addGitignorePattern(repo, "/scratch.log");
+
+    expect(readFileSync(path.join(repo, ".gitignore"), "utf8")).toEqual(
+      "node_modules\n/scratch.log\n",
+    );
+    expect((await getGitSnapshot(repo)).untracked.map((file) => file.path)).toEqual([".gitignore"]);
+  });
+
+  test("keeps escaped trailing spaces in .gitignore patterns", async () => {
+    const repo = makeRepo();
+    writeFileSync(path.join(repo, "notes "), "draft\n");
+
+    expect(await addGitignorePattern(repo, "/notes\\ ")).toBe(true);
+    expect(await addGitignorePattern(repo, "/notes\\ ")).toBe(false);
+
+    expect(readFileSync(path.join(repo, ".gitignore"), "utf8")).toEqual("/notes\\ \n");
+    expect((await getGitSnapshot(repo)).untracked.map((file) => file.path)).toEqual([".gitignore"]);
+  });
+
+  test("reports the first parent's subject and null for a root commit", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\n");
+    git(repo, ["add", "tracked.txt"]);
+    git(repo, ["commit", "-m", "fix bug"]);
+    const rootCommit = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(path.join(repo, "tracked.txt"), "two\n");
+    git(repo, ["commit", "-am", "add feature"]);
+    const childCommit = git(repo, ["rev-parse", "HEAD"]);
+
+    expect(await getParentSummary(repo, childCommit)).toBe("fix bug");
+    expect(await getParentSummary(repo, rootCommit)).toBeNull();
+  });
+
+  test("resolves a ref to its commit and summary", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "notes.md"), "v1\n");
+    git(repo, ["add", "notes.md"]);
+    git(repo, ["commit", "-m", "First commit"]);
+    writeFileSync(path.join(repo, "notes.md"), "v2\n");
+    git(repo, ["commit", "-am", "Update release notes", "-m", "Body text."]);
+    const commitId = git(repo, ["rev-parse", "HEAD"]);
+
+    const refInfo = await resolveRefInfo(repo, "HEAD");
+
+    expect(refInfo).toEqual({
+      commitId,
+      shortId: git(repo, ["rev-parse", "--short", "HEAD"]),
+      summary: "Update release notes",
+      author: git(repo, ["log", "-1", "--format=%an"]),
+    });
+    await expect(resolveRefInfo(repo, "missing-branch")).rejects.toThrow("unknown ref");
+  });
+
+  test("formats a commit as a mailbox patch", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "notes.md"), "v1\n");
+    git(repo, ["add", "notes.md"]);
+    git(repo, ["commit", "-m", "Add release notes", "-m", "Explains the release."]);
+    const commitId = git(repo, ["rev-parse", "HEAD"]);
+
+    const patch = await formatCommitPatch(repo, commitId);
+
+    expect(patch.startsWith(`From ${commitId} `)).toBe(true);
+    expect(patch).toContain("Subject: [PATCH] Add release notes");
+    expect(patch).toContain("Explains the release.");
+    expect(patch).toContain("+++ b/notes.md");
+    expect(patch).toMatch(/\n-- \n/);
+  });
+
+  test("blames each line to the commit that last changed it", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "notes.md"), "one\ntwo\n");
+    git(repo, ["add", "notes.md"]);
+    git(repo, ["commit", "-m", "init"]);
+    const firstCommit = git(repo, ["rev-parse", "HEAD"]);
+    writeFileSync(path.join(repo, "notes.md"), "one\nTWO\nthree\n");
+    git(repo, ["commit", "-am", "Update notes"]);
+    const secondCommit = git(repo, ["rev-parse", "HEAD"]);
+    writeFileSync(path.join(repo, "notes.md"), "one\nTWO\nthree\nfour\n");
+
+    const worktreeBlame = await blameFile(repo, "notes.md");
+    const firstBlame = await blameFile(repo, "notes.md", `${secondCommit}^`);
+
+    expect(worktreeBlame.slice(0, 3)).toEqual([firstCommit, secondCommit, secondCommit]);
+    expect(worktreeBlame[3]).toMatch(/^0+$/);
+    expect(firstBlame).toEqual([firstCommit, firstCommit]);
+  });
+
+  test("summarizes binary changes in a commit patch", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "logo.png"), Buffer.from([0, 1, 2, 3, 255]));
+    writeFileSync(path.join(repo, "notes.md"), "v1\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "logo.png"), Buffer.from([0, 4, 5, 6, 255]));
+    writeFileSync(path.join(repo, "notes.md"), "v2\n");
+    git(repo, ["commit", "-am", "Update logo and notes"]);
+
+    const patch = await formatCommitPatch(repo, "HEAD");
+    const [logo, notes] = parseUnifiedDiff(patch);
+
+    expect(patch).toContain("Binary files a/logo.png and b/logo.png differ");
+    expect(patch).not.toContain("GIT binary patch");
+    expect(logo).toMatchObject({ newPath: "logo.png", isBinary: true, hunks: [] });
+    expect(notes).toMatchObject({ newPath: "notes.md", isBinary: false });
+    expect(notes?.hunks).toHaveLength(1);
+  });
+
+  test("loads one file of a commit consistent with the full commit diff", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "a1\na2\na3\n");
+    writeFileSync(path.join(repo, "b.txt"), "b1\nb2\nb3\nb4\n");
+    writeFileSync(path.join(repo, "c.txt"), "c1\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "a.txt"), "a1\nA2\na3\n");
+    writeFileSync(path.join(repo, "b.txt"), "b1\nB2\nb3\nb4\nb5\n");
+    writeFileSync(path.join(repo, "c.txt"), "C1\n");
+    git(repo, ["commit", "-am", "Touch every file"]);
+    const commitId = git(repo, ["rev-parse", "HEAD"]);
+
+    const { oldFile, newFile } = await getCommitFileVersions(repo, commitId, "b.txt");
+    const fullDiff = parseUnifiedDiff(await formatCommitPatch(repo, commitId));
+    const fileDiff = fullDiff.find((file) => file.newPath === "b.txt");
+
+    expect(fullDiff.map((file) => file.newPath)).toEqual(["a.txt", "b.txt", "c.txt"]);
+    expect(fileDiff?.hunks.length).toBeGreaterThan(0);
+    const oldLines = oldFile?.contents.split("\n") ?? [];
+    const newLines = newFile?.contents.split("\n") ?? [];
+    for (const hunk of fileDiff?.hunks ?? []) {
+      const oldSide = hunk.lines.filter((line) => line.kind !== "add").map((line) => line.content);
+      const newSide = hunk.lines
+        .filter((line) => line.kind !== "remove")
+        .map((line) => line.content);
+
+      expect(oldLines.slice(hunk.oldStart - 1, hunk.oldStart - 1 + hunk.oldLines)).toEqual(oldSide);
+      expect(newLines.slice(hunk.newStart - 1, hunk.newStart - 1 + hunk.newLines)).toEqual(newSide);
+    }
+  });
+
+  test("diffs a file against its renamed successor line by line", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "old-name.ts"), "one\ntwo\nthree\nfour\n");
+    git(repo, ["add", "old-name.ts"]);
+    git(repo, ["commit", "-m", "init"]);
+    const oldCommit = git(repo, ["rev-parse", "HEAD"]);
+
+    git(repo, ["mv", "old-name.ts", "new-name.ts"]);
+    writeFileSync(path.join(repo, "new-name.ts"), "one\nTWO\nthree\nfour\nfive\n");
+    git(repo, ["add", "-A"]);
+    git(repo, ["commit", "-m", "rename and edit"]);
+
+    const diff = await diffPathsBetweenRefs(repo, oldCommit, "old-name.ts", "HEAD", "new-name.ts");
+
+    expect(diff).toContain("--- a/old-name.ts");
+    expect(diff).toContain("+++ b/new-name.ts");
+    expect(diff).toContain("\n-two\n+TWO\n three\n four\n+five\n");
+    expect(diff).toContain("\n one\n");
+    await expect(
+      diffPathsBetweenRefs(repo, oldCommit, "missing.ts", "HEAD", "new-name.ts"),
+    ).rejects.toThrow("missing.ts does not exist at");
+  });
+
+  test("limits a working tree diff to the requested paths", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "a.txt"), "a1\n");
+    writeFileSync(path.join(repo, "b.txt"), "b1\n");
+    writeFileSync(path.join(repo, "c.txt"), "c1\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "a.txt"), "a2\n");
+    writeFileSync(path.join(repo, "b.txt"), "b2\n");
+    writeFileSync(path.join(repo, "c.txt"), "c2\n");
+    git(repo, ["add", "c.txt"]);
+
+    const unstaged = await getWorkingTreeDiffForPaths(repo, false, ["a.txt"]);
+    const staged = await getWorkingTreeDiffForPaths(repo, true, ["c.txt", "a.txt"]);
+
+    expect(unstaged).toContain("+++ b/a.txt");
+    expect(unstaged).not.toContain("b.txt");
+    expect(unstaged).not.toContain("c.txt");
+    expect(staged).toContain("+++ b/c.txt");
+    expect(staged).not.toContain("a.txt");
+    expect(await getWorkingTreeDiffForPaths(repo, false, [])).toBe("");
+  });
+
+  test("diffs a commit against an earlier range base cumulatively", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "base.txt"), "base\n");
+    git(repo, ["add", "base.txt"]);
+    git(repo, ["commit", "-m", "base"]);
+    const rangeBase = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(path.join(repo, "first.txt"), "first\n");
+    git(repo, ["add", "first.txt"]);
+    git(repo, ["commit", "-m", "first"]);
+
+    writeFileSync(path.join(repo, "second.txt"), "second\n");
+    git(repo, ["add", "second.txt"]);
+    git(repo, ["commit", "-m", "second"]);
+    const head = git(repo, ["rev-parse", "HEAD"]);
+
+    const commitFiles = await getCommitFiles(repo, head);
+    const cumulativeFiles = await getBranchFiles(repo, rangeBase, head);
+
+    expect(commitFiles.map((file) => file.path)).toEqual(["second.txt"]);
+    expect(cumulativeFiles.map((file) => file.path).sort()).toEqual(["first.txt", "second.txt"]);
+  });
+
   test("compares branches with rename-aware and copy-aware diff metadata", async () => {
     const repo = makeRepo();
     const baseBranch = git(repo, ["branch", "--show-current"]);
@@ -163,11 +772,13 @@ describe("electron git backend", () => {
         path: "copied.txt",
         previousPath: "file.txt",
         status: "copied",
+        similarity: 100,
       },
       {
         path: "renamed.txt",
         previousPath: "file.txt",
         status: "renamed",
+        similarity: 100,
       },
     ]);
     expect(renamedVersions.oldFile?.name).toEqual("file.txt");
@@ -178,6 +789,227 @@ describe("electron git backend", () => {
     expect(copiedVersions.oldFile?.contents).toEqual(copiedVersions.newFile?.contents);
   });
 
+  test("compares the working tree against a tag", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "v1\n");
+    git(repo, ["add", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+    git(repo, ["tag", "v1.0.0"]);
+
+    writeFileSync(path.join(repo, "tracked.txt"), "v2\n");
+    git(repo, ["commit", "-am", "second"]);
+    writeFileSync(path.join(repo, "tracked.txt"), "v3\n");
+    writeFileSync(path.join(repo, "new.txt"), "new\n");
+
+    const branches = await getBranches(repo);
+    const files = await getBranchFiles(repo, "v1.0.0", WORKTREE_REF);
+    const versions = await getBranchFileVersions(repo, "v1.0.0", WORKTREE_REF, "tracked.txt");
+
+    expect(branches).toContain("v1.0.0");
+    expect(files).toEqual([
+      { path: "new.txt", previousPath: null, status: "untracked" },
+      { path: "tracked.txt", previousPath: null, status: "modified" },
+    ]);
+    expect(versions.oldFile?.contents).toEqual("v1\n");
+    expect(versions.newFile?.contents).toEqual("v3\n");
+  });
+
+  test("compares an older commit against the working tree", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\n");
+    git(repo, ["add", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+    const oldCommit = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\ntwo\n");
+    git(repo, ["commit", "-am", "second"]);
+    writeFileSync(path.join(repo, "tracked.txt"), "one\ntwo\nthree\n");
+    git(repo, ["add", "tracked.txt"]);
+    writeFileSync(path.join(repo, "tracked.txt"), "one\ntwo\nthree\nfour\n");
+
+    const files = await getBranchFiles(repo, oldCommit, WORKTREE_REF);
+    const versions = await getBranchFileVersions(repo, oldCommit, WORKTREE_REF, "tracked.txt");
+
+    expect(files).toEqual([{ path: "tracked.txt", previousPath: null, status: "modified" }]);
+    expect(versions.oldFile?.contents).toEqual("one\n");
+    expect(versions.newFile?.contents).toEqual("one\ntwo\nthree\nfour\n");
+  });
+
+  test("previews stage-all and unstage-all without touching the index", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "modified.txt"), "v1\n");
+    writeFileSync(path.join(repo, "removed.txt"), "gone\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    writeFileSync(path.join(repo, "modified.txt"), "v2\n");
+    unlinkSync(path.join(repo, "removed.txt"));
+    writeFileSync(path.join(repo, "new file.txt"), "new\n");
+
+    const stagePreview = await previewStageAll(repo);
+
+    expect(stagePreview).toEqual([
+      { path: "modified.txt", action: "add" },
+      { path: "new file.txt", action: "add" },
+      { path: "removed.txt", action: "remove" },
+    ]);
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toEqual("");
+
+    await stageAll(repo);
+
+    expect(git(repo, ["diff", "--cached", "--name-only"]).split("\n")).toEqual(
+      stagePreview.map((entry) => entry.path),
+    );
+    expect(await previewUnstageAll(repo)).toEqual([
+      { path: "modified.txt", action: "unstage" },
+      { path: "new file.txt", action: "unstage" },
+      { path: "removed.txt", action: "unstage" },
+    ]);
+  });
+
+  test("stages and unstages every change under a directory in one call", async () => {
+    const repo = makeRepo();
+
+    mkdirSync(path.join(repo, "src"));
+    writeFileSync(path.join(repo, "src", "tracked.ts"), "one\n");
+    writeFileSync(path.join(repo, "outside.txt"), "one\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    writeFileSync(path.join(repo, "src", "tracked.ts"), "two\n");
+    writeFileSync(path.join(repo, "src", "added.ts"), "new\n");
+    writeFileSync(path.join(repo, "outside.txt"), "two\n");
+
+    await stageDirectory(repo, "src/");
+    expect(git(repo, ["diff", "--cached", "--name-only"]).split("\n")).toEqual([
+      "src/added.ts",
+      "src/tracked.ts",
+    ]);
+
+    await unstageDirectory(repo, "src");
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toBe("");
+    await expect(stageDirectory(repo, "../elsewhere")).rejects.toThrow("path cannot contain '..'");
+    await expect(stageDirectory(repo, ".")).rejects.toThrow(
+      "directory must be below the repository root",
+    );
+  });
+
+  test("restores a single file from an earlier revision", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "restore.txt"), "first\n");
+    writeFileSync(path.join(repo, "other.txt"), "untouched\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    writeFileSync(path.join(repo, "restore.txt"), "second\n");
+    git(repo, ["commit", "-am", "update"]);
+    writeFileSync(path.join(repo, "other.txt"), "edited\n");
+
+    await checkoutFileFromRef(repo, "restore.txt", "HEAD~1");
+
+    expect(readFileSync(path.join(repo, "restore.txt"), "utf8")).toEqual("first\n");
+    expect(readFileSync(path.join(repo, "other.txt"), "utf8")).toEqual("edited\n");
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toEqual("");
+
+    await checkoutFileFromRef(repo, "restore.txt", "HEAD~1", true);
+
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toEqual("restore.txt");
+    await expect(checkoutFileFromRef(repo, "restore.txt", "no-such-ref")).rejects.toThrow(
+      "unknown ref: no-such-ref",
+    );
+    await expect(checkoutFileFromRef(repo, "missing.txt", "HEAD")).rejects.toThrow(
+      "missing.txt does not exist at HEAD",
+    );
+    await expect(
+      checkoutFileFromRef(repo, "restore.txt", "HEAD~1", false, ["restore.txt"]),
+    ).rejects.toThrow("restore.txt is a protected path");
+  });
+
+  test("reads files up to a byte limit and rejects binary content", async () => {
+    const repo = makeRepo();
+
+    // The two-byte "é" straddles the 10-byte limit, so only the ASCII prefix survives.
+    writeFileSync(path.join(repo, "big.txt"), `${"a".repeat(9)}é${"b".repeat(100)}\n`);
+    writeFileSync(path.join(repo, "small.txt"), "small\n");
+    writeFileSync(path.join(repo, "image.bin"), Buffer.from([0x89, 0x50, 0x4e, 0x47, 0, 1, 2]));
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    for (const revision of [null, "HEAD"]) {
+      await expect(
+        getRepoFile({ repoPath: repo, relPath: "big.txt", revision, maxBytes: 10 }),
+      ).resolves.toEqual({ name: "big.txt", contents: "a".repeat(9), truncated: true });
+      await expect(
+        getRepoFile({ repoPath: repo, relPath: "small.txt", revision, maxBytes: 10 }),
+      ).resolves.toEqual({ name: "small.txt", contents: "small\n" });
+      await expect(
+        getRepoFile({ repoPath: repo, relPath: "image.bin", revision, maxBytes: 4 }),
+      ).rejects.toThrow("binary file is not supported: image.bin");
+    }
+  });
+
+  test("lists tracked and untracked repo files with ignored files behind a flag", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, ".gitignore"), "*.log\n");
+    writeFileSync(path.join(repo, "tracked.txt"), "tracked\n");
+    git(repo, ["add", ".gitignore", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "untracked.txt"), "new\n");
+    writeFileSync(path.join(repo, "debug.log"), "noise\n");
+
+    expect(await getRepoFiles(repo)).toEqual([
+      { path: ".gitignore" },
+      { path: "tracked.txt" },
+      { path: "untracked.txt" },
+    ]);
+    expect(await getRepoFiles(repo, { includeIgnored: true })).toEqual([
+      { path: ".gitignore" },
+      { path: "debug.log", ignored: true },
+      { path: "tracked.txt" },
+      { path: "untracked.txt" },
+    ]);
+  });
+
+  test("reports whether a path is gitignored", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, ".gitignore"), "*.log\n");
+    writeFileSync(path.join(repo, "tracked.txt"), "tracked\n");
+    git(repo, ["add", ".gitignore", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "debug.log"), "noise\n");
+
+    expect(await isPathIgnored(repo, "debug.log")).toBe(true);
+    expect(await isPathIgnored(repo, "tracked.txt")).toBe(false);
+  });
+
+  test("reports rename and copy similarity for commit files", async () => {
+    const repo = makeRepo();
+    const original = Array.from({ length: 10 }, (_, index) => `line ${index + 1}`).join("\n");
+
+    writeFileSync(path.join(repo, "source.txt"), `${original}\n`);
+    git(repo, ["add", "source.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+
+    git(repo, ["mv", "source.txt", "moved.txt"]);
+    copyFileSync(path.join(repo, "moved.txt"), path.join(repo, "copied.txt"));
+    writeFileSync(path.join(repo, "moved.txt"), `${original.replace("line 10", "line ten")}\n`);
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "move and copy"]);
+
+    const files = await getCommitFiles(repo, git(repo, ["rev-parse", "HEAD"]));
+
+    expect(files).toEqual([
+      { path: "copied.txt", previousPath: "source.txt", status: "copied", similarity: 100 },
+      { path: "moved.txt", previousPath: "source.txt", status: "renamed", similarity: 87 },
+    ]);
+  });
+
   test("reports detached HEAD state and still lists branches", async () => {
     const repo = makeRepo();
     const initialBranch = git(repo, ["branch", "--show-current"]);
@@ -196,6 +1028,32 @@ describe("electron git backend", () => {
     expect(branches).toContain(initialBranch);
   });
 
+  test("labels paths outside a sparse-checkout cone instead of treating them as deleted", async () => {
+    const repo = makeRepo();
+
+    mkdirSync(path.join(repo, "included"));
+    mkdirSync(path.join(repo, "excluded"));
+    writeFileSync(path.join(repo, "included", "kept.txt"), "kept\n");
+    writeFileSync(path.join(repo, "excluded", "sparse.txt"), "sparse\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    git(repo, ["sparse-checkout", "set", "included"]);
+
+    expect(existsSync(path.join(repo, "excluded", "sparse.txt"))).toBe(false);
+    expect(await getRepoFiles(repo)).toEqual([
+      { path: "excluded/sparse.txt", outsideSparseCone: true },
+      { path: "included/kept.txt" },
+    ]);
+
+    const snapshot = await getGitSnapshot(repo);
+    expect(snapshot.unstaged).toEqual([]);
+
+    const versions = await getFileVersions(repo, "excluded/sparse.txt", "unstaged");
+    expect(versions.outsideSparseCone).toBe(true);
+    expect(versions.oldFile?.contents).toEqual("sparse\n");
+    expect(versions.newFile).toBeNull();
+  });
+
   test("rejects binary file contents in a controlled way", async () => {
     const repo = makeRepo();
 
@@ -237,6 +1095,216 @@ describe("electron git backend", () => {
     expect(readFileSync(path.join(repo, "tracked.txt"), "utf8")).toEqual("one\n");
   });
 
+  test("aborts the commit and surfaces the output when a pre-commit hook fails", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\n");
+    git(repo, ["add", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+    const head = git(repo, ["rev-parse", "HEAD"]);
+
+    writeFileSync(
+      path.join(repo, ".git", "hooks", "pre-commit"),
+      '#!/bin/sh\necho "lint failed: trailing whitespace"\nexit 1\n',
+      { mode: 0o755 },
+    );
+    writeFileSync(path.join(repo, "tracked.txt"), "two \n");
+    await stageAll(repo);
+
+    await expect(commitStaged(repo, "second commit")).rejects.toThrow(
+      "lint failed: trailing whitespace",
+    );
+    expect(git(repo, ["rev-parse", "HEAD"])).toEqual(head);
+    expect(git(repo, ["diff", "--cached", "--name-only"])).toEqual("tracked.txt");
+  });
+
+  test("loads a diff, comments on it, and builds a fix request without the renderer", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "notes.md"), "one\ntwo\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "notes.md"), "one\nTWO\nthree\n");
+    git(repo, ["add", "notes.md"]);
+
+    const staged = await loadReviewDiff(gitBackend, repo, { kind: "bucket", bucket: "staged" });
+    const commit = await loadReviewDiff(gitBackend, repo, { kind: "commit", commitId: "HEAD" });
+    const [file] = staged.files;
+
+    expect(staged.files.map((entry) => entry.path)).toEqual(["notes.md"]);
+    expect(file?.fileDiff?.hunks).toHaveLength(1);
+    expect(file?.fileDiff?.additionLines[1]).toBe("TWO\n");
+    expect(commit.files[0]?.fileDiff?.additionLines).toEqual(["one\n", "two\n"]);
+
+    const comment = createReviewComment({
+      repoPath: repo,
+      filePath: file!.path,
+      bucket: "staged",
+      range: { start: 2, end: 3 },
+      text: "  Keep this lowercase.  ",
+      id: "c1",
+    });
+    const request = buildFixRequest(repo, comment ? [comment] : []);
+
+    expect(comment).toMatchObject({ id: "c1", bucket: "staged", side: "additions" });
+    expect(request).toEqual({
+      repoPath: repo,
+      comments: [comment],
+      prompt: "@notes.md#L2-3 - Keep this lowercase.",
+      patch: "",
+    });
+    expect(buildFixRequest(repo, [])).toBeNull();
+  });
+
+  test("carries a fix request patch that applies to the reviewed base", async () => {
+    const repo = makeRepo();
+    const patchPath = path.join(repo, ".git", "fix.patch");
+
+    writeFileSync(path.join(repo, "notes.md"), "one\ntwo\n");
+    writeFileSync(path.join(repo, "old.md"), "gone\n");
+    writeFileSync(path.join(repo, "skipped.md"), "a\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "notes.md"), "one\nTWO\nthree");
+    writeFileSync(path.join(repo, "todo.md"), "first\nsecond\n");
+    writeFileSync(path.join(repo, "skipped.md"), "b\n");
+    unlinkSync(path.join(repo, "old.md"));
+    git(repo, ["add", "-A"]);
+
+    const diff = await loadReviewDiff(gitBackend, repo, { kind: "bucket", bucket: "staged" });
+    const comments = ["notes.md", "todo.md", "old.md"].flatMap((filePath) => {
+      const comment = createReviewComment({
+        repoPath: repo,
+        filePath,
+        bucket: "staged",
+        range: { start: 1, end: 1 },
+        text: "Check this.",
+      });
+      return comment ? [comment] : [];
+    });
+    const request = buildFixRequest(repo, comments, diff);
+
+    expect(request?.patch).toContain("diff --git a/notes.md b/notes.md");
+    expect(request?.patch).toContain("--- /dev/null\n+++ b/todo.md");
+    expect(request?.patch).toContain("--- a/old.md\n+++ /dev/null");
+    expect(request?.patch).not.toContain("skipped.md");
+
+    git(repo, ["reset", "-q", "--hard"]);
+    writeFileSync(patchPath, request!.patch);
+    expect(git(repo, ["apply", "--check", patchPath])).toBe("");
+    git(repo, ["apply", patchPath]);
+    expect(readFileSync(path.join(repo, "notes.md"), "utf8")).toBe("one\nTWO\nthree");
+    expect(readFileSync(path.join(repo, "todo.md"), "utf8")).toBe("first\nsecond\n");
+    expect(existsSync(path.join(repo, "old.md"))).toBe(false);
+  });
+
+  test("keeps executable modes in a fix request patch", async () => {
+    const repo = makeRepo();
+    const patchPath = path.join(repo, ".git", "fix.patch");
+
+    writeFileSync(path.join(repo, "run.sh"), "#!/bin/sh\necho one\n", { mode: 0o755 });
+    writeFileSync(path.join(repo, "build.sh"), "#!/bin/sh\n");
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    writeFileSync(path.join(repo, "run.sh"), "#!/bin/sh\necho two\n");
+    writeFileSync(path.join(repo, "build.sh"), "#!/bin/sh\nmake\n");
+    chmodSync(path.join(repo, "build.sh"), 0o755);
+    writeFileSync(path.join(repo, "new.sh"), "#!/bin/sh\n", { mode: 0o755 });
+    git(repo, ["add", "-A"]);
+
+    const diff = await loadReviewDiff(gitBackend, repo, { kind: "bucket", bucket: "staged" });
+    const comments = ["run.sh", "build.sh", "new.sh"].flatMap((filePath) => {
+      const comment = createReviewComment({
+        repoPath: repo,
+        filePath,
+        bucket: "staged",
+        range: { start: 1, end: 1 },
+        text: "Check this.",
+      });
+      return comment ? [comment] : [];
+    });
+    const request = buildFixRequest(repo, comments, diff);
+
+    expect(diff.files.find((file) => file.path === "run.sh")).toMatchObject({
+      oldMode: "100755",
+      newMode: "100755",
+    });
+    expect(request?.patch).toContain("old mode 100644\nnew mode 100755\n");
+    expect(request?.patch).toContain("new file mode 100755\n");
+
+    git(repo, ["reset", "-q", "--hard"]);
+    writeFileSync(patchPath, request!.patch);
+    git(repo, ["apply", "--index", patchPath]);
+    expect(git(repo, ["ls-files", "-s", "run.sh", "build.sh", "new.sh"])).toMatch(
+      /^100755 .*\tbuild\.sh\n100755 .*\tnew\.sh\n100755 .*\trun\.sh$/,
+    );
+  });
+
+  test("cuts an oversized fix request patch down to the commented hunks", async () => {
+    const repo = makeRepo();
+    const patchPath = path.join(repo, ".git", "fix.patch");
+    const lines = Array.from({ length: 200 }, (_, index) => `line ${index + 1}`);
+
+    writeFileSync(path.join(repo, "long.txt"), `${lines.join("\n")}\n`);
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+    lines[4] = "LINE 5";
+    lines[149] = "LINE 150";
+    writeFileSync(path.join(repo, "long.txt"), `${lines.join("\n")}\n`);
+
+    const diff = await loadReviewDiff(gitBackend, repo, { kind: "bucket", bucket: "unstaged" });
+    const comment = createReviewComment({
+      repoPath: repo,
+      filePath: "long.txt",
+      bucket: "unstaged",
+      range: { start: 150, end: 150 },
+      text: "Keep this lowercase.",
+    });
+    const full = buildFixRequest(repo, [comment!], diff);
+    const maxPatchBytes = full!.patch.length - 1;
+    const request = buildFixRequest(repo, [comment!], diff, { maxPatchBytes });
+
+    expect(full?.patch).toContain("+LINE 5\n");
+    expect(request?.patch.length).toBeLessThanOrEqual(maxPatchBytes);
+    expect(request?.patch).toContain("+LINE 150\n");
+    expect(request?.patch).not.toContain("LINE 5\n");
+    expect(request?.prompt).toContain("only keeps the hunks nearest each comment");
+
+    git(repo, ["checkout", "--", "long.txt"]);
+    writeFileSync(patchPath, request!.patch);
+    expect(git(repo, ["apply", "--check", patchPath])).toBe("");
+  });
+
+  test("reports common commit and read failures with a specific error kind", async () => {
+    const repo = makeRepo();
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\n");
+    writeFileSync(path.join(repo, "image.bin"), Buffer.from([0x89, 0x50, 0x4e, 0x47, 0, 1]));
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    await expect(commitStaged(repo, "empty")).rejects.toMatchObject({ kind: "nothing-to-commit" });
+    await expect(checkoutFileFromRef(repo, "missing.txt", "HEAD")).rejects.toBeInstanceOf(
+      GitError,
+    );
+    await expect(checkoutFileFromRef(repo, "missing.txt", "HEAD")).rejects.toMatchObject({
+      kind: "path-not-found",
+    });
+    await expect(
+      getRepoFile({ repoPath: repo, relPath: "image.bin", revision: "HEAD", maxBytes: 64 }),
+    ).rejects.toMatchObject({ kind: "binary-file" });
+
+    git(repo, ["checkout", "-b", "theirs"]);
+    writeFileSync(path.join(repo, "tracked.txt"), "theirs\n");
+    git(repo, ["commit", "-am", "theirs"]);
+    git(repo, ["checkout", "-"]);
+    writeFileSync(path.join(repo, "tracked.txt"), "ours\n");
+    git(repo, ["commit", "-am", "ours"]);
+    expect(() => git(repo, ["merge", "theirs"])).toThrow();
+
+    await expect(commitStaged(repo, "merge")).rejects.toMatchObject({ kind: "merge-conflict" });
+  });
+
   test("handles deleted files, multi-file discard, and discardAll", async () => {
     const repo = makeRepo();
 
@@ -284,6 +1352,67 @@ describe("electron git backend", () => {
     expect(existsSync(path.join(repo, "temp.txt"))).toBe(false);
   });
 
+  test("refuses to stage or discard protected paths", async () => {
+    const repo = makeRepo();
+    const protectedPaths = ["dist"];
+
+    writeFileSync(path.join(repo, "tracked.txt"), "one\n");
+    git(repo, ["add", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+
+    mkdirSync(path.join(repo, "dist"));
+    writeFileSync(path.join(repo, "dist", "bundle.js"), "built\n");
+    writeFileSync(path.join(repo, "tracked.txt"), "two\n");
+
+    await expect(stageFile(repo, "dist/bundle.js", protectedPaths)).rejects.toThrow(
+      "dist/bundle.js is a protected path",
+    );
+    await expect(
+      discardFile(repo, "dist/bundle.js", "untracked", protectedPaths),
+    ).rejects.toThrow("dist/bundle.js is a protected path");
+    await expect(
+      updateWorktreeFileContents(repo, "dist/bundle.js", "edited\n", protectedPaths),
+    ).rejects.toThrow("dist/bundle.js is a protected path");
+    await stageFile(repo, "tracked.txt", protectedPaths);
+
+    let snapshot = await getGitSnapshot(repo);
+    expect(snapshot.staged.map((file) => file.path)).toEqual(["tracked.txt"]);
+
+    await stageAll(repo, protectedPaths);
+    snapshot = await getGitSnapshot(repo);
+    expect(snapshot.staged.map((file) => file.path)).toEqual(["tracked.txt"]);
+
+    await discardAll(repo, protectedPaths);
+    expect(readFileSync(path.join(repo, "tracked.txt"), "utf8")).toEqual("one\n");
+    expect(readFileSync(path.join(repo, "dist", "bundle.js"), "utf8")).toEqual("built\n");
+  });
+
+  test("discards unstaged changes while keeping staged hunks", async () => {
+    const repo = makeRepo();
+    const lines = ["one", "two", "three", "four", "five", "six", "seven", "eight"];
+
+    writeFileSync(path.join(repo, "tracked.txt"), `${lines.join("\n")}\n`);
+    git(repo, ["add", "tracked.txt"]);
+    git(repo, ["commit", "-m", "init"]);
+
+    const staged = ["ONE", ...lines.slice(1)];
+    writeFileSync(path.join(repo, "tracked.txt"), `${staged.join("\n")}\n`);
+    git(repo, ["add", "tracked.txt"]);
+    const unstaged = [...staged.slice(0, -1), "EIGHT"];
+    writeFileSync(path.join(repo, "tracked.txt"), `${unstaged.join("\n")}\n`);
+    writeFileSync(path.join(repo, "scratch.txt"), "scratch\n");
+
+    await discardUnstaged(repo);
+
+    expect(readFileSync(path.join(repo, "tracked.txt"), "utf8")).toBe(`${staged.join("\n")}\n`);
+    expect(existsSync(path.join(repo, "scratch.txt"))).toBe(false);
+    const snapshot = await getGitSnapshot(repo);
+    expect(snapshot.staged.map((file) => file.path)).toEqual(["tracked.txt"]);
+    expect(snapshot.unstaged).toEqual([]);
+    expect(snapshot.untracked).toEqual([]);
+    await expect(discardUnstaged(makeRepo())).resolves.toBeUndefined();
+  });
+
   test("discards staged files that only exist in the index", async () => {
     const repo = makeRepo();
 
@@ -303,6 +1432,39 @@ describe("electron git backend", () => {
     expect(existsSync(path.join(repo, "added.txt"))).toBe(false);
   });
 
+  test("restores deleted tracked files from the index or HEAD", async () => {
+    const repo = makeRepo();
+    const binary = Buffer.from([0x89, 0x50, 0x4e, 0x47, 0x00, 0xff, 0xfe, 0x0a]);
+
+    writeFileSync(path.join(repo, "kept.txt"), "committed\n");
+    writeFileSync(path.join(repo, "removed.txt"), "committed\n");
+    writeFileSync(path.join(repo, "logo.png"), binary);
+    git(repo, ["add", "."]);
+    git(repo, ["commit", "-m", "init"]);
+
+    writeFileSync(path.join(repo, "kept.txt"), "staged edit\n");
+    git(repo, ["add", "kept.txt"]);
+    unlinkSync(path.join(repo, "kept.txt"));
+    git(repo, ["rm", "-q", "removed.txt", "logo.png"]);
+
+    let snapshot = await getGitSnapshot(repo);
+    expect(snapshot.unstaged).toMatchObject([{ path: "kept.txt", status: "deleted" }]);
+    expect(snapshot.staged).toContainEqual(
+      expect.objectContaining({ path: "removed.txt", status: "deleted" }),
+    );
+
+    await discardFile(repo, "kept.txt", "unstaged");
+    await checkoutFileFromRef(repo, "removed.txt", "HEAD", true);
+    await checkoutFileFromRef(repo, "logo.png", "HEAD", true);
+
+    expect(readFileSync(path.join(repo, "kept.txt"), "utf8")).toBe("staged edit\n");
+    expect(readFileSync(path.join(repo, "removed.txt"), "utf8")).toBe("committed\n");
+    expect(readFileSync(path.join(repo, "logo.png"))).toEqual(binary);
+    snapshot = await getGitSnapshot(repo);
+    expect(snapshot.unstaged).toEqual([]);
+    expect(snapshot.staged.map((file) => file.path)).toEqual(["kept.txt"]);
+  });
+
   test("falls back to staged discard when an unstaged conflict path is unmerged", async () => {
     const repo = makeRepo();
     const defaultBranch = git(repo, ["branch", "--show-current"]);
@@ -350,4 +1512,29 @@ describe("electron git backend", () => {
       process.env.PATH = previousPath;
     }
   });
+
+  test("logs a warning when a git command fails", async () => {
+    const dir = mkdtempSync(path.join(os.tmpdir(), "open-warden-not-a-repo-"));
+    tempDirs.push(dir);
+    const events: LogEvent[] = [];
+
+    setLogLevel("warn");
+    setLogSink((event) => events.push(event));
+
+    try {
+      await expect(getBranches(dir)).rejects.toThrow(/not a git repository/i);
+    } finally {
+      setLogSink(null);
+      setLogLevel("error");
+    }
+
+    expect(events).toEqual([
+      expect.objectContaining({
+        level: "warn",
+        scope: "git",
+        message: "git command failed",
+        fields: expect.objectContaining({ repoPath: dir, code: 128 }),
+      }),
+    ]);
+  });
 });
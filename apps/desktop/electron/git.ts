@@ -1,4 +1,4 @@
-import { execFile as nodeExecFile } from "node:child_process";
+import { execFile as nodeExecFile, spawn } from "node:child_process";
 import { promises as fs } from "node:fs";
 import os from "node:os";
 import path from "node:path";
@@ -6,14 +6,28 @@ import { promisify } from "node:util";
 
 import type {
   Bucket,
+  CommitStats,
   DiscardFileInput,
   FileItem,
   FileStatus,
   FileVersions,
   GitSnapshot,
   HistoryCommit,
+  IndexPreviewEntry,
+  RefResolution,
+  ReflogEntry,
+  RepoFile,
   RepoFileItem,
+  RepoFilesOptions,
+  PushStashOptions,
+  StashEntry,
+  StashSelector,
+  StashTarget,
+  WorkingCopySummary,
 } from "../src/platform/desktop/contracts";
+import { isWorktreeRef } from "../src/platform/desktop/gitRefs";
+import { isProtectedPath, protectedPathExcludes } from "../src/platform/desktop/protectedPaths";
+import { createLogger } from "./logger";
 
 const execFile = promisify(nodeExecFile);
 const textDecoder = new TextDecoder("utf-8", { fatal: true });
@@ -21,6 +35,9 @@ const MAX_BUFFER = 32 * 1024 * 1024;
 const GIT_TIMEOUT_MS = 30_000;
 const GIT_WRITE_RETRY_COUNT = 3;
 const GIT_WRITE_RETRY_DELAY_MS = 120;
+const log = createLogger("git");
+const HASH_OBJECT_BATCH_SIZE = 200;
+const STAT_BATCH_SIZE = 256;
 
 class GitCommandError extends Error {
   constructor(
@@ -33,6 +50,27 @@ class GitCommandError extends Error {
   }
 }
 
+export type GitErrorKind =
+  | "index-locked"
+  | "nothing-to-commit"
+  | "nothing-to-stash"
+  | "path-not-found"
+  | "merge-conflict"
+  | "binary-file"
+  | "no-commits";
+
+// Failures callers are expected to handle on their own terms. Anything else stays a plain Error
+// or a GitCommandError carrying git's stderr.
+export class GitError extends Error {
+  constructor(
+    readonly kind: GitErrorKind,
+    message: string,
+  ) {
+    super(message);
+    this.name = "GitError";
+  }
+}
+
 function ensureRepoPath(repoPath: string) {
   if (!repoPath.trim()) {
     throw new Error("repository path is empty");
@@ -61,13 +99,13 @@ function normalizeGitPath(relPath: string) {
 
 function decodeUtf8(buffer: Buffer, label: string) {
   if (buffer.includes(0)) {
-    throw new Error(`binary file is not supported: ${label}`);
+    throw new GitError("binary-file", `binary file is not supported: ${label}`);
   }
 
   try {
     return textDecoder.decode(buffer);
   } catch {
-    throw new Error(`binary file is not supported: ${label}`);
+    throw new GitError("binary-file", `binary file is not supported: ${label}`);
   }
 }
 
@@ -77,6 +115,7 @@ async function runGit(
   options?: { allowFailure?: boolean },
 ): Promise<Buffer> {
   ensureRepoPath(repoPath);
+  const startedAt = Date.now();
 
   try {
     const { stdout } = await execFile("git", args, {
@@ -87,34 +126,42 @@ async function runGit(
       killSignal: "SIGKILL",
     });
 
+    log.debug("git command finished", { repoPath, args, durationMs: Date.now() - startedAt });
     return Buffer.isBuffer(stdout) ? stdout : Buffer.from(stdout);
   } catch (error) {
-    if (!(error instanceof Error)) {
-      throw error;
-    }
-
-    const rawCode = "code" in error ? error.code : null;
-    if (rawCode === "ENOENT") {
-      throw new GitCommandError(args, "git is not installed or not available in PATH", null);
-    }
-
-    if ("killed" in error && error.killed) {
-      throw new GitCommandError(args, `git command timed out after ${GIT_TIMEOUT_MS}ms`, null);
-    }
+    const commandError = toGitCommandError(args, error);
+    // Expected failures (missing objects, probing refs) stay at debug so warn output is actionable.
+    log[options?.allowFailure ? "debug" : "warn"]("git command failed", {
+      repoPath,
+      args,
+      durationMs: Date.now() - startedAt,
+      code: commandError instanceof GitCommandError ? commandError.code : null,
+      stderr: commandError instanceof GitCommandError ? commandError.stderr : String(commandError),
+    });
+    throw commandError;
+  }
+}
 
-    const stderr = "stderr" in error ? String(error.stderr ?? "").trim() : error.message;
-    const code = typeof rawCode === "number" ? rawCode : null;
-    const commandError = new GitCommandError(args, stderr, code);
+function toGitCommandError(args: string[], error: unknown) {
+  if (!(error instanceof Error)) {
+    return error;
+  }
 
-    if (options?.allowFailure) {
-      throw commandError;
-    }
+  const rawCode = "code" in error ? error.code : null;
+  if (rawCode === "ENOENT") {
+    return new GitCommandError(args, "git is not installed or not available in PATH", null);
+  }
 
-    throw commandError;
+  if ("killed" in error && error.killed) {
+    return new GitCommandError(args, `git command timed out after ${GIT_TIMEOUT_MS}ms`, null);
   }
+
+  const stderr = "stderr" in error ? String(error.stderr ?? "").trim() : error.message;
+  const code = typeof rawCode === "number" ? rawCode : null;
+  return new GitCommandError(args, stderr, code);
 }
 
-function isGitLockError(error: unknown) {
+function isGitLockError(error: unknown): error is GitCommandError {
   if (!(error instanceof GitCommandError)) return false;
 
   const stderr = error.stderr.toLowerCase();
@@ -125,6 +172,17 @@ function isGitLockError(error: unknown) {
   );
 }
 
+function isUnmergedError(error: unknown): error is GitCommandError {
+  if (!(error instanceof GitCommandError)) return false;
+
+  const stderr = error.stderr.toLowerCase();
+  return (
+    stderr.includes("unmerged files") ||
+    stderr.includes("resolve your current index first") ||
+    stderr.includes("needs merge")
+  );
+}
+
 async function wait(ms: number) {
   await new Promise((resolve) => setTimeout(resolve, ms));
 }
@@ -138,13 +196,19 @@ async function runGitWrite(repoPath: string, args: string[]) {
       return;
     } catch (error) {
       const canRetry = isGitLockError(error) && attempt + 1 < GIT_WRITE_RETRY_COUNT;
-      if (!canRetry) throw error;
+      if (!canRetry) throw classifyWriteError(error);
       attempt += 1;
       await wait(GIT_WRITE_RETRY_DELAY_MS * attempt);
     }
   }
 }
 
+function classifyWriteError(error: unknown) {
+  if (isGitLockError(error)) return new GitError("index-locked", error.message);
+  if (isUnmergedError(error)) return new GitError("merge-conflict", error.message);
+  return error;
+}
+
 function splitNullTerminated(buffer: Buffer) {
   return buffer.toString("utf8").split("\0").filter(Boolean);
 }
@@ -274,6 +338,11 @@ function mapDiffStatus(code: string): FileStatus {
   }
 }
 
+function parseSimilarityScore(statusToken: string) {
+  const score = Number.parseInt(statusToken.slice(1), 10);
+  return Number.isInteger(score) && score >= 0 && score <= 100 ? score : null;
+}
+
 function parseNameStatusOutput(output: Buffer) {
   const entries = splitNullTerminated(output);
   const files: FileItem[] = [];
@@ -288,7 +357,10 @@ function parseNameStatusOutput(output: Buffer) {
       const previousPath = entries[index++] ?? "";
       const pathname = entries[index++] ?? "";
       if (!pathname) continue;
-      files.push(makeFileItem(pathname, status, previousPath || null));
+      files.push({
+        ...makeFileItem(pathname, status, previousPath || null),
+        similarity: parseSimilarityScore(statusToken),
+      });
       continue;
     }
 
@@ -306,17 +378,28 @@ async function resolveRepoRoot(repoPath: string) {
 }
 
 function parseHistoryOutput(output: Buffer) {
-  const entries = splitNullTerminated(output);
+  // Fields can be empty (root commits have no parents), so keep empty entries and only skip
+  // the record separators that precede each commit id.
+  const entries = output.toString("utf8").split("\0");
   const commits: HistoryCommit[] = [];
 
-  for (let index = 0; index + 4 < entries.length; index += 5) {
+  let index = 0;
+  while (index + 6 < entries.length) {
+    if (!entries[index]) {
+      index += 1;
+      continue;
+    }
+
     commits.push({
       commitId: entries[index] ?? "",
       shortId: entries[index + 1] ?? "",
       summary: entries[index + 2] ?? "",
-      author: entries[index + 3] ?? "Unknown",
+      author: entries[index + 3] || "Unknown",
       relativeTime: entries[index + 4] ?? "",
+      timestamp: Number(entries[index + 5]) || undefined,
+      parentIds: (entries[index + 6] ?? "").split(" ").filter(Boolean),
     });
+    index += 7;
   }
 
   return commits;
@@ -385,6 +468,197 @@ async function readWorktreeFile(repoPath: string, relPath: string, label: string
   };
 }
 
+// The git file mode of each side of a diff, so patches built from it keep executable bits and
+// symlinks. Null when the side does not exist.
+function parseEntryMode(output: Buffer, label: string) {
+  const mode = decodeUtf8(output, label).split("\n")[0]?.trim().split(/\s+/)[0];
+  return mode && /^\d{6}$/.test(mode) ? mode : null;
+}
+
+async function readTreeEntryMode(repoPath: string, treeish: string, relPath: string) {
+  try {
+    const output = await runGit(repoPath, ["ls-tree", treeish, "--", relPath], {
+      allowFailure: true,
+    });
+    return parseEntryMode(output, `${treeish} mode for ${relPath}`);
+  } catch (error) {
+    // ls-tree reports an unborn HEAD this way rather than as a missing object.
+    if (
+      isMissingGitObjectError(error) ||
+      (error instanceof GitCommandError && error.stderr.includes("Not a valid object name"))
+    ) {
+      return null;
+    }
+
+    throw error;
+  }
+}
+
+async function readIndexEntryMode(repoPath: string, relPath: string) {
+  const output = await runGit(repoPath, ["ls-files", "-s", "--", relPath]);
+  return parseEntryMode(output, `index mode for ${relPath}`);
+}
+
+async function readWorktreeMode(repoPath: string, relPath: string) {
+  try {
+    const stats = await fs.lstat(path.join(repoPath, relPath));
+    if (stats.isSymbolicLink()) return "120000";
+    if (!stats.isFile()) return null;
+    return stats.mode & 0o111 ? "100755" : "100644";
+  } catch {
+    return null;
+  }
+}
+
+// A byte limit can cut a multi-byte character in half; drop that partial tail so the strict
+// decoder only rejects content that is really binary.
+function decodeUtf8Prefix(buffer: Buffer, label: string) {
+  let end = buffer.length;
+  let continuationBytes = 0;
+  while (end > 0 && continuationBytes < 3 && (buffer[end - 1]! & 0xc0) === 0x80) {
+    end -= 1;
+    continuationBytes += 1;
+  }
+
+  const leadByte = end > 0 ? buffer[end - 1]! : 0;
+  if (leadByte >= 0xc0) {
+    const expectedBytes = leadByte >= 0xf0 ? 4 : leadByte >= 0xe0 ? 3 : 2;
+    end = continuationBytes + 1 < expectedBytes ? end - 1 : end + continuationBytes;
+  } else {
+    end += continuationBytes;
+  }
+
+  return decodeUtf8(buffer.subarray(0, end), label);
+}
+
+// Streams only the first `maxBytes` of a blob instead of buffering the whole object.
+async function readGitObjectHead(repoPath: string, spec: string, maxBytes: number) {
+  ensureRepoPath(repoPath);
+
+  return new Promise<Buffer>((resolve, reject) => {
+    const child = spawn("git", ["cat-file", "blob", spec], { cwd: repoPath });
+    const chunks: Buffer[] = [];
+    let length = 0;
+    let settled = false;
+
+    const finish = () => {
+      if (settled) return;
+      settled = true;
+      resolve(Buffer.concat(chunks, length).subarray(0, maxBytes));
+    };
+
+    child.stdout.on("data", (chunk: Buffer) => {
+      if (settled) return;
+      chunks.push(chunk);
+      length += chunk.length;
+      if (length >= maxBytes) {
+        finish();
+        child.kill();
+      }
+    });
+    child.on("error", (error) => {
+      if (settled) return;
+      settled = true;
+      reject(toGitCommandError(["cat-file", "blob", spec], error));
+    });
+    child.on("close", (code) => {
+      if (settled) return;
+      if (code === 0) {
+        finish();
+        return;
+      }
+      settled = true;
+      reject(new GitCommandError(["cat-file", "blob", spec], "", code));
+    });
+  });
+}
+
+async function readLimitedGitObject(
+  repoPath: string,
+  spec: string,
+  label: string,
+  maxBytes: number,
+): Promise<RepoFile | null> {
+  let size: number;
+  try {
+    const output = await runGit(repoPath, ["cat-file", "-s", spec], { allowFailure: true });
+    size = Number(decodeUtf8(output, "object size").trim());
+  } catch {
+    // Let the full read decide whether the object is missing or the lookup really failed.
+    return readGitObject(repoPath, spec, label);
+  }
+
+  if (size <= maxBytes) {
+    return readGitObject(repoPath, spec, label);
+  }
+
+  const head = await readGitObjectHead(repoPath, spec, maxBytes);
+  return { name: label, contents: decodeUtf8Prefix(head, label), truncated: true };
+}
+
+async function readLimitedWorktreeFile(
+  repoPath: string,
+  relPath: string,
+  label: string,
+  maxBytes: number,
+): Promise<RepoFile | null> {
+  const fullPath = path.join(repoPath, relPath);
+
+  let size: number;
+  try {
+    const stats = await fs.stat(fullPath);
+    if (!stats.isFile()) return null;
+    size = stats.size;
+  } catch {
+    return null;
+  }
+
+  if (size <= maxBytes) {
+    return readWorktreeFile(repoPath, relPath, label);
+  }
+
+  const handle = await fs.open(fullPath, "r");
+  try {
+    const buffer = Buffer.alloc(maxBytes);
+    const { bytesRead } = await handle.read(buffer, 0, maxBytes, 0);
+    return {
+      name: label,
+      contents: decodeUtf8Prefix(buffer.subarray(0, bytesRead), label),
+      truncated: true,
+    };
+  } finally {
+    await handle.close();
+  }
+}
+
+async function isSparseCheckout(repoPath: string) {
+  try {
+    const output = await runGit(repoPath, ["config", "--bool", "core.sparseCheckout"], {
+      allowFailure: true,
+    });
+    return decodeUtf8(output, "sparse checkout config").trim() === "true";
+  } catch {
+    return false;
+  }
+}
+
+async function readSparseExcludedPaths(repoPath: string): Promise<Set<string>> {
+  if (!(await isSparseCheckout(repoPath))) {
+    return new Set();
+  }
+
+  const output = await runGit(repoPath, ["ls-files", "-t", "-z", "--cached"]);
+  const excluded = new Set<string>();
+  for (const entry of splitNullTerminated(output)) {
+    // `S` marks skip-worktree entries, i.e. tracked paths outside the sparse cone.
+    if (entry.startsWith("S ")) {
+      excluded.add(entry.slice(2));
+    }
+  }
+
+  return excluded;
+}
+
 async function hasHeadCommit(repoPath: string) {
   try {
     await runGit(repoPath, ["rev-parse", "--verify", "HEAD"], { allowFailure: true });
@@ -480,16 +754,49 @@ async function discardFileForBucket(repoPath: string, relPath: string, bucket: B
   await removeWorktreePath(repoPath, relPath);
 }
 
-async function readCommitParent(repoPath: string, commitId: string) {
+async function readCommitParents(repoPath: string, commitId: string) {
   const output = await runGit(repoPath, ["show", "-s", "--format=%P", commitId]);
-  const firstParent = decodeUtf8(output, "commit parents").trim().split(/\s+/).find(Boolean);
+  return decodeUtf8(output, "commit parents").trim().split(/\s+/).filter(Boolean);
+}
+
+async function readCommitParent(repoPath: string, commitId: string, parentIndex = 0) {
+  const parents = await readCommitParents(repoPath, commitId);
+  if (parents.length === 0) {
+    return null;
+  }
 
-  return firstParent ?? null;
+  const parent = parents[parentIndex];
+  if (!parent) {
+    throw new Error(`commit ${commitId.slice(0, 7)} has no parent ${parentIndex + 1}`);
+  }
+
+  return parent;
+}
+
+// Submodules are declared in .gitmodules; git exits non-zero when there is none or it is empty.
+async function readSubmodulePaths(repoRoot: string): Promise<string[]> {
+  try {
+    const output = await runGit(
+      repoRoot,
+      ["config", "--file", ".gitmodules", "--null", "--get-regexp", "^submodule\\..*\\.path$"],
+      { allowFailure: true },
+    );
+    return output
+      .toString("utf8")
+      .split("\0")
+      .filter(Boolean)
+      .map((record) => normalizeGitPath(record.slice(record.indexOf("\n") + 1)));
+  } catch {
+    return [];
+  }
 }
 
 export async function getGitSnapshot(repoPath: string): Promise<GitSnapshot> {
-  const [repoRoot, statusOutput] = await Promise.all([
-    resolveRepoRoot(repoPath),
+  const [{ repoRoot, submodulePaths }, statusOutput] = await Promise.all([
+    resolveRepoRoot(repoPath).then(async (root) => ({
+      repoRoot: root,
+      submodulePaths: await readSubmodulePaths(root),
+    })),
     runGit(repoPath, ["status", "--porcelain=v1", "-z", "-b", "-uall"]),
   ]);
   const parsed = parseStatusOutput(statusOutput);
@@ -500,40 +807,272 @@ export async function getGitSnapshot(repoPath: string): Promise<GitSnapshot> {
     unstaged: parsed.unstaged,
     staged: parsed.staged,
     untracked: parsed.untracked,
+    submodulePaths,
   };
 }
 
-export async function getRepoFiles(repoPath: string): Promise<RepoFileItem[]> {
+function parseWorkingCopySummary(output: Buffer): WorkingCopySummary {
+  const summary: WorkingCopySummary = {
+    branch: null,
+    upstream: null,
+    ahead: 0,
+    behind: 0,
+    dirtyCount: 0,
+  };
+  const tokens = output.toString("utf8").split("\0");
+
+  for (let index = 0; index < tokens.length; index += 1) {
+    const token = tokens[index]!;
+    if (!token) continue;
+
+    if (token.startsWith("# branch.head ")) {
+      const head = token.slice("# branch.head ".length);
+      summary.branch = head === "(detached)" ? null : head;
+    } else if (token.startsWith("# branch.upstream ")) {
+      summary.upstream = token.slice("# branch.upstream ".length);
+    } else if (token.startsWith("# branch.ab ")) {
+      const match = /^\+(\d+) -(\d+)$/.exec(token.slice("# branch.ab ".length));
+      summary.ahead = Number(match?.[1] ?? 0);
+      summary.behind = Number(match?.[2] ?? 0);
+    } else if (!token.startsWith("#")) {
+      summary.dirtyCount += 1;
+      // Rename and copy records carry the original path as an extra NUL-separated field.
+      if (token.startsWith("2 ")) index += 1;
+    }
+  }
+
+  return summary;
+}
+
+export async function getWorkingCopySummary(repoPath: string): Promise<WorkingCopySummary> {
+  const output = await runGit(repoPath, ["status", "--porcelain=v2", "--branch", "-z", "-uall"]);
+  return parseWorkingCopySummary(output);
+}
+
+export async function getChangedFileHashes(repoPath: string): Promise<Record<string, string>> {
+  const statusOutput = await runGit(repoPath, ["status", "--porcelain=v1", "-z", "-uall"]);
+  const parsed = parseStatusOutput(statusOutput);
+  const changedPaths = new Set(
+    [...parsed.unstaged, ...parsed.staged, ...parsed.untracked].map((file) => file.path),
+  );
+  const hashes: Record<string, string> = {};
+  const hashablePaths: string[] = [];
+
+  for (const relPath of changedPaths) {
+    try {
+      const stats = await fs.stat(path.join(repoPath, relPath));
+      if (stats.isFile()) {
+        hashablePaths.push(relPath);
+        continue;
+      }
+    } catch {
+      // Missing from the worktree; recorded as deleted below.
+    }
+    hashes[relPath] = "deleted";
+  }
+
+  for (let index = 0; index < hashablePaths.length; index += HASH_OBJECT_BATCH_SIZE) {
+    const batch = hashablePaths.slice(index, index + HASH_OBJECT_BATCH_SIZE);
+    const output = await runGit(repoPath, ["hash-object", "--", ...batch]);
+    const objectIds = decodeUtf8(output, "hash-object output").trim().split("\n");
+    batch.forEach((relPath, batchIndex) => {
+      hashes[relPath] = objectIds[batchIndex] ?? "";
+    });
+  }
+
+  return hashes;
+}
+
+async function readIgnoredRepoFiles(repoPath: string): Promise<RepoFileItem[]> {
   const output = await runGit(repoPath, [
     "ls-files",
     "-z",
-    "--cached",
     "--others",
+    "--ignored",
     "--exclude-standard",
   ]);
+  return parseRepoFilesOutput(output).map((file) => ({ ...file, ignored: true }));
+}
+
+export async function getRepoFiles(
+  repoPath: string,
+  options: RepoFilesOptions = {},
+): Promise<RepoFileItem[]> {
+  const [output, sparseExcludedPaths, ignoredFiles] = await Promise.all([
+    runGit(repoPath, ["ls-files", "-z", "--cached", "--others", "--exclude-standard"]),
+    readSparseExcludedPaths(repoPath),
+    options.includeIgnored ? readIgnoredRepoFiles(repoPath) : Promise.resolve([]),
+  ]);
+  const files = parseRepoFilesOutput(output);
+  const markedFiles =
+    sparseExcludedPaths.size === 0
+      ? files
+      : files.map((file) =>
+          sparseExcludedPaths.has(file.path) ? { ...file, outsideSparseCone: true } : file,
+        );
+  if (ignoredFiles.length === 0) {
+    return markedFiles;
+  }
+
+  return [...markedFiles, ...ignoredFiles].toSorted((a, b) =>
+    a.path.localeCompare(b.path, undefined, { numeric: true, sensitivity: "base" }),
+  );
+}
+
+export async function isPathIgnored(repoPath: string, relPath: string): Promise<boolean> {
+  try {
+    await runGit(repoPath, ["check-ignore", "-q", "--", normalizeGitPath(relPath)]);
+    return true;
+  } catch (error) {
+    // check-ignore exits with 1 when the path is not ignored.
+    if (error instanceof GitCommandError && error.code === 1) {
+      return false;
+    }
+    throw error;
+  }
+}
 
-  return parseRepoFilesOutput(output);
+// Tracked files only: `ls-files --cached` leaves out untracked and ignored ones. Ordered by
+// worktree mtime, newest first; files deleted from the worktree are skipped.
+export async function getRecentlyModifiedFiles(repoPath: string, limit = 20): Promise<string[]> {
+  if (limit <= 0) return [];
+
+  const output = await runGit(repoPath, ["ls-files", "-z", "--cached"]);
+  // Unmerged paths are listed once per stage.
+  const relPaths = [...new Set(splitNullTerminated(output))];
+  const modified: Array<{ relPath: string; mtimeMs: number }> = [];
+
+  for (let index = 0; index < relPaths.length; index += STAT_BATCH_SIZE) {
+    const batch = relPaths.slice(index, index + STAT_BATCH_SIZE);
+    const stats = await Promise.all(
+      batch.map((relPath) => fs.stat(path.join(repoPath, relPath)).catch(() => null)),
+    );
+    batch.forEach((relPath, batchIndex) => {
+      const fileStats = stats[batchIndex];
+      if (fileStats?.isFile()) {
+        modified.push({ relPath, mtimeMs: fileStats.mtimeMs });
+      }
+    });
+  }
+
+  return modified
+    .toSorted((a, b) => b.mtimeMs - a.mtimeMs || a.relPath.localeCompare(b.relPath))
+    .slice(0, limit)
+    .map((file) => file.relPath);
 }
 
-export async function getCommitHistory(repoPath: string, limit = 200): Promise<HistoryCommit[]> {
+export async function getCommitHistory(
+  repoPath: string,
+  limit = 200,
+  skip = 0,
+): Promise<HistoryCommit[]> {
   const normalizedLimit = limit > 0 ? String(limit) : "1";
   const output = await runGit(repoPath, [
     "log",
     "-z",
-    "--format=%H%x00%h%x00%s%x00%an%x00%ar%x00",
+    "--format=%H%x00%h%x00%s%x00%an%x00%ar%x00%at%x00%P%x00",
     "-n",
     normalizedLimit,
+    ...(skip > 0 ? [`--skip=${Math.floor(skip)}`] : []),
   ]);
 
   return parseHistoryOutput(output);
 }
 
+function parseNumstatOutput(output: Buffer): CommitStats {
+  const stats: CommitStats = { insertions: 0, deletions: 0, files: 0 };
+
+  for (const line of decodeUtf8(output, "commit stats").split("\n")) {
+    const [added, removed] = line.split("\t");
+    if (added === undefined || removed === undefined) continue;
+
+    stats.files += 1;
+    // Binary files report "-" for both counts.
+    stats.insertions += Number.parseInt(added, 10) || 0;
+    stats.deletions += Number.parseInt(removed, 10) || 0;
+  }
+
+  return stats;
+}
+
+function parseReflogRelativeTime(selector: string) {
+  const start = selector.lastIndexOf("@{");
+  return start >= 0 && selector.endsWith("}") ? selector.slice(start + 2, -1) : "";
+}
+
+export async function getReflog(
+  repoPath: string,
+  refName = "HEAD",
+  limit = 100,
+): Promise<ReflogEntry[]> {
+  const normalizedRef = refName.trim();
+  if (!normalizedRef || normalizedRef.startsWith("-")) {
+    throw new Error(`invalid ref: ${refName}`);
+  }
+
+  const normalizedLimit = limit > 0 ? limit : 1;
+  // Read one extra entry so the oldest returned entry still knows where it moved from.
+  const output = await runGit(repoPath, [
+    "log",
+    "-g",
+    "-z",
+    "--date=relative",
+    "--format=%H%x00%gd%x00%gn%x00%gs%x00",
+    "-n",
+    String(normalizedLimit + 1),
+    normalizedRef,
+    "--",
+  ]);
+  const fields = output.toString("utf8").split("\0");
+  const records: Array<Omit<ReflogEntry, "selector" | "oldId">> = [];
+
+  let index = 0;
+  while (index + 3 < fields.length) {
+    if (!fields[index]) {
+      index += 1;
+      continue;
+    }
+
+    records.push({
+      newId: fields[index] ?? "",
+      relativeTime: parseReflogRelativeTime(fields[index + 1] ?? ""),
+      committer: fields[index + 2] || "Unknown",
+      message: fields[index + 3] ?? "",
+    });
+    index += 4;
+  }
+
+  return records.slice(0, normalizedLimit).map((record, position) => ({
+    ...record,
+    selector: `${normalizedRef}@{${position}}`,
+    oldId: records[position + 1]?.newId ?? null,
+  }));
+}
+
+export async function getCommitStats(repoPath: string, commitId: string): Promise<CommitStats> {
+  const parentId = await readCommitParent(repoPath, commitId);
+  const output = parentId
+    ? await runGit(repoPath, ["diff", "--numstat", "--no-renames", parentId, commitId])
+    : await runGit(repoPath, [
+        "diff-tree",
+        "--root",
+        "-r",
+        "--numstat",
+        "--no-renames",
+        "--no-commit-id",
+        commitId,
+      ]);
+
+  return parseNumstatOutput(output);
+}
+
 export async function getBranches(repoPath: string): Promise<string[]> {
   const output = await runGit(repoPath, [
     "for-each-ref",
     "--format=%(refname:short)",
     "refs/heads",
     "refs/remotes",
+    "refs/tags",
   ]);
   const branches = decodeUtf8(output, "branches")
     .split("\n")
@@ -544,11 +1083,62 @@ export async function getBranches(repoPath: string): Promise<string[]> {
   return [...new Set(branches)].toSorted((a, b) => a.localeCompare(b));
 }
 
+const DEFAULT_BRANCH_CANDIDATES = ["main", "master", "trunk"];
+
+async function hasRef(repoPath: string, ref: string) {
+  try {
+    await runGit(repoPath, ["rev-parse", "--verify", "--quiet", ref]);
+    return true;
+  } catch (error) {
+    if (error instanceof GitCommandError && error.code === 1) {
+      return false;
+    }
+    throw error;
+  }
+}
+
+async function readRemoteHeadBranch(repoPath: string) {
+  try {
+    const output = await runGit(repoPath, ["symbolic-ref", "--quiet", "refs/remotes/origin/HEAD"]);
+    const target = decodeUtf8(output, "origin HEAD").trim();
+    return target.startsWith("refs/remotes/origin/")
+      ? target.slice("refs/remotes/origin/".length)
+      : null;
+  } catch (error) {
+    // symbolic-ref exits with 1 when origin/HEAD is missing or not a symref.
+    if (error instanceof GitCommandError && error.code === 1) {
+      return null;
+    }
+    throw error;
+  }
+}
+
+export async function getDefaultBranch(repoPath: string): Promise<string | null> {
+  const remoteHead = await readRemoteHeadBranch(repoPath);
+  if (remoteHead) {
+    return (await hasRef(repoPath, `refs/heads/${remoteHead}`))
+      ? remoteHead
+      : `origin/${remoteHead}`;
+  }
+
+  for (const candidate of DEFAULT_BRANCH_CANDIDATES) {
+    if (await hasRef(repoPath, `refs/heads/${candidate}`)) {
+      return candidate;
+    }
+  }
+
+  return null;
+}
+
 export async function getBranchFiles(
   repoPath: string,
   baseRef: string,
   headRef: string,
 ): Promise<FileItem[]> {
+  if (isWorktreeRef(headRef)) {
+    return getWorktreeFilesAgainstRef(repoPath, baseRef);
+  }
+
   const output = await runGit(repoPath, [
     "diff",
     "--name-status",
@@ -562,7 +1152,31 @@ export async function getBranchFiles(
   return parseNameStatusOutput(output);
 }
 
-export async function getCommitFiles(repoPath: string, commitId: string): Promise<FileItem[]> {
+async function getWorktreeFilesAgainstRef(repoPath: string, baseRef: string) {
+  const [diffOutput, untrackedOutput] = await Promise.all([
+    runGit(repoPath, ["diff", "--name-status", "-z", "--find-renames", baseRef]),
+    runGit(repoPath, ["ls-files", "--others", "--exclude-standard", "-z"]),
+  ]);
+  const files = parseNameStatusOutput(diffOutput);
+  const knownPaths = new Set(files.map((file) => file.path));
+
+  for (const pathname of splitNullTerminated(untrackedOutput)) {
+    const normalizedPath = normalizeGitPath(pathname);
+    if (!knownPaths.has(normalizedPath)) {
+      files.push(makeFileItem(normalizedPath, "untracked"));
+    }
+  }
+
+  return sortFiles(files);
+}
+
+export async function getCommitFiles(
+  repoPath: string,
+  commitId: string,
+  parentIndex = 0,
+): Promise<FileItem[]> {
+  // Diff against an explicit parent so merge commits show a real diff instead of nothing.
+  const parentId = await readCommitParent(repoPath, commitId, parentIndex);
   const output = await runGit(repoPath, [
     "diff-tree",
     "--root",
@@ -572,6 +1186,7 @@ export async function getCommitFiles(repoPath: string, commitId: string): Promis
     "-z",
     "--find-renames",
     "--find-copies",
+    ...(parentId ? [parentId] : []),
     commitId,
   ]);
 
@@ -583,17 +1198,20 @@ export async function getCommitFileVersions(
   commitId: string,
   relPath: string,
   previousPath?: string,
+  parentIndex = 0,
 ): Promise<FileVersions> {
   const normalizedPath = normalizeGitPath(relPath);
   const previousLookupPath = normalizeGitPath(previousPath ?? relPath);
-  const parent = await readCommitParent(repoPath, commitId);
+  const parent = await readCommitParent(repoPath, commitId, parentIndex);
 
-  const [oldFile, newFile] = await Promise.all([
+  const [oldFile, newFile, oldMode, newMode] = await Promise.all([
     parent ? readGitObject(repoPath, `${parent}:${previousLookupPath}`, previousLookupPath) : null,
     readGitObject(repoPath, `${commitId}:${normalizedPath}`, normalizedPath),
+    parent ? readTreeEntryMode(repoPath, parent, previousLookupPath) : null,
+    readTreeEntryMode(repoPath, commitId, normalizedPath),
   ]);
 
-  return { oldFile, newFile };
+  return { oldFile, newFile, oldMode, newMode };
 }
 
 export async function getFileVersions(
@@ -604,46 +1222,117 @@ export async function getFileVersions(
   const normalizedPath = normalizeGitPath(relPath);
 
   if (bucket === "unstaged") {
-    const [oldFile, newFile] = await Promise.all([
+    const [oldFile, newFile, oldMode, newMode] = await Promise.all([
       readGitObject(repoPath, `:${normalizedPath}`, normalizedPath),
       readWorktreeFile(repoPath, normalizedPath, normalizedPath),
+      readIndexEntryMode(repoPath, normalizedPath),
+      readWorktreeMode(repoPath, normalizedPath),
     ]);
 
-    return { oldFile, newFile };
+    if (!newFile && (await readSparseExcludedPaths(repoPath)).has(normalizedPath)) {
+      return { oldFile, newFile, oldMode, newMode, outsideSparseCone: true };
+    }
+
+    return { oldFile, newFile, oldMode, newMode };
   }
 
   if (bucket === "staged") {
-    const [oldFile, newFile] = await Promise.all([
+    const [oldFile, newFile, oldMode, newMode] = await Promise.all([
       readGitObject(repoPath, `HEAD:${normalizedPath}`, normalizedPath),
       readGitObject(repoPath, `:${normalizedPath}`, normalizedPath),
+      readTreeEntryMode(repoPath, "HEAD", normalizedPath),
+      readIndexEntryMode(repoPath, normalizedPath),
     ]);
 
-    return { oldFile, newFile };
+    return { oldFile, newFile, oldMode, newMode };
+  }
+
+  const [newFile, newMode] = await Promise.all([
+    readWorktreeFile(repoPath, normalizedPath, normalizedPath),
+    readWorktreeMode(repoPath, normalizedPath),
+  ]);
+  return { oldFile: null, newFile, oldMode: null, newMode };
+}
+
+async function readGitObjectBuffer(repoPath: string, spec: string): Promise<Buffer | null> {
+  try {
+    return await runGit(repoPath, ["show", "--no-ext-diff", "--no-textconv", spec], {
+      allowFailure: true,
+    });
+  } catch (error) {
+    if (isMissingGitObjectError(error)) {
+      return null;
+    }
+
+    throw error;
+  }
+}
+
+async function readWorktreeFileBuffer(repoPath: string, relPath: string) {
+  try {
+    return await fs.readFile(path.join(repoPath, relPath));
+  } catch {
+    return null;
+  }
+}
+
+// Raw bytes for both sides of a working-tree diff, so binary files can go to external tools.
+export async function getFileVersionBuffers(
+  repoPath: string,
+  relPath: string,
+  bucket: Bucket,
+): Promise<{ oldContents: Buffer | null; newContents: Buffer | null }> {
+  const normalizedPath = normalizeGitPath(relPath);
+
+  if (bucket === "unstaged") {
+    const [oldContents, newContents] = await Promise.all([
+      readGitObjectBuffer(repoPath, `:${normalizedPath}`),
+      readWorktreeFileBuffer(repoPath, normalizedPath),
+    ]);
+    return { oldContents, newContents };
+  }
+
+  if (bucket === "staged") {
+    const [oldContents, newContents] = await Promise.all([
+      readGitObjectBuffer(repoPath, `HEAD:${normalizedPath}`),
+      readGitObjectBuffer(repoPath, `:${normalizedPath}`),
+    ]);
+    return { oldContents, newContents };
   }
 
   return {
-    oldFile: null,
-    newFile: await readWorktreeFile(repoPath, normalizedPath, normalizedPath),
+    oldContents: null,
+    newContents: await readWorktreeFileBuffer(repoPath, normalizedPath),
   };
 }
 
+// Without `maxBytes` the whole file is read; with it, larger files come back cut at that many
+// bytes and flagged `truncated`. Binary content is rejected either way.
 export async function getRepoFile({
   repoPath,
   relPath,
   revision,
+  maxBytes,
 }: {
   repoPath: string;
   relPath: string;
   revision?: string | null;
-}) {
+  maxBytes?: number;
+}): Promise<RepoFile | null> {
   const normalizedPath = normalizeGitPath(relPath);
   const normalizedRevision = revision?.trim();
+  const byteLimit = maxBytes && maxBytes > 0 ? Math.floor(maxBytes) : null;
 
   if (normalizedRevision) {
-    return readGitObject(repoPath, `${normalizedRevision}:${normalizedPath}`, normalizedPath);
+    const spec = `${normalizedRevision}:${normalizedPath}`;
+    return byteLimit
+      ? readLimitedGitObject(repoPath, spec, normalizedPath, byteLimit)
+      : readGitObject(repoPath, spec, normalizedPath);
   }
 
-  return readWorktreeFile(repoPath, normalizedPath, normalizedPath);
+  return byteLimit
+    ? readLimitedWorktreeFile(repoPath, normalizedPath, normalizedPath, byteLimit)
+    : readWorktreeFile(repoPath, normalizedPath, normalizedPath);
 }
 
 export async function getBranchFileVersions(
@@ -658,14 +1347,28 @@ export async function getBranchFileVersions(
 
   const [oldFile, newFile] = await Promise.all([
     readGitObject(repoPath, `${baseRef}:${previousLookupPath}`, previousLookupPath),
-    readGitObject(repoPath, `${headRef}:${normalizedPath}`, normalizedPath),
+    isWorktreeRef(headRef)
+      ? readWorktreeFile(repoPath, normalizedPath, normalizedPath)
+      : readGitObject(repoPath, `${headRef}:${normalizedPath}`, normalizedPath),
   ]);
 
   return { oldFile, newFile };
 }
 
-export async function stageFile(repoPath: string, relPath: string) {
-  await runGitWrite(repoPath, ["add", "--", normalizeGitPath(relPath)]);
+function assertPathNotProtected(relPath: string, protectedPaths: readonly string[]) {
+  if (isProtectedPath(relPath, protectedPaths)) {
+    throw new Error(`${relPath} is a protected path and can't be staged or discarded`);
+  }
+}
+
+export async function stageFile(
+  repoPath: string,
+  relPath: string,
+  protectedPaths: readonly string[] = [],
+) {
+  const normalizedPath = normalizeGitPath(relPath);
+  assertPathNotProtected(normalizedPath, protectedPaths);
+  await runGitWrite(repoPath, ["add", "--", normalizedPath]);
 }
 
 export async function unstageFile(repoPath: string, relPath: string) {
@@ -689,8 +1392,14 @@ async function readIndexMode(repoPath: string, relPath: string) {
   }
 }
 
-export async function updateIndexFileContents(repoPath: string, relPath: string, contents: string) {
+export async function updateIndexFileContents(
+  repoPath: string,
+  relPath: string,
+  contents: string,
+  protectedPaths: readonly string[] = [],
+) {
   const normalizedPath = normalizeGitPath(relPath);
+  assertPathNotProtected(normalizedPath, protectedPaths);
   const mode = await readIndexMode(repoPath, normalizedPath);
   const tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "open-warden-index-"));
   const tempPath = path.join(tempDir, "contents");
@@ -720,23 +1429,393 @@ export async function updateWorktreeFileContents(
   repoPath: string,
   relPath: string,
   contents: string,
+  protectedPaths: readonly string[] = [],
 ) {
   const normalizedPath = normalizeGitPath(relPath);
+  assertPathNotProtected(normalizedPath, protectedPaths);
   const fullPath = path.join(repoPath, normalizedPath);
   await fs.mkdir(path.dirname(fullPath), { recursive: true });
   await fs.writeFile(fullPath, contents, "utf8");
 }
 
-export async function stageAll(repoPath: string) {
-  await runGitWrite(repoPath, ["add", "-A", "--", "."]);
+// Git ignores trailing whitespace on a .gitignore line unless it is backslash-escaped.
+function trimGitignoreLine(line: string) {
+  return line.trimStart().replace(/(?<!\\)\s+$/, "");
+}
+
+// Appends a pattern to the root .gitignore, creating it when absent. Returns false when present.
+export async function addGitignorePattern(repoPath: string, pattern: string): Promise<boolean> {
+  ensureRepoPath(repoPath);
+  const trimmedPattern = trimGitignoreLine(pattern);
+  if (!trimmedPattern || trimmedPattern.includes("\n")) {
+    throw new Error(`invalid .gitignore pattern: ${pattern}`);
+  }
+
+  const gitignorePath = path.join(repoPath, ".gitignore");
+  const existing = await fs.readFile(gitignorePath, "utf8").catch(() => "");
+  if (existing.split(/\r?\n/).some((line) => trimGitignoreLine(line) === trimmedPattern)) {
+    return false;
+  }
+
+  const separator = existing && !existing.endsWith("\n") ? "\n" : "";
+  await fs.writeFile(gitignorePath, `${existing}${separator}${trimmedPattern}\n`, "utf8");
+  return true;
+}
+
+export async function stageAll(repoPath: string, protectedPaths: readonly string[] = []) {
+  await runGitWrite(repoPath, ["add", "-A", "--", ".", ...protectedPathExcludes(protectedPaths)]);
 }
 
 export async function unstageAll(repoPath: string) {
   await runGitWrite(repoPath, ["reset"]);
 }
 
-export async function discardFile(repoPath: string, relPath: string, bucket: Bucket) {
+// A directory pathspec covers every tracked and untracked change beneath it in one git call.
+function normalizeGitDirectoryPath(dirRelPath: string) {
+  const normalizedPath = normalizeGitPath(dirRelPath).replace(/\/+$/, "");
+  if (!normalizedPath || normalizedPath === ".") {
+    throw new Error("directory must be below the repository root");
+  }
+  return normalizedPath;
+}
+
+export async function stageDirectory(
+  repoPath: string,
+  dirRelPath: string,
+  protectedPaths: readonly string[] = [],
+) {
+  const normalizedPath = normalizeGitDirectoryPath(dirRelPath);
+  await runGitWrite(repoPath, [
+    "add",
+    "-A",
+    "--",
+    normalizedPath,
+    ...protectedPathExcludes(protectedPaths),
+  ]);
+}
+
+export async function unstageDirectory(repoPath: string, dirRelPath: string) {
+  await runGitWrite(repoPath, ["reset", "--", normalizeGitDirectoryPath(dirRelPath)]);
+}
+
+function parseAddDryRunOutput(output: Buffer): IndexPreviewEntry[] {
+  const entries: IndexPreviewEntry[] = [];
+
+  for (const line of decodeUtf8(output, "stage preview").split("\n")) {
+    const match = /^(add|remove) '(.*)'$/.exec(line.trim());
+    if (!match) continue;
+    entries.push({ path: match[2]!, action: match[1] as "add" | "remove" });
+  }
+
+  return entries.toSorted((a, b) => a.path.localeCompare(b.path));
+}
+
+export async function previewStageAll(
+  repoPath: string,
+  protectedPaths: readonly string[] = [],
+): Promise<IndexPreviewEntry[]> {
+  // Same pathspec as stageAll, so the preview lists exactly what it would touch.
+  const output = await runGit(repoPath, [
+    "add",
+    "-A",
+    "--dry-run",
+    "--",
+    ".",
+    ...protectedPathExcludes(protectedPaths),
+  ]);
+  return parseAddDryRunOutput(output);
+}
+
+export async function previewUnstageAll(repoPath: string): Promise<IndexPreviewEntry[]> {
+  const output = await runGit(repoPath, ["diff", "--cached", "--name-only", "-z", "--no-renames"]);
+  return splitNullTerminated(output)
+    .map((pathname) => ({ path: normalizeGitPath(pathname), action: "unstage" as const }))
+    .toSorted((a, b) => a.path.localeCompare(b.path));
+}
+
+async function resolveCommitRef(repoPath: string, ref: string) {
+  const trimmedRef = ref.trim();
+  if (!trimmedRef || trimmedRef.startsWith("-")) {
+    throw new Error(`invalid ref: ${ref}`);
+  }
+
+  try {
+    const output = await runGit(repoPath, [
+      "rev-parse",
+      "--verify",
+      "--quiet",
+      `${trimmedRef}^{commit}`,
+    ]);
+    return decodeUtf8(output, "ref").trim();
+  } catch {
+    throw new Error(`unknown ref: ${ref}`);
+  }
+}
+
+// Commit metadata for a ref label, without computing any diff.
+export async function resolveRefInfo(repoPath: string, ref: string): Promise<RefResolution> {
+  const commitId = await resolveCommitRef(repoPath, ref);
+  const output = await runGit(repoPath, [
+    "show",
+    "-s",
+    "--no-color",
+    "--format=%H%x00%h%x00%an%x00%s",
+    commitId,
+  ]);
+  const fields = decodeUtf8(output, "ref info").trimEnd().split("\0");
+  const [fullId = commitId, shortId = "", author = "", summary = ""] = fields;
+
+  return { commitId: fullId, shortId, summary, author };
+}
+
+// Subject of the first parent, for "on top of" context; null for root commits.
+export async function getParentSummary(repoPath: string, commitId: string): Promise<string | null> {
+  const resolvedCommitId = await resolveCommitRef(repoPath, commitId);
+  const output = await runGit(repoPath, ["rev-list", "--parents", "-n", "1", resolvedCommitId]);
+  const [, parentId] = decodeUtf8(output, "commit parents").trim().split(" ");
+  if (!parentId) return null;
+
+  const summary = await runGit(repoPath, ["show", "-s", "--no-color", "--format=%s", parentId]);
+  return decodeUtf8(summary, "parent summary").trim();
+}
+
+export async function listStashes(repoPath: string): Promise<StashEntry[]> {
+  if (!(await hasRef(repoPath, "refs/stash"))) return [];
+
+  const output = await runGit(repoPath, [
+    "log",
+    "-g",
+    "-z",
+    "--format=%H%x00%gs%x00%an%x00%cr",
+    "refs/stash",
+    "--",
+  ]);
+  const fields = output.toString("utf8").split("\0");
+  const entries: StashEntry[] = [];
+  for (let index = 0; index + 3 < fields.length; index += 4) {
+    entries.push({
+      index: index / 4,
+      oid: fields[index]!,
+      message: fields[index + 1]!,
+      author: fields[index + 2]!,
+      relativeTime: fields[index + 3]!,
+    });
+  }
+  return entries;
+}
+
+// Stash indexes shift as stashes are pushed and dropped, so a message substring is the stable
+// handle. The newest matching stash wins.
+function matchStashMessage(stashes: StashEntry[], needle: string) {
+  const normalizedNeedle = needle.trim().toLowerCase();
+  if (!normalizedNeedle) return undefined;
+
+  return stashes.find((stash) => stash.message.toLowerCase().includes(normalizedNeedle));
+}
+
+export async function findStashByMessage(repoPath: string, needle: string): Promise<number | null> {
+  return matchStashMessage(await listStashes(repoPath), needle)?.index ?? null;
+}
+
+export async function resolveStash(repoPath: string, stash: StashSelector): Promise<StashTarget> {
+  const stashes = await listStashes(repoPath);
+  const entry = typeof stash === "number" ? stashes[stash] : matchStashMessage(stashes, stash);
+  if (!entry) {
+    throw new Error(`no stash matches ${JSON.stringify(stash)}`);
+  }
+
+  const baseId = await resolveCommitRef(repoPath, `${entry.oid}^1`);
+  return { ...entry, baseId };
+}
+
+export async function applyStash(repoPath: string, stash: StashSelector) {
+  const { index } = await resolveStash(repoPath, stash);
+  await runGitWrite(repoPath, ["stash", "apply", `stash@{${index}}`]);
+}
+
+async function stashTip(repoPath: string) {
+  return (await hasRef(repoPath, "refs/stash")) ? resolveCommitRef(repoPath, "refs/stash") : null;
+}
+
+export async function pushStash(repoPath: string, options: PushStashOptions = {}) {
+  const message = options.message?.trim();
+  const before = await stashTip(repoPath);
+  await runGitWrite(repoPath, [
+    "stash",
+    "push",
+    ...(options.includeUntracked ? ["--include-untracked"] : []),
+    ...(message ? ["-m", message] : []),
+  ]);
+
+  // git exits cleanly when there is nothing to save, so the stash ref tells whether it did.
+  if ((await stashTip(repoPath)) === before) {
+    throw new GitError("nothing-to-stash", "nothing to stash: no local changes");
+  }
+}
+
+// A pop that conflicts leaves the conflicted files in the working tree and keeps the stash.
+export async function popStash(repoPath: string, stash: StashSelector) {
+  const { index } = await resolveStash(repoPath, stash);
+  try {
+    await runGitWrite(repoPath, ["stash", "pop", `stash@{${index}}`]);
+  } catch (error) {
+    // An index that was already unmerged is reported as is; it is not this stash's conflict.
+    if (error instanceof GitError) throw error;
+    const output = await runGit(repoPath, ["diff", "--name-only", "-z", "--diff-filter=U"]);
+    const conflicted = splitNullTerminated(output);
+    if (conflicted.length > 0) {
+      throw new GitError(
+        "merge-conflict",
+        `stash@{${index}} conflicts with ${conflicted.join(", ")}; the stash was kept`,
+      );
+    }
+    throw error;
+  }
+}
+
+const BLAME_HEADER_PATTERN = /^([0-9a-f]{40}(?:[0-9a-f]{24})?) \d+ (\d+)/;
+
+// One commit id per line of the file, indexed by line number - 1. Without a revision the working
+// tree is blamed, and lines that are not committed yet get an all-zero id.
+export async function blameFile(
+  repoPath: string,
+  relPath: string,
+  revision?: string,
+): Promise<string[]> {
+  const gitPath = normalizeGitPath(relPath);
+  const commitId = revision ? await resolveCommitRef(repoPath, revision) : null;
+  const output = await runGit(repoPath, [
+    "blame",
+    "--porcelain",
+    ...(commitId ? [commitId] : []),
+    "--",
+    gitPath,
+  ]);
+
+  const lineCommits: string[] = [];
+  // Content lines start with a tab, so only headers can match; they need not be valid UTF-8.
+  for (const line of output.toString("utf8").split("\n")) {
+    const match = BLAME_HEADER_PATTERN.exec(line);
+    if (match) {
+      lineCommits[Number(match[2]) - 1] = match[1]!;
+    }
+  }
+  return lineCommits;
+}
+
+export async function formatCommitPatch(repoPath: string, commitId: string): Promise<string> {
+  const resolvedCommitId = await resolveCommitRef(repoPath, commitId);
+  // Binary changes collapse to git's "Binary files ... differ" notice instead of a base85 blob,
+  // which keeps the patch readable and parseable at the cost of applying those files.
+  const output = await runGit(repoPath, [
+    "format-patch",
+    "-1",
+    "--stdout",
+    "--no-color",
+    "--no-binary",
+    resolvedCommitId,
+  ]);
+  return decodeUtf8(output, "commit patch");
+}
+
+// Diffs two explicit blobs, so a rename with heavy edits still reads as a line-level change.
+export async function diffPathsBetweenRefs(
+  repoPath: string,
+  oldRef: string,
+  oldPath: string,
+  newRef: string,
+  newPath: string,
+): Promise<string> {
+  const normalizedOldPath = normalizeGitPath(oldPath);
+  const normalizedNewPath = normalizeGitPath(newPath);
+  const [oldCommitId, newCommitId] = await Promise.all([
+    resolveCommitRef(repoPath, oldRef),
+    resolveCommitRef(repoPath, newRef),
+  ]);
+
+  const [oldBlob, newBlob] = await Promise.all([
+    readGitObjectBuffer(repoPath, `${oldCommitId}:${normalizedOldPath}`),
+    readGitObjectBuffer(repoPath, `${newCommitId}:${normalizedNewPath}`),
+  ]);
+  if (!oldBlob) {
+    throw new GitError("path-not-found", `${normalizedOldPath} does not exist at ${oldRef}`);
+  }
+  if (!newBlob) {
+    throw new GitError("path-not-found", `${normalizedNewPath} does not exist at ${newRef}`);
+  }
+
+  const output = await runGit(repoPath, [
+    "diff",
+    "--no-ext-diff",
+    "--no-textconv",
+    "--no-color",
+    `${oldCommitId}:${normalizedOldPath}`,
+    `${newCommitId}:${normalizedNewPath}`,
+  ]);
+  return decodeUtf8(output, "path diff");
+}
+
+// Passes the paths as a pathspec so git only diffs those files instead of the whole working tree.
+export async function getWorkingTreeDiffForPaths(
+  repoPath: string,
+  staged: boolean,
+  relPaths: string[],
+): Promise<string> {
+  if (relPaths.length === 0) return "";
+
+  const output = await runGit(repoPath, [
+    "diff",
+    ...(staged ? ["--cached"] : []),
+    "--no-ext-diff",
+    "--no-textconv",
+    "--no-color",
+    "--",
+    ...relPaths.map(normalizeGitPath),
+  ]);
+  return decodeUtf8(output, "working tree diff");
+}
+
+export async function checkoutFileFromRef(
+  repoPath: string,
+  relPath: string,
+  ref: string,
+  stage = false,
+  protectedPaths: readonly string[] = [],
+) {
   const normalizedPath = normalizeGitPath(relPath);
+  assertPathNotProtected(normalizedPath, protectedPaths);
+  const commitId = await resolveCommitRef(repoPath, ref);
+  // Existence check only, so binary files restore without being decoded.
+  try {
+    await runGit(repoPath, ["cat-file", "-e", `${commitId}:${normalizedPath}`], {
+      allowFailure: true,
+    });
+  } catch (error) {
+    if (isMissingGitObjectError(error)) {
+      throw new GitError("path-not-found", `${normalizedPath} does not exist at ${ref}`);
+    }
+
+    throw error;
+  }
+
+  await runGitWrite(repoPath, [
+    "restore",
+    `--source=${commitId}`,
+    "--worktree",
+    ...(stage ? ["--staged"] : []),
+    "--",
+    normalizedPath,
+  ]);
+}
+
+export async function discardFile(
+  repoPath: string,
+  relPath: string,
+  bucket: Bucket,
+  protectedPaths: readonly string[] = [],
+) {
+  const normalizedPath = normalizeGitPath(relPath);
+  assertPathNotProtected(normalizedPath, protectedPaths);
   const attempted = new Set<Bucket>();
   const queue: Bucket[] = [bucket];
 
@@ -771,12 +1850,16 @@ export async function discardFile(repoPath: string, relPath: string, bucket: Buc
   }
 }
 
-export async function discardFiles(repoPath: string, files: DiscardFileInput[]) {
+export async function discardFiles(
+  repoPath: string,
+  files: DiscardFileInput[],
+  protectedPaths: readonly string[] = [],
+) {
   const failures: string[] = [];
 
   for (const file of files) {
     try {
-      await discardFile(repoPath, file.relPath, file.bucket);
+      await discardFile(repoPath, file.relPath, file.bucket, protectedPaths);
     } catch (error) {
       const message = error instanceof Error ? error.message : String(error);
       failures.push(`${file.relPath}: ${message}`);
@@ -788,12 +1871,54 @@ export async function discardFiles(repoPath: string, files: DiscardFileInput[])
   throw new Error(`Failed to discard ${failures.length} files:\n${failures.join("\n")}`);
 }
 
-export async function discardAll(repoPath: string) {
+export async function discardAll(repoPath: string, protectedPaths: readonly string[] = []) {
+  const excludes = protectedPathExcludes(protectedPaths);
+
   if (await hasHeadCommit(repoPath)) {
-    await runGitWrite(repoPath, ["reset", "--hard", "HEAD"]);
+    // `reset --hard` takes no pathspec, so protected paths need the slower restore.
+    await runGitWrite(
+      repoPath,
+      excludes.length === 0
+        ? ["reset", "--hard", "HEAD"]
+        : ["restore", "--source=HEAD", "--staged", "--worktree", "--", ".", ...excludes],
+    );
   }
 
-  await runGitWrite(repoPath, ["clean", "-fd", "--", "."]);
+  await runGitWrite(repoPath, ["clean", "-fd", "--", ".", ...excludes]);
+}
+
+// Worktree files go back to their index version, so staged work survives. Untracked files are
+// removed. The restore is skipped when nothing tracked changed, because an empty index would
+// make the `.` pathspec fail.
+export async function discardUnstaged(repoPath: string, protectedPaths: readonly string[] = []) {
+  const excludes = protectedPathExcludes(protectedPaths);
+  const changedOutput = await runGit(repoPath, [
+    "diff",
+    "--name-only",
+    "-z",
+    "--",
+    ".",
+    ...excludes,
+  ]);
+
+  if (changedOutput.length > 0) {
+    await runGitWrite(repoPath, ["restore", "--worktree", "--", ".", ...excludes]);
+  }
+
+  await runGitWrite(repoPath, ["clean", "-fd", "--", ".", ...excludes]);
+}
+
+async function hasStagedChanges(repoPath: string) {
+  try {
+    await runGit(repoPath, ["diff", "--cached", "--quiet"], { allowFailure: true });
+    return false;
+  } catch (error) {
+    // diff --quiet exits with 1 when there are differences.
+    if (error instanceof GitCommandError && error.code === 1) {
+      return true;
+    }
+    throw error;
+  }
 }
 
 export async function commitStaged(repoPath: string, message: string) {
@@ -801,7 +1926,46 @@ export async function commitStaged(repoPath: string, message: string) {
     throw new Error("commit message is empty");
   }
 
-  await runGitWrite(repoPath, ["commit", "-m", message]);
+  try {
+    await runGitWrite(repoPath, ["commit", "-m", message]);
+  } catch (error) {
+    // git reports an empty commit on stdout, so it is recognized by checking the index instead.
+    if (error instanceof GitCommandError && !(await hasStagedChanges(repoPath))) {
+      throw new GitError("nothing-to-commit", "nothing to commit: no changes are staged");
+    }
+    throw error;
+  }
+  const output = await runGit(repoPath, ["rev-parse", "HEAD"]);
+  return decodeUtf8(output, "commit id").trim();
+}
+
+// Rewrites HEAD with whatever is staged now. Without a message the original one is kept.
+export async function amendHead(repoPath: string, message?: string) {
+  if (message !== undefined && !message.trim()) {
+    throw new Error("commit message is empty");
+  }
+
+  try {
+    await runGit(repoPath, ["rev-parse", "--verify", "--quiet", "HEAD^{commit}"]);
+  } catch {
+    throw new GitError("no-commits", "nothing to amend: the branch has no commits yet");
+  }
+
+  const messageArgs = message === undefined ? ["--no-edit"] : ["-m", message];
+  await runGitWrite(repoPath, ["commit", "--amend", "--allow-empty", ...messageArgs]);
+  const output = await runGit(repoPath, ["rev-parse", "HEAD"]);
+  return decodeUtf8(output, "commit id").trim();
+}
+
+export async function rewordHead(repoPath: string, message: string) {
+  if (!message.trim()) {
+    throw new Error("commit message is empty");
+  }
+
+  await resolveCommitRef(repoPath, "HEAD");
+  // --only without paths leaves staged changes out of the amended commit, so only the message
+  // changes; amend keeps the original author.
+  await runGitWrite(repoPath, ["commit", "--amend", "--only", "--allow-empty", "-m", message]);
   const output = await runGit(repoPath, ["rev-parse", "HEAD"]);
   return decodeUtf8(output, "commit id").trim();
 }
@@ -0,0 +1,34 @@
+import { describe, expect, test } from "vitest";
+
+import { LAUNCH_MODE_ENV_VAR, parseLaunchBucket, resolveLaunchBucket } from "./launchOptions";
+
+describe("launch bucket", () => {
+  test("maps mode names to buckets", () => {
+    expect(parseLaunchBucket("staged")).toBe("staged");
+    expect(parseLaunchBucket(" Unstaged ")).toBe("unstaged");
+  });
+
+  test("falls back to unstaged for missing or invalid values", () => {
+    expect(parseLaunchBucket(undefined)).toBe("unstaged");
+    expect(parseLaunchBucket("")).toBe("unstaged");
+    expect(parseLaunchBucket("untracked")).toBe("unstaged");
+    expect(parseLaunchBucket("index")).toBe("unstaged");
+  });
+
+  test("reads the --mode flag in either form", () => {
+    expect(resolveLaunchBucket(["electron", ".", "--mode", "staged"], {})).toBe("staged");
+    expect(resolveLaunchBucket(["electron", ".", "--mode=staged"], {})).toBe("staged");
+    expect(resolveLaunchBucket(["electron", ".", "--mode"], {})).toBe("unstaged");
+  });
+
+  test("prefers the flag over the environment variable", () => {
+    expect(resolveLaunchBucket(["electron", "."], { [LAUNCH_MODE_ENV_VAR]: "staged" })).toBe(
+      "staged",
+    );
+    expect(
+      resolveLaunchBucket(["electron", ".", "--mode", "unstaged"], {
+        [LAUNCH_MODE_ENV_VAR]: "staged",
+      }),
+    ).toBe("unstaged");
+  });
+});
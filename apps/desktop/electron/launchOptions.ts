@@ -0,0 +1,34 @@
+import type { Bucket } from "../src/platform/desktop/contracts";
+
+export const LAUNCH_MODE_FLAG = "--mode";
+export const LAUNCH_MODE_ENV_VAR = "OPEN_WARDEN_MODE";
+export const DEFAULT_LAUNCH_BUCKET: Bucket = "unstaged";
+
+const LAUNCH_BUCKETS: readonly Bucket[] = ["staged", "unstaged"];
+
+export function parseLaunchBucket(value: string | undefined): Bucket {
+  const normalized = value?.trim().toLowerCase();
+  return LAUNCH_BUCKETS.find((bucket) => bucket === normalized) ?? DEFAULT_LAUNCH_BUCKET;
+}
+
+function readLaunchModeFlag(argv: readonly string[]): string | undefined {
+  for (let index = 0; index < argv.length; index += 1) {
+    const arg = argv[index];
+    if (arg === LAUNCH_MODE_FLAG) return argv[index + 1];
+    if (arg.startsWith(`${LAUNCH_MODE_FLAG}=`)) return arg.slice(LAUNCH_MODE_FLAG.length + 1);
+  }
+
+  return undefined;
+}
+
+// The flag wins over the environment so a hook script can override a shell-wide default.
+export function resolveLaunchBucket(
+  argv: readonly string[] = process.argv,
+  env: NodeJS.ProcessEnv = process.env,
+): Bucket {
+  return parseLaunchBucket(readLaunchModeFlag(argv) ?? env[LAUNCH_MODE_ENV_VAR]);
+}
+
+export async function getLaunchBucket(): Promise<Bucket> {
+  return resolveLaunchBucket();
+}
@@ -0,0 +1,43 @@
+import { afterEach, describe, expect, test } from "vitest";
+
+import { createLogger, type LogEvent, parseLogLevel, setLogLevel, setLogSink } from "./logger";
+
+afterEach(() => {
+  setLogSink(null);
+  setLogLevel("error");
+});
+
+describe("logger", () => {
+  test("parses log levels from the environment value", () => {
+    expect(parseLogLevel(undefined)).toBe("error");
+    expect(parseLogLevel(" DEBUG ")).toBe("debug");
+    expect(parseLogLevel("off")).toBe("off");
+    expect(parseLogLevel("verbose")).toBe("error");
+  });
+
+  test("drops events below the configured level", () => {
+    const events: LogEvent[] = [];
+    const log = createLogger("test");
+    setLogSink((event) => events.push(event));
+    setLogLevel("info");
+
+    log.debug("hidden");
+    log.info("shown", { count: 1 });
+    log.error("also shown");
+
+    expect(events).toEqual([
+      { level: "info", scope: "test", message: "shown", fields: { count: 1 } },
+      { level: "error", scope: "test", message: "also shown", fields: {} },
+    ]);
+  });
+
+  test("suppresses everything when turned off", () => {
+    const events: LogEvent[] = [];
+    setLogSink((event) => events.push(event));
+    setLogLevel("off");
+
+    createLogger("test").error("hidden");
+
+    expect(events).toEqual([]);
+  });
+});
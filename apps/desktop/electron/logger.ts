@@ -0,0 +1,65 @@
+export type LogLevel = "error" | "warn" | "info" | "debug";
+
+export type LogEvent = {
+  level: LogLevel;
+  scope: string;
+  message: string;
+  fields: Record<string, unknown>;
+};
+
+export type LogSink = (event: LogEvent) => void;
+
+const LOG_LEVEL_ORDER: Record<LogLevel, number> = {
+  error: 0,
+  warn: 1,
+  info: 2,
+  debug: 3,
+};
+
+const DEFAULT_LOG_LEVEL: LogLevel = "error";
+
+export function parseLogLevel(value: string | undefined): LogLevel | "off" {
+  const normalized = value?.trim().toLowerCase();
+  if (!normalized) return DEFAULT_LOG_LEVEL;
+  if (normalized === "off") return "off";
+  return normalized in LOG_LEVEL_ORDER ? (normalized as LogLevel) : DEFAULT_LOG_LEVEL;
+}
+
+function writeToConsole({ level, scope, message, fields }: LogEvent) {
+  const line = `[${scope}] ${message}`;
+  const args = Object.keys(fields).length > 0 ? [line, fields] : [line];
+
+  if (level === "error") console.error(...args);
+  else if (level === "warn") console.warn(...args);
+  else console.log(...args);
+}
+
+let sink: LogSink = writeToConsole;
+let threshold = parseLogLevel(process.env.OPEN_WARDEN_LOG);
+
+// Tests swap the sink to capture events; passing null restores console output.
+export function setLogSink(nextSink: LogSink | null) {
+  sink = nextSink ?? writeToConsole;
+}
+
+export function setLogLevel(level: LogLevel | "off") {
+  threshold = level;
+}
+
+export function isLogLevelEnabled(level: LogLevel) {
+  return threshold !== "off" && LOG_LEVEL_ORDER[level] <= LOG_LEVEL_ORDER[threshold];
+}
+
+export function createLogger(scope: string) {
+  const log = (level: LogLevel, message: string, fields: Record<string, unknown> = {}) => {
+    if (!isLogLevelEnabled(level)) return;
+    sink({ level, scope, message, fields });
+  };
+
+  return {
+    error: (message: string, fields?: Record<string, unknown>) => log("error", message, fields),
+    warn: (message: string, fields?: Record<string, unknown>) => log("warn", message, fields),
+    info: (message: string, fields?: Record<string, unknown>) => log("info", message, fields),
+    debug: (message: string, fields?: Record<string, unknown>) => log("debug", message, fields),
+  };
+}
@@ -13,6 +13,7 @@ import {
   UPDATE_GET_STATE_CHANNEL,
   UPDATE_INSTALL_CHANNEL,
 } from "./ipc-channels";
+import { createLogger } from "./logger";
 import { resolvePreloadPath } from "./preload-path";
 import { createUpdateManager } from "./updateManager";
 
@@ -22,6 +23,7 @@ let disposeAppSettingsWatcher: (() => void) | null = null;
 const updateManager = createUpdateManager({
   getWindow: () => mainWindow,
 });
+const log = createLogger("main");
 const gotSingleInstanceLock = app.requestSingleInstanceLock();
 
 if (!gotSingleInstanceLock) {
@@ -151,7 +153,7 @@ app.whenReady().then(() => {
       mainWindow.webContents.send(APP_SETTINGS_CHANGED_CHANNEL, settings);
     },
     onError(error) {
-      console.error("Failed to reload app settings", error);
+      log.error("Failed to reload app settings", { error });
     },
   }).then((dispose) => {
     disposeAppSettingsWatcher = dispose;
@@ -1,4 +1,4 @@
-import { execFile as nodeExecFile } from "node:child_process";
+import { execFile as nodeExecFile, spawn } from "node:child_process";
 import { constants as fsConstants, promises as fs } from "node:fs";
 import os from "node:os";
 import path from "node:path";
@@ -6,6 +6,8 @@ import { promisify } from "node:util";
 
 import { BrowserWindow, dialog, shell } from "electron";
 
+import { fileManagerCommand } from "./fileManager";
+
 const execFile = promisify(nodeExecFile);
 
 async function pathExists(targetPath: string) {
@@ -100,3 +102,35 @@ export async function openPath(targetPath: string, appName?: string | null) {
     throw new Error(error);
   }
 }
+
+export async function revealInFileManager(repoPath: string, filePath?: string | null) {
+  if (!repoPath.trim()) {
+    throw new Error("path is empty");
+  }
+
+  let targetPath = filePath ? path.resolve(repoPath, filePath) : repoPath;
+  let isDirectory = false;
+  // Deleted files no longer exist on disk; fall back to the nearest folder that still does.
+  for (;;) {
+    try {
+      isDirectory = (await fs.stat(targetPath)).isDirectory();
+      break;
+    } catch (error) {
+      const parent = path.dirname(targetPath);
+      if (targetPath === repoPath || parent === targetPath) throw error;
+      targetPath = parent;
+    }
+  }
+
+  const { command, args } = fileManagerCommand(process.platform, targetPath, isDirectory);
+
+  // explorer.exe exits non-zero even on success, so only a failed spawn counts as an error.
+  await new Promise<void>((resolve, reject) => {
+    const child = spawn(command, args, { detached: true, stdio: "ignore" });
+    child.once("error", reject);
+    child.once("spawn", () => {
+      child.unref();
+      resolve();
+    });
+  });
+}
@@ -1,4 +1,5 @@
 import {
+  ClipboardList,
   Copy,
   PanelLeftInactive,
   PanelLeftOpen,
@@ -19,7 +20,11 @@ import { useSidebarToggleHotkeys } from "@/app/useSidebarToggleHotkeys";
 import { useSidebarPanelRegistry } from "@/components/layout/SidebarPanelRegistry";
 import { Tooltip, TooltipContent, TooltipProvider, TooltipTrigger } from "@/components/ui/tooltip";
 import { DesktopUpdateButton } from "@/features/desktop-update/DesktopUpdateButton";
-import { copyComments, copyLastCommentsPayload } from "@/features/comments/actions";
+import {
+  copyComments,
+  copyCommentsAsPlainList,
+  copyLastCommentsPayload,
+} from "@/features/comments/actions";
 import { countCommentsForRepoContext } from "@/features/comments/selectors";
 import type { CommentContext } from "@/features/source-control/types";
 
@@ -117,6 +122,14 @@ function HeaderCommentActions({ activeFeature, currentPath }: HeaderCommentActio
     if (result.ok) toast.success(copyAndClearMessage(result.clearedCount));
   };
 
+  const onCopyPlainList = async () => {
+    if (!commentContext) return;
+    const result = await dispatch(copyCommentsAsPlainList(commentContext));
+    if (result.ok) {
+      toast.success(`Copied ${result.copiedCount} comment${result.copiedCount === 1 ? "" : "s"}`);
+    }
+  };
+
   const onCopyLastComments = async () => {
     const result = await dispatch(copyLastCommentsPayload());
     if (result.ok) toast.success("Copied last comments payload");
@@ -145,6 +158,21 @@ function HeaderCommentActions({ activeFeature, currentPath }: HeaderCommentActio
 
   return (
     <TooltipProvider>
+      {hasComments ? (
+        <Tooltip>
+          <TooltipTrigger asChild>
+            <button
+              type="button"
+              className="border-input bg-surface-alt text-muted-foreground hover:text-foreground inline-flex h-8 w-8 items-center justify-center rounded-md border transition-[transform] duration-150 ease-[var(--ease-out)] active:scale-[0.95]"
+              onClick={() => void onCopyPlainList()}
+              aria-label="Copy comments as a plain list"
+            >
+              <ClipboardList className="h-3.5 w-3.5" />
+            </button>
+          </TooltipTrigger>
+          <TooltipContent side="bottom">Copy comments as a plain list</TooltipContent>
+        </Tooltip>
+      ) : null}
       <Tooltip>
         <TooltipTrigger asChild>
           <button
@@ -1,4 +1,4 @@
-import { useState } from "react";
+import { useEffect, useRef, useState } from "react";
 import { Outlet, useLocation, useNavigate } from "react-router";
 import { useHotkey } from "@tanstack/react-hotkeys";
 
@@ -7,9 +7,22 @@ import { NuqsAdapter } from "nuqs/adapters/react-router/v7";
 import { featureKeyFromPath } from "@/app/featureNavigation";
 import { RepoTabs } from "@/app/RepoTabs";
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
-import { SidebarPanelRegistryProvider } from "@/components/layout/SidebarPanelRegistry";
+import {
+  SidebarPanelRegistryProvider,
+  useSidebarPanelRegistryOptional,
+} from "@/components/layout/SidebarPanelRegistry";
+import { captureFocusModeSnapshot, panelsToRestore, type FocusModeSnapshot } from "@/app/focusMode";
 import { AppCommandPalette } from "@/features/command-palette/AppCommandPalette";
-import { closeRepo, openRepo, selectFolder, selectRepo } from "@/features/source-control/actions";
+import { PasteDiffDialog } from "@/features/diff-view/components/PasteDiffDialog";
+import {
+  closeRepo,
+  moveRepoTab,
+  openRepo,
+  reconnectRepo,
+  selectFolder,
+  selectRepo,
+} from "@/features/source-control/actions";
+import { dismissUnavailableRepo } from "@/features/source-control/sourceControlSlice";
 import { RecentProjectsPicker } from "@/features/source-control/RecentProjectsPicker";
 
 export type AppShellOutletContext = {
@@ -24,6 +37,8 @@ export function AppShell() {
   const recentRepos = useAppSelector((state) => state.sourceControl.recentRepos);
   const [commandPaletteOpen, setCommandPaletteOpen] = useState(false);
   const [recentProjectsPickerOpen, setRecentProjectsPickerOpen] = useState(false);
+  const [pasteDiffOpen, setPasteDiffOpen] = useState(false);
+  const [focusMode, setFocusMode] = useState(false);
   const isSettingsRoute = location.pathname.startsWith("/settings");
   const activeFeature = isSettingsRoute ? null : featureKeyFromPath(location.pathname);
 
@@ -56,27 +71,48 @@ export function AppShell() {
     },
   );
 
+  useHotkey(
+    "Mod+Shift+F",
+    (event) => {
+      event.preventDefault();
+      setFocusMode((value) => !value);
+    },
+    {
+      ignoreInputs: false,
+      preventDefault: false,
+      stopPropagation: false,
+    },
+  );
+
   return (
     <NuqsAdapter>
       <SidebarPanelRegistryProvider>
+        <FocusModePanels active={focusMode} />
         <div className="bg-background text-foreground h-screen w-screen overflow-hidden">
-          <div className="grid h-full" style={{ gridTemplateRows: "56px 1fr 34px" }}>
-            <AppHeader
-              activeFeature={activeFeature}
-              currentPath={location.pathname}
-              onOpenCommandPalette={() => {
-                setCommandPaletteOpen(true);
-              }}
-            />
+          <div
+            className="grid h-full"
+            style={{ gridTemplateRows: focusMode ? "0 1fr 0" : "56px 1fr 34px" }}
+          >
+            <div className={focusMode ? "hidden" : "contents"}>
+              <AppHeader
+                activeFeature={activeFeature}
+                currentPath={location.pathname}
+                onOpenCommandPalette={() => {
+                  setCommandPaletteOpen(true);
+                }}
+              />
+            </div>
 
             <div className="relative min-h-0">
               <Outlet context={{ openRecentProjectsPicker }} />
             </div>
 
-            <RepoTabsContainer
-              currentPath={location.pathname}
-              onShowRecentProjects={openRecentProjectsPicker}
-            />
+            <div className={focusMode ? "hidden" : "contents"}>
+              <RepoTabsContainer
+                currentPath={location.pathname}
+                onShowRecentProjects={openRecentProjectsPicker}
+              />
+            </div>
           </div>
 
           <RecentProjectsPicker
@@ -94,7 +130,12 @@ export function AppShell() {
               void dispatch(selectFolder());
             }}
           />
-          <AppCommandPalette open={commandPaletteOpen} onOpenChange={setCommandPaletteOpen} />
+          <PasteDiffDialog open={pasteDiffOpen} onOpenChange={setPasteDiffOpen} />
+          <AppCommandPalette
+            open={commandPaletteOpen}
+            onOpenChange={setCommandPaletteOpen}
+            onOpenPastedDiff={() => setPasteDiffOpen(true)}
+          />
         </div>
         <div id="modal-root" />
       </SidebarPanelRegistryProvider>
@@ -102,6 +143,34 @@ export function AppShell() {
   );
 }
 
+// Collapses every sidebar while focus mode is on and reopens the ones that were open before.
+function FocusModePanels({ active }: { active: boolean }) {
+  const registry = useSidebarPanelRegistryOptional();
+  const snapshotRef = useRef<FocusModeSnapshot | null>(null);
+
+  useEffect(() => {
+    if (!registry) return;
+    const panels = registry.getSnapshot();
+
+    if (active && !snapshotRef.current) {
+      snapshotRef.current = captureFocusModeSnapshot(panels);
+      for (const panelId of panels.keys()) {
+        registry.setPanelCollapsed(panelId, true);
+      }
+      return;
+    }
+
+    if (!active && snapshotRef.current) {
+      for (const panelId of panelsToRestore(snapshotRef.current, panels)) {
+        registry.setPanelCollapsed(panelId, false);
+      }
+      snapshotRef.current = null;
+    }
+  }, [active, registry]);
+
+  return null;
+}
+
 type RepoTabsContainerProps = {
   currentPath: string;
   onShowRecentProjects: () => void;
@@ -113,6 +182,7 @@ function RepoTabsContainer({ currentPath, onShowRecentProjects }: RepoTabsContai
   const repos = useAppSelector((state) => state.sourceControl.repos);
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const recentRepos = useAppSelector((state) => state.sourceControl.recentRepos);
+  const unavailableRepos = useAppSelector((state) => state.sourceControl.unavailableRepos);
 
   function navigateToChangesAfterRepoSwitch(switchingRepo: boolean) {
     if (!switchingRepo) {
@@ -131,6 +201,7 @@ function RepoTabsContainer({ currentPath, onShowRecentProjects }: RepoTabsContai
       repos={repos}
       activeRepo={activeRepo}
       recentRepos={recentRepos}
+      unavailableRepos={unavailableRepos}
       onSelectRepo={(repo) => {
         const switchingRepo = repo !== activeRepo;
         void dispatch(selectRepo(repo));
@@ -143,6 +214,15 @@ function RepoTabsContainer({ currentPath, onShowRecentProjects }: RepoTabsContai
           }
         });
       }}
+      onMoveRepo={(from, to) => {
+        void dispatch(moveRepoTab(from, to));
+      }}
+      onReconnectRepo={(repo) => {
+        void dispatch(reconnectRepo(repo));
+      }}
+      onRemoveUnavailableRepo={(repo) => {
+        dispatch(dismissUnavailableRepo(repo));
+      }}
       onOpenRecentRepo={(repo) => {
         const switchingRepo = repo !== activeRepo;
         void dispatch(openRepo(repo)).then(() => {
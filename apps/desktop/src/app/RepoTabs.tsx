@@ -1,5 +1,5 @@
 import { useState } from "react";
-import { ChevronRight, FolderOpen, Plus, X } from "lucide-react";
+import { ChevronRight, FolderOpen, Plus, TriangleAlert, X } from "lucide-react";
 
 import { Popover, PopoverContent, PopoverTrigger } from "@/components/ui/popover";
 import { repoLabel, repoParentPath } from "@/features/source-control/utils";
@@ -8,8 +8,12 @@ type Props = {
   repos: Array<string | undefined>;
   activeRepo: string;
   recentRepos: string[];
+  unavailableRepos: string[];
   onSelectRepo: (repo: string) => void;
   onCloseRepo: (repo: string) => void;
+  onMoveRepo: (from: number, to: number) => void;
+  onReconnectRepo: (repo: string) => void;
+  onRemoveUnavailableRepo: (repo: string) => void;
   onOpenRecentRepo: (repo: string) => void;
   onShowAllRecentProjects: () => void;
   onOpenFolder: () => void;
@@ -33,14 +37,19 @@ export function RepoTabs({
   repos,
   activeRepo,
   recentRepos,
+  unavailableRepos,
   onSelectRepo,
   onCloseRepo,
+  onMoveRepo,
+  onReconnectRepo,
+  onRemoveUnavailableRepo,
   onOpenRecentRepo,
   onShowAllRecentProjects,
   onOpenFolder,
 }: Props) {
   const openRepos = repos.filter((repoPath): repoPath is string => Boolean(repoPath));
   const [pickerOpen, setPickerOpen] = useState(false);
+  const [dragIndex, setDragIndex] = useState<number | null>(null);
   const previewRecentRepos = recentRepos.slice(0, 5);
 
   return (
@@ -51,12 +60,31 @@ export function RepoTabs({
           const tabClass = tabStateClass(isActive);
           const closeClass = closeButtonClass(isActive);
           const firstTabEdgeClass = index === 0 ? "rounded-tl-none" : "";
+          const dragClass = dragIndex === index ? "opacity-50" : "";
 
           return (
             <div
               key={repoPath}
-              className={`flex h-7 shrink-0 items-center rounded-md border pl-1.5 ${tabClass} ${firstTabEdgeClass}`}
+              draggable
+              className={`flex h-7 shrink-0 items-center rounded-md border pl-1.5 ${tabClass} ${firstTabEdgeClass} ${dragClass}`}
               title={repoPath}
+              onDragStart={(event) => {
+                event.dataTransfer.effectAllowed = "move";
+                setDragIndex(index);
+              }}
+              onDragOver={(event) => {
+                if (dragIndex === null) return;
+                event.preventDefault();
+                event.dataTransfer.dropEffect = "move";
+              }}
+              onDrop={(event) => {
+                event.preventDefault();
+                if (dragIndex !== null && dragIndex !== index) {
+                  onMoveRepo(dragIndex, index);
+                }
+                setDragIndex(null);
+              }}
+              onDragEnd={() => setDragIndex(null)}
             >
               <button
                 type="button"
@@ -81,6 +109,33 @@ export function RepoTabs({
           );
         })}
 
+        {unavailableRepos.map((repoPath) => (
+          <div
+            key={`unavailable-${repoPath}`}
+            className="border-border/70 text-muted-foreground/70 flex h-7 shrink-0 items-center rounded-md border border-dashed pl-1.5"
+            title={`${repoPath} is missing or unreadable`}
+          >
+            <button
+              type="button"
+              className="hover:text-foreground flex h-full max-w-56 min-w-0 items-center gap-1 truncate pr-1 text-sm font-medium"
+              onClick={() => onReconnectRepo(repoPath)}
+              title={`Reconnect ${repoPath}`}
+            >
+              <TriangleAlert className="h-3.5 w-3.5 shrink-0 text-amber-500" />
+              <span className="truncate line-through">{repoLabel(repoPath)}</span>
+            </button>
+            <button
+              type="button"
+              className={`ml-1 inline-flex h-5 w-5 items-center justify-center rounded-sm ${closeButtonClass(false)}`}
+              onClick={() => onRemoveUnavailableRepo(repoPath)}
+              title={`Remove ${repoLabel(repoPath)}`}
+              aria-label={`Remove unavailable ${repoLabel(repoPath)} repository`}
+            >
+              <X className="h-3.5 w-3.5" />
+            </button>
+          </div>
+        ))}
+
         <Popover open={pickerOpen} onOpenChange={setPickerOpen}>
           <PopoverTrigger asChild>
             <button
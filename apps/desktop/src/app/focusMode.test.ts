@@ -0,0 +1,30 @@
+import { describe, expect, it } from "vitest";
+
+import { captureFocusModeSnapshot, panelsToRestore } from "./focusMode";
+
+describe("focus mode snapshots", () => {
+  it("restores only the panels that were open before focus mode", () => {
+    const snapshot = captureFocusModeSnapshot(
+      new Map([
+        ["primary", { collapsed: false }],
+        ["history-files", { collapsed: true }],
+      ]),
+    );
+
+    expect(snapshot.collapsedPanels).toEqual({ primary: false, "history-files": true });
+
+    const whileFocused = new Map([
+      ["primary", { collapsed: true }],
+      ["history-files", { collapsed: true }],
+      ["review", { collapsed: true }],
+    ]);
+
+    expect(panelsToRestore(snapshot, whileFocused)).toEqual(["primary"]);
+  });
+
+  it("skips panels the user reopened while focused", () => {
+    const snapshot = captureFocusModeSnapshot(new Map([["primary", { collapsed: false }]]));
+
+    expect(panelsToRestore(snapshot, new Map([["primary", { collapsed: false }]]))).toEqual([]);
+  });
+});
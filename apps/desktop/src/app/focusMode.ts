@@ -0,0 +1,27 @@
+type PanelVisibility = { collapsed: boolean };
+
+export type FocusModeSnapshot = {
+  collapsedPanels: Record<string, boolean>;
+};
+
+export function captureFocusModeSnapshot(
+  panels: ReadonlyMap<string, PanelVisibility>,
+): FocusModeSnapshot {
+  const collapsedPanels: Record<string, boolean> = {};
+  for (const [panelId, panel] of panels) {
+    collapsedPanels[panelId] = panel.collapsed;
+  }
+
+  return { collapsedPanels };
+}
+
+// Panels the user had open before entering focus mode. Panels that were already collapsed, or
+// that mounted while focus mode was on, are left alone.
+export function panelsToRestore(
+  snapshot: FocusModeSnapshot,
+  panels: ReadonlyMap<string, PanelVisibility>,
+): string[] {
+  return [...panels]
+    .filter(([panelId, panel]) => snapshot.collapsedPanels[panelId] === false && panel.collapsed)
+    .map(([panelId]) => panelId);
+}
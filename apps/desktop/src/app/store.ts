@@ -6,6 +6,7 @@ import { desktopUpdateReducer } from "@/features/desktop-update/desktopUpdateSli
 import { hostedReposApi } from "@/features/hosted-repos/api";
 import { lspReducer } from "@/features/lsp/lspSlice";
 import { commentsReducer } from "@/features/comments/commentsSlice";
+import { reviewedFilesReducer } from "@/features/comments/reviewedFilesSlice";
 import { pullRequestsReducer } from "@/features/pull-requests/pullRequestsSlice";
 import { settingsReducer } from "@/features/settings/settingsSlice";
 import { gitApi } from "@/features/source-control/api";
@@ -19,6 +20,7 @@ export const store = configureStore({
     sourceControl: sourceControlReducer,
     pullRequests: pullRequestsReducer,
     comments: commentsReducer,
+    reviewedFiles: reviewedFilesReducer,
     commentsClipboard: commentsClipboardReducer,
     [gitApi.reducerPath]: gitApi.reducer,
     [hostedReposApi.reducerPath]: hostedReposApi.reducer,
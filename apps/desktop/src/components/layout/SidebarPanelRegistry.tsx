@@ -12,6 +12,7 @@ type SidebarPanelRegistryApi = {
   unregister: (id: string) => void;
   setCollapsed: (id: string, collapsed: boolean) => void;
   toggle: (id: string) => void;
+  setPanelCollapsed: (id: string, collapsed: boolean) => void;
   subscribe: (callback: () => void) => () => void;
   getSnapshot: () => ReadonlyMap<string, PanelEntry>;
 };
@@ -56,6 +57,16 @@ function createRegistryApi(): SidebarPanelRegistryApi {
       }
     },
 
+    setPanelCollapsed(id, collapsed) {
+      const entry = panels.get(id);
+      if (!entry || entry.ref.isCollapsed() === collapsed) return;
+      if (collapsed) {
+        entry.ref.collapse();
+      } else {
+        entry.ref.expand();
+      }
+    },
+
     subscribe(callback) {
       listeners.add(callback);
       return () => {
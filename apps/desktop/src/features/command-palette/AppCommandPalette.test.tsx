@@ -7,10 +7,14 @@ import { AppCommandPalette } from "./AppCommandPalette";
 const mocks = vi.hoisted(() => ({
   navigate: vi.fn(),
   setTheme: vi.fn(),
+  useGetAllRepoFilesQuery: vi.fn(),
   useGetBranchFilesQuery: vi.fn(),
   useGetCommitFilesQuery: vi.fn(),
-  useGetCommitHistoryQuery: vi.fn(),
+  useGetCommitHistoryInfiniteQuery: vi.fn(),
   useGetGitSnapshotQuery: vi.fn(),
+  useGetRecentlyModifiedFilesQuery: vi.fn(),
+  useGetReflogQuery: vi.fn(),
+  useListStashesQuery: vi.fn(),
   useHotkey: vi.fn(),
 }));
 
@@ -47,13 +51,23 @@ vi.mock("@/app/hooks", () => ({
         selectedFiles: [],
         commitMessage: "",
         diffStyle: "split",
+        diffWrap: false,
+        diffChangesOnly: false,
+        diffClusterPreview: false,
         historyCommitId: "",
+        historyParentIndex: 0,
+        historyRangeStartId: "",
         reviewBaseRef: "main",
         reviewHeadRef: "feature",
         reviewActivePath: "src/large-file.ts",
         fileViewerTarget: null,
         symbolPeek: null,
       },
+      settings: {
+        appSettings: {
+          sourceControl: { historyPageSize: 200 },
+        },
+      },
       comments: [],
     }),
 }));
@@ -71,9 +85,14 @@ vi.mock("@/components/ui/command", () => ({
 
 vi.mock("@/features/source-control/api", () => ({
   useGetGitSnapshotQuery: mocks.useGetGitSnapshotQuery,
-  useGetCommitHistoryQuery: mocks.useGetCommitHistoryQuery,
+  loadedHistoryCommits: () => [],
+  useGetCommitHistoryInfiniteQuery: mocks.useGetCommitHistoryInfiniteQuery,
   useGetCommitFilesQuery: mocks.useGetCommitFilesQuery,
   useGetBranchFilesQuery: mocks.useGetBranchFilesQuery,
+  useGetAllRepoFilesQuery: mocks.useGetAllRepoFilesQuery,
+  useGetRecentlyModifiedFilesQuery: mocks.useGetRecentlyModifiedFilesQuery,
+  useGetReflogQuery: mocks.useGetReflogQuery,
+  useListStashesQuery: mocks.useListStashesQuery,
 }));
 
 describe("AppCommandPalette", () => {
@@ -82,7 +101,7 @@ describe("AppCommandPalette", () => {
   });
 
   test("does not mount review data subscriptions while closed", () => {
-    render(<AppCommandPalette open={false} onOpenChange={() => {}} />);
+    render(<AppCommandPalette open={false} onOpenChange={() => {}} onOpenPastedDiff={() => {}} />);
 
     expect(mocks.useGetBranchFilesQuery).not.toHaveBeenCalled();
     expect(
@@ -94,11 +113,15 @@ describe("AppCommandPalette", () => {
     mocks.useGetGitSnapshotQuery.mockReturnValue({
       snapshot: { staged: [], unstaged: [], untracked: [] },
     });
-    mocks.useGetCommitHistoryQuery.mockReturnValue({ commits: [] });
+    mocks.useGetCommitHistoryInfiniteQuery.mockReturnValue({ data: undefined });
     mocks.useGetCommitFilesQuery.mockReturnValue({ historyFiles: [] });
     mocks.useGetBranchFilesQuery.mockReturnValue({ reviewFiles: [] });
+    mocks.useGetAllRepoFilesQuery.mockReturnValue({ allRepoFiles: [] });
+    mocks.useGetRecentlyModifiedFilesQuery.mockReturnValue({ recentFiles: [] });
+    mocks.useGetReflogQuery.mockReturnValue({ reflogEntries: [] });
+    mocks.useListStashesQuery.mockReturnValue({ stashEntries: [] });
 
-    render(<AppCommandPalette open onOpenChange={() => {}} />);
+    render(<AppCommandPalette open onOpenChange={() => {}} onOpenPastedDiff={() => {}} />);
 
     expect(mocks.useGetBranchFilesQuery).toHaveBeenCalledOnce();
     expect(
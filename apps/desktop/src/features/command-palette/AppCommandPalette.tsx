@@ -1,12 +1,14 @@
 import { skipToken } from "@reduxjs/toolkit/query";
 import { useHotkey } from "@tanstack/react-hotkeys";
-import { Clock3, File, GitCommitHorizontal, Wrench } from "lucide-react";
+import { Archive, Clock3, File, GitCommitHorizontal, History, Wrench } from "lucide-react";
 import { useTheme } from "next-themes";
+import { useState } from "react";
 import { useLocation, useNavigate } from "react-router";
 import { toast } from "sonner";
 
 import { FEATURE_NAV_ITEMS, featureKeyFromPath } from "@/app/featureNavigation";
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
+import { Button } from "@/components/ui/button";
 import {
   CommandDialog,
   CommandEmpty,
@@ -17,36 +19,64 @@ import {
   CommandSeparator,
   CommandShortcut,
 } from "@/components/ui/command";
-import { confirmDiscard, copyComments } from "@/features/comments/actions";
+import { confirmDiscard, copyComments, copyFixRequest } from "@/features/comments/actions";
 import { compactComments } from "@/features/comments/selectors";
 import {
+  applyStashAction,
   closeRepo,
   commitAction,
+  copyCommitPatchAction,
+  copySelectionDiffAction,
   discardChangesGroupAction,
+  discardUnstagedAction,
+  moveRepoTab,
+  openActiveFileInDifftoolAction,
   openRepo,
+  popStashAction,
+  pushStashAction,
   refreshActiveRepo,
+  revealInFileManagerAction,
+  rewordLastCommitAction,
   selectFile,
   selectFolder,
   selectHistoryCommit,
   selectHistoryFile,
   selectRepo,
   setDiffStyleValue,
+  reviewStashAction,
   stageAllAction,
   stageFileAction,
+  toggleDiffChangesOnly,
+  toggleDiffClusterPreview,
+  toggleDiffWrap,
   unstageAllAction,
 } from "@/features/source-control/actions";
 import {
+  useGetAllRepoFilesQuery,
   useGetBranchFilesQuery,
   useGetCommitFilesQuery,
-  useGetCommitHistoryQuery,
+  loadedHistoryCommits,
+  useGetCommitHistoryInfiniteQuery,
   useGetGitSnapshotQuery,
+  useGetRecentlyModifiedFilesQuery,
+  useGetReflogQuery,
+  useListStashesQuery,
 } from "@/features/source-control/api";
-import { setReviewActivePath } from "@/features/source-control/sourceControlSlice";
+import { useCommitHistoryArgs } from "@/features/source-control/hooks/useCommitHistoryArgs";
+import { useHistoryDiffBase } from "@/features/source-control/hooks/useHistoryDiffBase";
+import {
+  openFileViewer,
+  setReviewActivePath,
+  setReviewBaseRef,
+  setReviewHeadRef,
+} from "@/features/source-control/sourceControlSlice";
 import type {
   BucketedFile,
   CommentContext,
   CommentItem,
   SelectedFile,
+  StashEntry,
+  StashSelector,
 } from "@/features/source-control/types";
 
 import {
@@ -56,9 +86,12 @@ import {
 } from "./buildCommandItems";
 import type { CommandActionItem, CommandCommitItem, CommandFileItem } from "./commandPaletteTypes";
 
+const RECENT_FILES_LIMIT = 8;
+
 type AppCommandPaletteProps = {
   open: boolean;
   onOpenChange: (open: boolean) => void;
+  onOpenPastedDiff: () => void;
 };
 
 function isMatchingContext(comment: CommentItem, context: CommentContext): boolean {
@@ -114,6 +147,14 @@ function commandItemSearchValue(item: CommandActionItem | CommandFileItem | Comm
   return `${item.label} ${item.searchText}`;
 }
 
+// A stash's message outlives its index, unless a newer stash's message also contains it and
+// would be matched first; such a stash is acted on by index.
+function stashSelector(entry: StashEntry, entries: StashEntry[]): StashSelector {
+  const needle = entry.message.trim().toLowerCase();
+  const match = entries.find((other) => other.message.toLowerCase().includes(needle));
+  return needle && match?.index === entry.index ? entry.message : entry.index;
+}
+
 function copyAndClearMessage(count: number): string {
   return `Copied ${count} comment${count === 1 ? "" : "s"} and cleared them`;
 }
@@ -131,7 +172,11 @@ async function runCommandItem(
   }
 }
 
-export function AppCommandPalette({ open, onOpenChange }: AppCommandPaletteProps) {
+export function AppCommandPalette({
+  open,
+  onOpenChange,
+  onOpenPastedDiff,
+}: AppCommandPaletteProps) {
   useHotkey(
     "Mod+K",
     (event) => {
@@ -154,16 +199,56 @@ export function AppCommandPalette({ open, onOpenChange }: AppCommandPaletteProps
       description="Search files, commands, or commits"
       showCloseButton={false}
     >
-      {open ? <AppCommandPaletteContent onOpenChange={onOpenChange} /> : null}
+      {open ? (
+        <AppCommandPaletteContent
+          onOpenChange={onOpenChange}
+          onOpenPastedDiff={onOpenPastedDiff}
+        />
+      ) : null}
     </CommandDialog>
   );
 }
 
+type PaletteFileScopeToggleProps = {
+  active: boolean;
+  disabled?: boolean;
+  label: string;
+  title: string;
+  onToggle: () => void;
+};
+
+function PaletteFileScopeToggle({
+  active,
+  disabled = false,
+  label,
+  title,
+  onToggle,
+}: PaletteFileScopeToggleProps) {
+  return (
+    <Button
+      type="button"
+      size="xs"
+      variant={active ? "secondary" : "ghost"}
+      className="h-6 rounded-sm px-2 text-[11px]"
+      aria-pressed={active}
+      disabled={disabled}
+      title={title}
+      onClick={onToggle}
+    >
+      {label}
+    </Button>
+  );
+}
+
 type AppCommandPaletteContentProps = {
   onOpenChange: (open: boolean) => void;
+  onOpenPastedDiff: () => void;
 };
 
-function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProps) {
+function AppCommandPaletteContent({
+  onOpenChange,
+  onOpenPastedDiff,
+}: AppCommandPaletteContentProps) {
   const dispatch = useAppDispatch();
   const navigate = useNavigate();
   const location = useLocation();
@@ -172,6 +257,7 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
 
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const repos = useAppSelector((state) => state.sourceControl.repos);
+  const activeRepoIndex = activeRepo ? repos.indexOf(activeRepo) : -1;
   const recentRepos = useAppSelector((state) => state.sourceControl.recentRepos);
   const runningAction = useAppSelector((state) => state.sourceControl.runningAction);
   const activeBucket = useAppSelector((state) => state.sourceControl.activeBucket);
@@ -179,11 +265,20 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
   const selectedFiles = useAppSelector((state) => state.sourceControl.selectedFiles);
   const commitMessage = useAppSelector((state) => state.sourceControl.commitMessage);
   const diffStyle = useAppSelector((state) => state.sourceControl.diffStyle);
+  const diffWrap = useAppSelector((state) => state.sourceControl.diffWrap);
+  const diffChangesOnly = useAppSelector((state) => state.sourceControl.diffChangesOnly);
+  const diffClusterPreview = useAppSelector((state) => state.sourceControl.diffClusterPreview);
   const historyCommitId = useAppSelector((state) => state.sourceControl.historyCommitId);
+  const historyParentIndex = useAppSelector((state) => state.sourceControl.historyParentIndex);
+  const historyDiffBase = useHistoryDiffBase();
   const reviewBaseRef = useAppSelector((state) => state.sourceControl.reviewBaseRef);
   const reviewHeadRef = useAppSelector((state) => state.sourceControl.reviewHeadRef);
   const reviewActivePath = useAppSelector((state) => state.sourceControl.reviewActivePath);
   const comments = useAppSelector((state) => state.comments);
+  const [showAllFiles, setShowAllFiles] = useState(false);
+  const [includeIgnoredFiles, setIncludeIgnoredFiles] = useState(false);
+  const [showReflog, setShowReflog] = useState(false);
+  const [showStashes, setShowStashes] = useState(false);
 
   const { snapshot } = useGetGitSnapshotQuery(activeRepo, {
     skip: !activeRepo,
@@ -198,16 +293,17 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
     }),
   });
 
-  const { commits } = useGetCommitHistoryQuery(
-    activeRepo ? { repoPath: activeRepo, limit: 200 } : skipToken,
-    {
-      selectFromResult: ({ data }) => ({ commits: data ?? [] }),
-    },
-  );
+  const { data: historyPages } = useGetCommitHistoryInfiniteQuery(useCommitHistoryArgs());
+  const commits = loadedHistoryCommits(historyPages);
 
   const { historyFiles } = useGetCommitFilesQuery(
     feature === "history" && activeRepo && historyCommitId
-      ? { repoPath: activeRepo, commitId: historyCommitId }
+      ? {
+          repoPath: activeRepo,
+          commitId: historyCommitId,
+          parentIndex: historyParentIndex,
+          baseRef: historyDiffBase,
+        }
       : skipToken,
     {
       selectFromResult: ({ data }) => ({ historyFiles: data ?? [] }),
@@ -228,10 +324,41 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
     },
   );
 
+  const { allRepoFiles } = useGetAllRepoFilesQuery(
+    showAllFiles && activeRepo
+      ? { repoPath: activeRepo, includeIgnored: includeIgnoredFiles }
+      : skipToken,
+    {
+      selectFromResult: ({ data }) => ({ allRepoFiles: data ?? [] }),
+    },
+  );
+
+  const { recentFiles } = useGetRecentlyModifiedFilesQuery(
+    activeRepo ? { repoPath: activeRepo, limit: RECENT_FILES_LIMIT } : skipToken,
+    {
+      selectFromResult: ({ data }) => ({ recentFiles: data ?? [] }),
+    },
+  );
+
+  const { reflogEntries } = useGetReflogQuery(
+    showReflog && activeRepo ? { repoPath: activeRepo } : skipToken,
+    {
+      selectFromResult: ({ data }) => ({ reflogEntries: data ?? [] }),
+    },
+  );
+
+  const { stashEntries } = useListStashesQuery(
+    showStashes && activeRepo ? activeRepo : skipToken,
+    {
+      selectFromResult: ({ data }) => ({ stashEntries: data ?? [] }),
+    },
+  );
+
   const snapshotRows = snapshot ? flattenSnapshot(snapshot) : [];
   const selectionTargets =
     feature === "changes" ? selectedOrFocusedFiles(selectedFiles, activeBucket, activePath) : [];
   const stageTargets = selectionTargets.filter((file) => file.bucket !== "staged");
+  const diffTargets = selectionTargets.filter((file) => file.bucket !== "untracked");
   const discardTargets = selectionTargets
     .map((selected) =>
       snapshotRows.find((row) => row.bucket === selected.bucket && row.path === selected.path),
@@ -239,6 +366,7 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
     .filter((row): row is BucketedFile => !!row);
 
   const stagedCount = snapshot?.staged.length ?? 0;
+  const unstagedCount = (snapshot?.unstaged.length ?? 0) + (snapshot?.untracked.length ?? 0);
   const hasRunningAction = runningAction !== "";
 
   const commentContext: CommentContext | null =
@@ -322,6 +450,24 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
         }
       },
     },
+    {
+      id: "repo:move-left",
+      label: "Move Repo Tab Left",
+      disabled: activeRepoIndex <= 0,
+      keywords: ["repo", "tab", "move", "reorder", "left"],
+      onSelect: async () => {
+        await dispatch(moveRepoTab(activeRepoIndex, activeRepoIndex - 1));
+      },
+    },
+    {
+      id: "repo:move-right",
+      label: "Move Repo Tab Right",
+      disabled: activeRepoIndex < 0 || activeRepoIndex >= repos.length - 1,
+      keywords: ["repo", "tab", "move", "reorder", "right"],
+      onSelect: async () => {
+        await dispatch(moveRepoTab(activeRepoIndex, activeRepoIndex + 1));
+      },
+    },
     {
       id: "repo:refresh",
       label: "Refresh Active Repo",
@@ -331,6 +477,15 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
         await dispatch(refreshActiveRepo());
       },
     },
+    {
+      id: "repo:reveal",
+      label: "Open Repo Folder in File Manager",
+      disabled: !activeRepo,
+      keywords: ["repo", "folder", "reveal", "finder", "explorer", "file manager"],
+      onSelect: async () => {
+        await dispatch(revealInFileManagerAction());
+      },
+    },
     {
       id: "changes:stage-selection",
       label: "Stage Selected / Focused Files",
@@ -342,6 +497,15 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
         }
       },
     },
+    {
+      id: "changes:copy-selection-diff",
+      label: "Copy Diff of Selected / Focused Files",
+      disabled: !activeRepo || diffTargets.length === 0,
+      keywords: ["diff", "patch", "copy", "selection"],
+      onSelect: async () => {
+        await dispatch(copySelectionDiffAction(diffTargets));
+      },
+    },
     {
       id: "changes:stage-all",
       label: "Stage All Changes",
@@ -374,6 +538,37 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
         await dispatch(discardChangesGroupAction(discardTargets));
       },
     },
+    {
+      id: "changes:discard-unstaged",
+      label: "Discard Unstaged Changes (Keep Staged)",
+      disabled: !activeRepo || hasRunningAction || unstagedCount === 0,
+      keywords: ["discard", "revert", "unstaged", "untracked", "keep staged"],
+      onSelect: async () => {
+        const confirmed = await confirmDiscard(
+          "Discard every unstaged change and delete untracked files? Staged changes are kept.",
+        );
+        if (!confirmed) return;
+        await dispatch(discardUnstagedAction());
+      },
+    },
+    {
+      id: "changes:stash",
+      label: "Stash Changes",
+      disabled: !activeRepo || hasRunningAction || stagedCount + unstagedCount === 0,
+      keywords: ["stash", "save", "shelve", "pull"],
+      onSelect: async () => {
+        await dispatch(pushStashAction());
+      },
+    },
+    {
+      id: "changes:stash-untracked",
+      label: "Stash Changes Including Untracked Files",
+      disabled: !activeRepo || hasRunningAction || stagedCount + unstagedCount === 0,
+      keywords: ["stash", "untracked", "save", "shelve"],
+      onSelect: async () => {
+        await dispatch(pushStashAction({ includeUntracked: true }));
+      },
+    },
     {
       id: "changes:commit",
       label: "Commit Staged Changes",
@@ -386,11 +581,24 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
         await dispatch(commitAction());
       },
     },
+    {
+      id: "git:reword",
+      label: "Reword Last Commit",
+      subtitle: commitMessage.trim()
+        ? `Message: ${commitMessage.trim()}`
+        : "Type the new message in the commit box first",
+      disabled: !activeRepo || hasRunningAction || !commitMessage.trim(),
+      keywords: ["reword", "amend", "message", "commit"],
+      onSelect: async () => {
+        await dispatch(rewordLastCommitAction());
+      },
+    },
     {
       id: "diff:split",
       label: "Switch Diff to Split",
       disabled: diffStyle === "split",
-      keywords: ["diff", "split"],
+      shortcut: "⌘⌥D",
+      keywords: ["diff", "split", "side by side"],
       onSelect: async () => {
         await dispatch(setDiffStyleValue("split"));
       },
@@ -399,11 +607,54 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
       id: "diff:unified",
       label: "Switch Diff to Unified",
       disabled: diffStyle === "unified",
+      shortcut: "⌘⌥D",
       keywords: ["diff", "unified"],
       onSelect: async () => {
         await dispatch(setDiffStyleValue("unified"));
       },
     },
+    {
+      id: "diff:wrap",
+      label: diffWrap ? "Disable Diff Line Wrapping" : "Wrap Long Diff Lines",
+      keywords: ["diff", "wrap", "soft wrap", "long lines"],
+      onSelect: async () => {
+        await dispatch(toggleDiffWrap());
+      },
+    },
+    {
+      id: "diff:changes-only",
+      label: diffChangesOnly ? "Show Diff Context Lines" : "Show Only Changed Lines",
+      keywords: ["diff", "context", "changes only", "terse", "hide unchanged"],
+      onSelect: async () => {
+        await dispatch(toggleDiffChangesOnly());
+      },
+    },
+    {
+      id: "diff:cluster-preview",
+      label: diffClusterPreview
+        ? "Stop Previewing Changed Blocks on Hover"
+        : "Preview Changed Blocks on Hover",
+      keywords: ["diff", "hover", "removed", "added", "block", "copy"],
+      onSelect: async () => {
+        await dispatch(toggleDiffClusterPreview());
+      },
+    },
+    {
+      id: "diff:difftool",
+      label: "Open in External Diff Tool",
+      subtitle: feature === "changes" && activePath ? activePath : undefined,
+      disabled: !activeRepo || feature !== "changes" || !activePath,
+      keywords: ["diff", "difftool", "external", "meld", "binary"],
+      onSelect: () => {
+        void dispatch(openActiveFileInDifftoolAction());
+      },
+    },
+    {
+      id: "diff:paste",
+      label: "Open Pasted Diff",
+      keywords: ["diff", "patch", "paste", "clipboard", "review"],
+      onSelect: onOpenPastedDiff,
+    },
     {
       id: "comments:copy-file",
       label: "Copy Comments (File)",
@@ -431,6 +682,29 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
         if (result.ok) toast.success(copyAndClearMessage(result.clearedCount));
       },
     },
+    {
+      id: "comments:copy-fix-request",
+      label: "Copy Fix Request with Patch",
+      disabled: !activeRepo || !commentContext || contextComments.length === 0,
+      keywords: ["comments", "copy", "fix", "request", "patch", "diff", "agent"],
+      onSelect: async () => {
+        if (!commentContext) return;
+        const result = await dispatch(copyFixRequest(commentContext));
+        if (!result.ok) return;
+        const noun = result.copiedCount === 1 ? "comment" : "comments";
+        toast.success(`Copied fix request for ${result.copiedCount} ${noun}`);
+      },
+    },
+    {
+      id: "history:copy-patch",
+      label: "Copy Commit as Patch",
+      subtitle: historyCommitId ? historyCommitId.slice(0, 7) : undefined,
+      disabled: !activeRepo || !historyCommitId,
+      keywords: ["history", "commit", "patch", "format-patch", "copy"],
+      onSelect: async () => {
+        await dispatch(copyCommitPatchAction(historyCommitId));
+      },
+    },
     {
       id: "theme:system",
       label: "Set Theme: System",
@@ -457,7 +731,7 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
     },
   ]);
 
-  const fileItems = buildCommandFileItems(
+  const featureFileCandidates =
     feature === "changes"
       ? snapshotRows.map((file) => ({
           path: file.path,
@@ -498,8 +772,39 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
                 dispatch(setReviewActivePath(file.path));
               },
             }))
-          : [],
-  );
+          : [];
+  const listedFilePaths = new Set(featureFileCandidates.map((file) => file.path));
+  // Recently edited files seed the list so recent work is one keystroke away.
+  const recentFileCandidates = recentFiles
+    .filter((relPath) => !listedFilePaths.has(relPath))
+    .map((relPath) => ({
+      path: relPath,
+      secondaryLabel: "recent",
+      keywords: ["recent", "modified"],
+      onSelect: () => {
+        navigate("/changes/files");
+        dispatch(openFileViewer({ repoPath: activeRepo, relPath }));
+      },
+    }));
+  const seededFilePaths = new Set([...listedFilePaths, ...recentFiles]);
+  const repoFileCandidates = showAllFiles
+    ? allRepoFiles
+        .filter((file) => !seededFilePaths.has(file.path))
+        .map((file) => ({
+          path: file.path,
+          secondaryLabel: file.ignored ? "ignored" : "repository",
+          keywords: file.ignored ? ["repository", "ignored"] : ["repository"],
+          onSelect: () => {
+            navigate("/changes/files");
+            dispatch(openFileViewer({ repoPath: activeRepo, relPath: file.path }));
+          },
+        }))
+    : [];
+  const fileItems = buildCommandFileItems([
+    ...featureFileCandidates,
+    ...recentFileCandidates,
+    ...repoFileCandidates,
+  ]);
 
   const historyItems = buildCommandCommitItems(
     commits.map((commit) => ({
@@ -516,9 +821,100 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
     })),
   );
 
+  const reflogItems = buildCommandCommitItems(
+    reflogEntries.map((entry) => ({
+      id: `reflog:${entry.selector}`,
+      commitId: entry.newId,
+      shortId: entry.newId.slice(0, 7),
+      summary: entry.message,
+      author: entry.committer,
+      relativeTime: entry.relativeTime,
+      keywords: ["reflog", entry.selector],
+      // The oldest entry has nothing to compare against.
+      disabled: !entry.oldId,
+      onSelect: () => {
+        if (!entry.oldId) return;
+        navigate("/review");
+        dispatch(setReviewBaseRef(entry.oldId));
+        dispatch(setReviewHeadRef(entry.newId));
+        dispatch(setReviewActivePath(""));
+      },
+    })),
+  );
+
+  const stashItems = buildCommandCommitItems(
+    stashEntries.flatMap((entry) => {
+      const name = `stash@{${entry.index}}`;
+      const stash = stashSelector(entry, stashEntries);
+      const candidate = {
+        commitId: entry.oid,
+        shortId: entry.oid.slice(0, 7),
+        author: entry.author,
+        relativeTime: entry.relativeTime,
+        keywords: ["stash", name, entry.message],
+      };
+      return [
+        {
+          ...candidate,
+          id: `stash:review:${entry.oid}`,
+          summary: `${name}: ${entry.message}`,
+          onSelect: async () => {
+            navigate("/review");
+            await dispatch(reviewStashAction(stash));
+          },
+        },
+        {
+          ...candidate,
+          id: `stash:apply:${entry.oid}`,
+          summary: `Apply ${name}: ${entry.message}`,
+          onSelect: async () => {
+            await dispatch(applyStashAction(stash));
+          },
+        },
+        {
+          ...candidate,
+          id: `stash:pop:${entry.oid}`,
+          summary: `Pop ${name}: ${entry.message}`,
+          onSelect: async () => {
+            await dispatch(popStashAction(stash));
+          },
+        },
+      ];
+    }),
+  );
+
   return (
     <>
       <CommandInput placeholder="Search files, commands, or commits..." />
+      {activeRepo ? (
+        <div className="border-border/70 flex items-center gap-1 border-b px-2 py-1">
+          <PaletteFileScopeToggle
+            active={showAllFiles}
+            label="All files"
+            title="Include unchanged repository files"
+            onToggle={() => setShowAllFiles((value) => !value)}
+          />
+          <PaletteFileScopeToggle
+            active={showAllFiles && includeIgnoredFiles}
+            disabled={!showAllFiles}
+            label="Ignored"
+            title="Include gitignored files"
+            onToggle={() => setIncludeIgnoredFiles((value) => !value)}
+          />
+          <PaletteFileScopeToggle
+            active={showReflog}
+            label="Reflog"
+            title="Show HEAD reflog entries and diff each move"
+            onToggle={() => setShowReflog((value) => !value)}
+          />
+          <PaletteFileScopeToggle
+            active={showStashes}
+            label="Stashes"
+            title="Show stashes to diff or apply them"
+            onToggle={() => setShowStashes((value) => !value)}
+          />
+        </div>
+      ) : null}
       <CommandList className="max-h-[65vh]">
         <CommandEmpty>No matching commands.</CommandEmpty>
 
@@ -599,6 +995,62 @@ function AppCommandPaletteContent({ onOpenChange }: AppCommandPaletteContentProp
             ))}
           </CommandGroup>
         ) : null}
+
+        {reflogItems.length > 0 ? <CommandSeparator /> : null}
+        {reflogItems.length > 0 ? (
+          <CommandGroup heading="REFLOG">
+            {reflogItems.map((item) => (
+              <CommandItem
+                key={item.id}
+                value={commandItemSearchValue(item)}
+                disabled={item.disabled}
+                onSelect={() => {
+                  void runCommandItem(item, onOpenChange);
+                }}
+              >
+                <History className="h-4 w-4" />
+                <div className="flex min-w-0 flex-1 flex-col">
+                  <span className="truncate">{item.label}</span>
+                  <span className="text-muted-foreground truncate text-xs">
+                    {commandItemSubtitle(item)}
+                  </span>
+                </div>
+                <CommandShortcut>
+                  <GitCommitHorizontal className="h-3 w-3" />
+                  {item.shortId}
+                </CommandShortcut>
+              </CommandItem>
+            ))}
+          </CommandGroup>
+        ) : null}
+
+        {stashItems.length > 0 ? <CommandSeparator /> : null}
+        {stashItems.length > 0 ? (
+          <CommandGroup heading="STASHES">
+            {stashItems.map((item) => (
+              <CommandItem
+                key={item.id}
+                value={commandItemSearchValue(item)}
+                disabled={item.disabled}
+                onSelect={() => {
+                  void runCommandItem(item, onOpenChange);
+                }}
+              >
+                <Archive className="h-4 w-4" />
+                <div className="flex min-w-0 flex-1 flex-col">
+                  <span className="truncate">{item.label}</span>
+                  <span className="text-muted-foreground truncate text-xs">
+                    {commandItemSubtitle(item)}
+                  </span>
+                </div>
+                <CommandShortcut>
+                  <GitCommitHorizontal className="h-3 w-3" />
+                  {item.shortId}
+                </CommandShortcut>
+              </CommandItem>
+            ))}
+          </CommandGroup>
+        ) : null}
       </CommandList>
       <div className="border-border text-muted-foreground bg-surface-toolbar flex items-center justify-between border-t px-3 py-2 text-xs">
         <span>Select</span>
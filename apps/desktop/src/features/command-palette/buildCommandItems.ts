@@ -14,7 +14,7 @@ type ActionCandidate = {
 
 type FileCandidate = {
   path: string;
-  status: FileStatus;
+  status?: FileStatus;
   bucket?: Bucket;
   secondaryLabel?: string;
   keywords?: string[];
@@ -23,6 +23,7 @@ type FileCandidate = {
 };
 
 type CommitCandidate = {
+  id?: string;
   commitId: string;
   shortId: string;
   summary: string;
@@ -92,7 +93,7 @@ export function buildCommandFileItems(items: FileCandidate[]): CommandFileItem[]
 
 export function buildCommandCommitItems(items: CommitCandidate[]): CommandCommitItem[] {
   return items.map((item) => ({
-    id: `commit:${item.commitId}`,
+    id: item.id ?? `commit:${item.commitId}`,
     section: "history",
     label: item.summary || "(no commit message)",
     subtitle: `${item.shortId} · ${item.author || "Unknown"} · ${item.relativeTime}`,
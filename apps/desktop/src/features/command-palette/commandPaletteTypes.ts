@@ -19,7 +19,7 @@ export type CommandActionItem = CommandItemBase & {
 export type CommandFileItem = CommandItemBase & {
   section: "files";
   path: string;
-  status: FileStatus;
+  status?: FileStatus;
   bucket?: Bucket;
   onSelect: () => void | Promise<void>;
 };
@@ -1,6 +1,12 @@
 import { configureStore } from "@reduxjs/toolkit";
 import { beforeEach, describe, expect, it, vi } from "vitest";
 
+import { hydrateAppSettings, settingsReducer } from "@/features/settings/settingsSlice";
+import { gitApi } from "@/features/source-control/api";
+import { stageFile } from "@/features/source-control/services/git";
+import { desktop } from "@/platform/desktop";
+import { createAppSettings } from "@/platform/desktop/appSettings";
+
 import {
   setActivePath,
   setActiveRepo,
@@ -12,9 +18,21 @@ import {
   commentsClipboardReducer,
   setLastCopiedPayload,
 } from "@/features/comments/commentsClipboardSlice";
-import { addComment, copyComments, copyLastCommentsPayload } from "@/features/comments/actions";
+import {
+  addComment,
+  copyComments,
+  copyCommentsAsPlainList,
+  copyFixRequest,
+  copyLastCommentsPayload,
+  formatCommentsAsPlainList,
+} from "@/features/comments/actions";
 import { commentsReducer } from "@/features/comments/commentsSlice";
 
+vi.mock("@/features/source-control/services/git", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("@/features/source-control/services/git")>()),
+  stageFile: vi.fn(),
+}));
+
 type TestStore = ReturnType<typeof createTestStore>;
 
 function createComment(overrides: Partial<CommentItem>): CommentItem {
@@ -41,7 +59,10 @@ function createTestStore(comments: CommentItem[] = []) {
       sourceControl: sourceControlReducer,
       comments: commentsReducer,
       commentsClipboard: commentsClipboardReducer,
+      settings: settingsReducer,
+      [gitApi.reducerPath]: gitApi.reducer,
     },
+    middleware: (getDefaultMiddleware) => getDefaultMiddleware().concat(gitApi.middleware),
     preloadedState: {
       comments,
     },
@@ -130,6 +151,97 @@ describe("comments actions", () => {
     expect(commentIds(store)).toEqual(["c2", "c3"]);
   });
 
+  it("formats comments as a plain list sorted by file then line", () => {
+    expect(
+      formatCommentsAsPlainList([
+        createComment({ id: "c1", filePath: "src/z.ts", startLine: 3, endLine: 3, text: "later" }),
+        createComment({
+          id: "c2",
+          filePath: "src/a.ts",
+          startLine: 10,
+          endLine: 12,
+          text: "spans\nlines",
+        }),
+      ]),
+    ).toBe("src/a.ts:10-12 — spans lines\nsrc/z.ts:3 — later");
+  });
+
+  it("copies the plain list without clearing comments", async () => {
+    const writeText = vi.fn().mockResolvedValue(undefined);
+    mockClipboard(writeText);
+    const store = createTestStore([
+      createComment({ id: "c1", filePath: "src/other.ts", text: "second" }),
+      createComment({ id: "c2", filePath: "src/file.ts", startLine: 7, endLine: 7, text: "first" }),
+    ]);
+
+    const result = await store.dispatch(copyCommentsAsPlainList({ kind: "changes" }));
+
+    expect(result).toEqual({ ok: true, copiedCount: 2 });
+    expect(writeText).toHaveBeenCalledWith("src/file.ts:7 — first\nsrc/other.ts:1 — second");
+    expect(commentIds(store)).toEqual(["c1", "c2"]);
+  });
+
+  it("copies a fix request with a patch of the commented files", async () => {
+    const writeText = vi.fn().mockResolvedValue(undefined);
+    mockClipboard(writeText);
+    vi.spyOn(desktop, "getGitSnapshot").mockResolvedValue({
+      repoRoot: "/repo/a",
+      branch: "main",
+      unstaged: [
+        { path: "src/file.ts", previousPath: null, status: "modified" },
+        { path: "src/quiet.ts", previousPath: null, status: "modified" },
+      ],
+      staged: [],
+      untracked: [],
+    });
+    vi.spyOn(desktop, "getFileVersions").mockImplementation(async (_repoPath, relPath) => ({
+      oldFile: { name: relPath, contents: "one\n" },
+      newFile: { name: relPath, contents: "two\n" },
+      oldMode: "100755",
+      newMode: "100755",
+    }));
+    const store = createTestStore([
+      createComment({ id: "c1", filePath: "src/file.ts", text: "Keep one." }),
+    ]);
+
+    const result = await store.dispatch(copyFixRequest({ kind: "changes" }));
+    const payload = writeText.mock.calls[0]?.[0] as string;
+
+    expect(result).toEqual({ ok: true, copiedCount: 1 });
+    expect(payload).toMatch(/^@src\/file\.ts#L1 - Keep one\.\n\n```diff\n/);
+    expect(payload).toContain("diff --git a/src/file.ts b/src/file.ts\n");
+    expect(payload).toMatch(/index [0-9a-f]{7}\.\.[0-9a-f]{7} 100755\n/);
+    expect(payload).toContain("-one\n+two\n```");
+    expect(payload).not.toContain("src/quiet.ts");
+    expect(commentIds(store)).toEqual(["c1"]);
+  });
+
+  it("stages commented files before copying when the setting is on", async () => {
+    const calls: string[] = [];
+    vi.mocked(stageFile).mockImplementation(async (_repoPath, relPath) => {
+      calls.push(`stage:${relPath}`);
+    });
+    mockClipboard(
+      vi.fn().mockImplementation(async () => {
+        calls.push("copy");
+      }),
+    );
+    const store = createTestStore([
+      createComment({ id: "c1", filePath: "src/file.ts", text: "first" }),
+      createComment({ id: "c2", filePath: "src/staged.ts", bucket: "staged", text: "second" }),
+    ]);
+    store.dispatch(
+      hydrateAppSettings(
+        createAppSettings({ sourceControl: { stageCommentedFilesOnCopy: true } }),
+      ),
+    );
+
+    const result = await store.dispatch(copyComments("all", { context: { kind: "changes" } }));
+
+    expect(result.ok).toBe(true);
+    expect(calls).toEqual(["stage:src/file.ts", "copy"]);
+  });
+
   it("adds a review comment to an explicit target path override", () => {
     const store = createTestStore();
 
@@ -1,3 +1,5 @@
+import { toast } from "sonner";
+
 import type { AppThunk } from "@/app/store";
 import { desktop } from "@/platform/desktop";
 import {
@@ -10,13 +12,22 @@ import {
   removeCommentsByIds as removeCommentsByIdsAction,
   updateComment as updateCommentAction,
 } from "@/features/comments/commentsSlice";
+import {
+  buildFixRequest,
+  createReviewComment,
+  formatFixRequestPrompt,
+  loadReviewDiff,
+  type ReviewDiff,
+} from "@/features/comments/review";
+import { saveReviewedFiles, setFileReviewed } from "@/features/comments/reviewedFilesSlice";
+import { gitApi } from "@/features/source-control/api";
+import { errorMessageFrom } from "@/features/source-control/shared-utils/errorMessage";
 import type {
   Bucket,
   CommentContext,
   CommentItem,
   SelectionRange,
 } from "@/features/source-control/types";
-import { formatRange } from "@/features/source-control/utils";
 
 export type CopyCommentsResult = {
   ok: boolean;
@@ -49,31 +60,18 @@ export const addComment =
     targetPathOverride?: string,
   ): AppThunk =>
   (dispatch, getState) => {
-    const trimmed = text.trim();
     const { activeRepo, activePath, activeBucket, reviewActivePath } = getState().sourceControl;
     const targetPath =
       targetPathOverride ?? (context.kind === "review" ? reviewActivePath : activePath);
-    if (!trimmed || !activeRepo || !targetPath) return;
-
-    const side = range.side ?? "additions";
-    const endSide = range.endSide ?? side;
-    const id = `${Date.now()}-${Math.random().toString(36).slice(2, 8)}`;
-
-    const next: CommentItem = {
-      type: "annotation",
-      id,
+    const next = createReviewComment({
       repoPath: activeRepo,
       filePath: targetPath,
-      bucket: context.kind === "review" ? "unstaged" : activeBucket,
-      startLine: range.start,
-      endLine: range.end,
-      side,
-      endSide,
-      text: trimmed,
-      contextKind: context.kind,
-      baseRef: context.kind === "review" ? context.baseRef : undefined,
-      headRef: context.kind === "review" ? context.headRef : undefined,
-    };
+      bucket: activeBucket,
+      range,
+      text,
+      context,
+    });
+    if (!next) return;
 
     dispatch(addCommentAction(next));
     dispatch(clearLastCopiedPayload());
@@ -99,12 +97,118 @@ export const updateComment =
     dispatch(updateCommentAction({ id, text: trimmed }));
   };
 
-function copyPayloadForComments(source: CommentItem[]): string {
+function plainListLine(comment: CommentItem): string {
+  const line =
+    comment.startLine === comment.endLine
+      ? String(comment.startLine)
+      : `${comment.startLine}-${comment.endLine}`;
+  return `${comment.filePath}:${line} — ${comment.text.replace(/\s*\n\s*/g, " ")}`;
+}
+
+export function formatCommentsAsPlainList(source: CommentItem[]): string {
   return source
-    .map((c) => `@${c.filePath}#${formatRange(c.startLine, c.endLine)} - ${c.text}`)
+    .toSorted((a, b) => a.filePath.localeCompare(b.filePath) || a.startLine - b.startLine)
+    .map(plainListLine)
     .join("\n");
 }
 
+// Unlike copyComments this is a read-only dump, so the comments stay in place afterwards.
+export const copyCommentsAsPlainList =
+  (context?: CommentContext): AppThunk<Promise<{ ok: boolean; copiedCount: number }>> =>
+  async (_dispatch, getState) => {
+    const { comments } = getState();
+    const { activeRepo } = getState().sourceControl;
+    const source = comments.filter(
+      (c) => c.repoPath === activeRepo && isMatchingContext(c, context),
+    );
+    if (!activeRepo || source.length === 0) return { ok: false, copiedCount: 0 };
+
+    try {
+      await navigator.clipboard.writeText(formatCommentsAsPlainList(source));
+      return { ok: true, copiedCount: source.length };
+    } catch {
+      return { ok: false, copiedCount: 0 };
+    }
+  };
+
+// The working-tree diffs the comments were left on, one bucket at a time, keeping only the files
+// commented in that bucket so a path changed in two buckets is not patched twice.
+async function loadCommentedDiff(repoPath: string, source: CommentItem[]): Promise<ReviewDiff> {
+  const changes = source.filter((c) => contextForComment(c).kind === "changes");
+  const buckets = [...new Set(changes.map((c) => c.bucket))];
+  const diffs = await Promise.all(
+    buckets.map((bucket) => loadReviewDiff(desktop, repoPath, { kind: "bucket", bucket })),
+  );
+
+  return {
+    repoPath,
+    target: { kind: "bucket", bucket: buckets[0] ?? "unstaged" },
+    files: diffs.flatMap((diff, index) =>
+      diff.files.filter((file) =>
+        changes.some((c) => c.bucket === buckets[index] && c.filePath === file.path),
+      ),
+    ),
+  };
+}
+
+function formatFixRequestPayload(prompt: string, patch: string): string {
+  return patch ? `${prompt}\n\n\`\`\`diff\n${patch}\`\`\`` : prompt;
+}
+
+// The comments plus a patch of the files they are on, for an agent that can't read the working
+// tree. Like the plain list it leaves the comments in place. Review comments point at refs
+// rather than a bucket, so they go in the prompt without a patch.
+export const copyFixRequest =
+  (context?: CommentContext): AppThunk<Promise<{ ok: boolean; copiedCount: number }>> =>
+  async (_dispatch, getState) => {
+    const { comments } = getState();
+    const { activeRepo } = getState().sourceControl;
+    const source = comments.filter(
+      (c) => c.repoPath === activeRepo && isMatchingContext(c, context),
+    );
+    if (!activeRepo || source.length === 0) return { ok: false, copiedCount: 0 };
+
+    let diff: ReviewDiff;
+    try {
+      diff = await loadCommentedDiff(activeRepo, source);
+    } catch (error) {
+      const message = errorMessageFrom(error, "unknown error");
+      toast.error(`Failed to load the fix request diff: ${message}`);
+      return { ok: false, copiedCount: 0 };
+    }
+
+    const request = buildFixRequest(activeRepo, source, diff);
+    if (!request) return { ok: false, copiedCount: 0 };
+
+    try {
+      await navigator.clipboard.writeText(formatFixRequestPayload(request.prompt, request.patch));
+      return { ok: true, copiedCount: request.comments.length };
+    } catch {
+      return { ok: false, copiedCount: 0 };
+    }
+  };
+
+// Review comments point at fixed refs, so only working-tree comments have edits to stage.
+const stageCommentedFiles =
+  (repoPath: string, source: CommentItem[]): AppThunk<Promise<boolean>> =>
+  async (dispatch) => {
+    const paths = new Set(
+      source
+        .filter((c) => contextForComment(c).kind === "changes" && c.bucket !== "staged")
+        .map((c) => c.filePath),
+    );
+
+    try {
+      for (const relPath of paths) {
+        await dispatch(gitApi.endpoints.stageFile.initiate({ repoPath, relPath })).unwrap();
+      }
+      return true;
+    } catch (error) {
+      toast.error(`Failed to stage commented files: ${errorMessageFrom(error, "unknown error")}`);
+      return false;
+    }
+  };
+
 export const copyComments =
   (
     scope: "file" | "all",
@@ -129,7 +233,12 @@ export const copyComments =
 
     if (source.length === 0) return { ok: false, copiedCount: 0, clearedCount: 0 };
 
-    const payload = copyPayloadForComments(source);
+    if (getState().settings.appSettings.sourceControl.stageCommentedFilesOnCopy) {
+      const staged = await dispatch(stageCommentedFiles(activeRepo, source));
+      if (!staged) return { ok: false, copiedCount: 0, clearedCount: 0 };
+    }
+
+    const payload = formatFixRequestPrompt(source);
 
     try {
       await navigator.clipboard.writeText(payload);
@@ -176,6 +285,14 @@ export function toLineAnnotations(comments: CommentItem[]) {
   );
 }
 
+export const setFileReviewedAction =
+  (repoPath: string, path: string, reviewed: boolean): AppThunk =>
+  (dispatch, getState) => {
+    if (!repoPath || !path) return;
+    dispatch(setFileReviewed({ repoPath, path, reviewed }));
+    saveReviewedFiles(getState().reviewedFiles);
+  };
+
 export async function confirmDiscard(message: string): Promise<boolean> {
   try {
     return await desktop.confirm(message, {
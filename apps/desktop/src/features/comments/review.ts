@@ -0,0 +1,257 @@
+import { parseDiffFromFile, type FileDiffMetadata } from "@pierre/diffs";
+
+import type { DesktopApi } from "@/platform/desktop/contracts";
+import type {
+  Bucket,
+  CommentContext,
+  CommentItem,
+  FileItem,
+  SelectionRange,
+} from "@/features/source-control/types";
+import {
+  formatFilePatch,
+  type PatchFileChange,
+  type PatchFileModes,
+} from "@/features/source-control/hunkOperations";
+import { formatRange } from "@/features/source-control/utils";
+
+// The review flow without a store, clipboard, or renderer: load a diff, collect comments on it,
+// and turn them into the request handed to an agent. The app's thunks build on these, and
+// any other frontend can drive them with a backend that implements the same git calls.
+
+export type ReviewBackend = Pick<
+  DesktopApi,
+  "getGitSnapshot" | "getFileVersions" | "getCommitFiles" | "getCommitFileVersions"
+>;
+
+export type ReviewTarget =
+  | { kind: "bucket"; bucket: Bucket }
+  | { kind: "commit"; commitId: string; parentIndex?: number };
+
+export type ReviewDiffFile = FileItem &
+  PatchFileModes & {
+    // Null when the file has no text diff to show, such as a binary file.
+    fileDiff: FileDiffMetadata | null;
+  };
+
+export type ReviewDiff = {
+  repoPath: string;
+  target: ReviewTarget;
+  files: ReviewDiffFile[];
+};
+
+export type FixRequest = {
+  repoPath: string;
+  comments: CommentItem[];
+  prompt: string;
+  // A `git apply`-able patch of the commented files, empty when no diff was given.
+  patch: string;
+};
+
+async function loadFileDiff(
+  backend: ReviewBackend,
+  repoPath: string,
+  target: ReviewTarget,
+  file: FileItem,
+): Promise<Omit<ReviewDiffFile, keyof FileItem>> {
+  try {
+    const { oldFile, newFile, oldMode, newMode } =
+      target.kind === "bucket"
+        ? await backend.getFileVersions(repoPath, file.path, target.bucket)
+        : await backend.getCommitFileVersions(
+            repoPath,
+            target.commitId,
+            file.path,
+            file.previousPath ?? undefined,
+            target.parentIndex,
+          );
+    if (!oldFile && !newFile) return { fileDiff: null };
+
+    const fileDiff = parseDiffFromFile(
+      oldFile ?? { name: file.previousPath ?? file.path, contents: "" },
+      newFile ?? { name: file.path, contents: "" },
+    );
+    return { fileDiff, oldMode, newMode };
+  } catch {
+    return { fileDiff: null };
+  }
+}
+
+export async function loadReviewDiff(
+  backend: ReviewBackend,
+  repoPath: string,
+  target: ReviewTarget,
+): Promise<ReviewDiff> {
+  const files =
+    target.kind === "bucket"
+      ? (await backend.getGitSnapshot(repoPath))[target.bucket]
+      : await backend.getCommitFiles(repoPath, target.commitId, target.parentIndex);
+
+  return {
+    repoPath,
+    target,
+    files: await Promise.all(
+      files.map(async (file) => ({
+        ...file,
+        ...(await loadFileDiff(backend, repoPath, target, file)),
+      })),
+    ),
+  };
+}
+
+export function createCommentId() {
+  return `${Date.now()}-${Math.random().toString(36).slice(2, 8)}`;
+}
+
+export function createReviewComment({
+  repoPath,
+  filePath,
+  bucket,
+  range,
+  text,
+  context = { kind: "changes" },
+  id = createCommentId(),
+}: {
+  repoPath: string;
+  filePath: string;
+  bucket: Bucket;
+  range: SelectionRange;
+  text: string;
+  context?: CommentContext;
+  id?: string;
+}): CommentItem | null {
+  const trimmed = text.trim();
+  if (!trimmed || !repoPath || !filePath) return null;
+
+  const side = range.side ?? "additions";
+  return {
+    type: "annotation",
+    id,
+    repoPath,
+    filePath,
+    bucket: context.kind === "review" ? "unstaged" : bucket,
+    startLine: range.start,
+    endLine: range.end,
+    side,
+    endSide: range.endSide ?? side,
+    text: trimmed,
+    contextKind: context.kind,
+    baseRef: context.kind === "review" ? context.baseRef : undefined,
+    headRef: context.kind === "review" ? context.headRef : undefined,
+  };
+}
+
+// One `@path#L<range> - text` line per comment, the format agents are given to act on.
+export function formatFixRequestPrompt(comments: CommentItem[]): string {
+  return comments
+    .map((c) => `@${c.filePath}#${formatRange(c.startLine, c.endLine)} - ${c.text}`)
+    .join("\n");
+}
+
+function patchFileChange(file: FileItem): PatchFileChange {
+  if (file.status === "added" || file.status === "untracked") return "added";
+  return file.status === "deleted" ? "deleted" : "modified";
+}
+
+function patchFileModes({ oldMode, newMode }: ReviewDiffFile): PatchFileModes {
+  return { oldMode, newMode };
+}
+
+// Large enough for any reviewable change, small enough to leave an agent room to work.
+export const FIX_REQUEST_MAX_PATCH_BYTES = 256 * 1024;
+
+function byteLength(text: string) {
+  return new TextEncoder().encode(text).length;
+}
+
+// How many lines separate a comment from a hunk on the side it was left on; 0 when they overlap.
+function hunkDistance(hunk: FileDiffMetadata["hunks"][number], comment: CommentItem) {
+  const isDeletion = comment.side === "deletions";
+  const start = isDeletion ? hunk.deletionStart : hunk.additionStart;
+  const end = start + Math.max(isDeletion ? hunk.deletionCount : hunk.additionCount, 1) - 1;
+  if (comment.endLine < start) return start - comment.endLine;
+  return comment.startLine > end ? comment.startLine - end : 0;
+}
+
+function commentedHunkIndexes(fileDiff: FileDiffMetadata, comments: CommentItem[]) {
+  const indexes = new Set<number>();
+  for (const comment of comments) {
+    const distances = fileDiff.hunks.map((hunk) => hunkDistance(hunk, comment));
+    const nearest = distances.indexOf(Math.min(...distances));
+    if (nearest >= 0) indexes.add(nearest);
+  }
+  return [...indexes].sort((a, b) => a - b);
+}
+
+export type FixRequestPatch = {
+  patch: string;
+  // Why the patch is smaller than the diff, for the agent to read; null when nothing was cut.
+  truncationNote: string | null;
+};
+
+// The commented files of the diff, in diff order, as one patch. Over the byte cap each file keeps
+// only the hunks nearest its comments, and files that still do not fit are left out.
+export function formatFixRequestPatch(
+  diff: ReviewDiff,
+  comments: CommentItem[],
+  maxPatchBytes = FIX_REQUEST_MAX_PATCH_BYTES,
+): FixRequestPatch {
+  const files = diff.files.flatMap((file) => {
+    const fileComments = comments.filter((comment) => comment.filePath === file.path);
+    return file.fileDiff && fileComments.length > 0
+      ? [{ file, fileDiff: file.fileDiff, fileComments }]
+      : [];
+  });
+  const patch = files
+    .map(({ file, fileDiff }) =>
+      formatFilePatch(fileDiff, patchFileChange(file), undefined, patchFileModes(file)),
+    )
+    .join("");
+  if (byteLength(patch) <= maxPatchBytes) return { patch, truncationNote: null };
+
+  let truncated = "";
+  const omittedPaths: string[] = [];
+  for (const { file, fileDiff, fileComments } of files) {
+    const hunkIndexes = commentedHunkIndexes(fileDiff, fileComments);
+    const filePatch = formatFilePatch(
+      fileDiff,
+      patchFileChange(file),
+      hunkIndexes,
+      patchFileModes(file),
+    );
+    if (byteLength(truncated) + byteLength(filePatch) > maxPatchBytes) {
+      omittedPaths.push(file.path);
+      continue;
+    }
+    truncated += filePatch;
+  }
+
+  const omitted = omittedPaths.length > 0 ? ` and leaves out ${omittedPaths.join(", ")}` : "";
+  return {
+    patch: truncated,
+    truncationNote:
+      `The diff is over ${maxPatchBytes} bytes, so the patch only keeps the hunks nearest ` +
+      `each comment${omitted}.`,
+  };
+}
+
+export function buildFixRequest(
+  repoPath: string,
+  comments: CommentItem[],
+  diff?: ReviewDiff,
+  { maxPatchBytes = FIX_REQUEST_MAX_PATCH_BYTES }: { maxPatchBytes?: number } = {},
+): FixRequest | null {
+  const repoComments = comments.filter((comment) => comment.repoPath === repoPath);
+  if (repoComments.length === 0) return null;
+
+  const { patch, truncationNote } = diff
+    ? formatFixRequestPatch(diff, repoComments, maxPatchBytes)
+    : { patch: "", truncationNote: null };
+  const prompt = formatFixRequestPrompt(repoComments);
+  return {
+    repoPath,
+    comments: repoComments,
+    prompt: truncationNote ? `${prompt}\n\n${truncationNote}` : prompt,
+    patch,
+  };
+}
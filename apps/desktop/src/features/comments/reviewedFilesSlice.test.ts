@@ -0,0 +1,52 @@
+import { afterEach, describe, expect, it } from "vitest";
+
+import {
+  isFileReviewed,
+  loadReviewedFiles,
+  reviewedFilesReducer,
+  saveReviewedFiles,
+  setFileReviewed,
+} from "@/features/comments/reviewedFilesSlice";
+
+afterEach(() => {
+  window.localStorage.clear();
+});
+
+describe("reviewedFilesSlice", () => {
+  it("persists a reviewed file and restores it collapsed on reload", () => {
+    const state = reviewedFilesReducer(
+      {},
+      setFileReviewed({ repoPath: "/repo", path: "src/app.ts", reviewed: true }),
+    );
+    saveReviewedFiles(state);
+
+    const reloaded = reviewedFilesReducer(undefined, { type: "@@INIT" });
+
+    expect(loadReviewedFiles()).toEqual({ "/repo": ["src/app.ts"] });
+    expect(isFileReviewed(reloaded, "/repo", "src/app.ts")).toBe(true);
+    expect(isFileReviewed(reloaded, "/repo", "src/other.ts")).toBe(false);
+    expect(isFileReviewed(reloaded, "/other-repo", "src/app.ts")).toBe(false);
+  });
+
+  it("drops the repo entry once its last file is unmarked", () => {
+    const marked = reviewedFilesReducer(
+      {},
+      setFileReviewed({ repoPath: "/repo", path: "a.ts", reviewed: true }),
+    );
+
+    const unmarked = reviewedFilesReducer(
+      marked,
+      setFileReviewed({ repoPath: "/repo", path: "a.ts", reviewed: false }),
+    );
+
+    expect(unmarked).toEqual({});
+  });
+
+  it("ignores malformed stored values", () => {
+    window.localStorage.setItem("open-warden.reviewed-files", "[1,2]");
+    expect(loadReviewedFiles()).toEqual({});
+
+    window.localStorage.setItem("open-warden.reviewed-files", '{"/repo":["a.ts",3]}');
+    expect(loadReviewedFiles()).toEqual({ "/repo": ["a.ts"] });
+  });
+});
@@ -0,0 +1,68 @@
+import { createSlice, type PayloadAction } from "@reduxjs/toolkit";
+
+const STORAGE_KEY = "open-warden.reviewed-files";
+
+type ReviewedFilesState = Record<string, string[]>;
+
+function getLocalStorage(): Storage | null {
+  try {
+    return window.localStorage;
+  } catch {
+    return null;
+  }
+}
+
+export function loadReviewedFiles(): ReviewedFilesState {
+  const rawValue = getLocalStorage()?.getItem(STORAGE_KEY);
+  if (!rawValue) return {};
+
+  try {
+    const parsed: unknown = JSON.parse(rawValue);
+    if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) return {};
+
+    const reviewedFiles: ReviewedFilesState = {};
+    for (const [repoPath, paths] of Object.entries(parsed)) {
+      if (!Array.isArray(paths)) continue;
+      reviewedFiles[repoPath] = paths.filter((path): path is string => typeof path === "string");
+    }
+    return reviewedFiles;
+  } catch {
+    return {};
+  }
+}
+
+export function saveReviewedFiles(state: ReviewedFilesState): void {
+  getLocalStorage()?.setItem(STORAGE_KEY, JSON.stringify(state));
+}
+
+export function isFileReviewed(state: ReviewedFilesState, repoPath: string, path: string) {
+  return state[repoPath]?.includes(path) ?? false;
+}
+
+const reviewedFilesSlice = createSlice({
+  name: "reviewedFiles",
+  initialState: loadReviewedFiles,
+  reducers: {
+    setFileReviewed(
+      state,
+      action: PayloadAction<{ repoPath: string; path: string; reviewed: boolean }>,
+    ) {
+      const { repoPath, path, reviewed } = action.payload;
+      const paths = state[repoPath] ?? [];
+      const nextPaths = reviewed
+        ? paths.includes(path)
+          ? paths
+          : [...paths, path]
+        : paths.filter((reviewedPath) => reviewedPath !== path);
+
+      if (nextPaths.length === 0) {
+        delete state[repoPath];
+      } else {
+        state[repoPath] = nextPaths;
+      }
+    },
+  },
+});
+
+export const { setFileReviewed } = reviewedFilesSlice.actions;
+export const reviewedFilesReducer = reviewedFilesSlice.reducer;
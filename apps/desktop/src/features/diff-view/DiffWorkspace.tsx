@@ -1,7 +1,9 @@
 import { useCallback, useMemo, useRef } from "react";
-import { Minus, Plus, Trash2 } from "lucide-react";
+import { Copy, Minus, Plus, Trash2, type LucideIcon } from "lucide-react";
+import { useNavigate } from "react-router";
+import { toast } from "sonner";
 
-import { useAppSelector } from "@/app/hooks";
+import { useAppDispatch, useAppSelector } from "@/app/hooks";
 import type { MentionConfig } from "@/components/markdown/MarkdownEditor";
 import { DiagnosticTokenPopover } from "@/features/diff-view/components/DiagnosticTokenPopover";
 import { DiffHeaderMetadataControls } from "@/features/diff-view/components/DiffHeaderMetadataControls";
@@ -22,14 +24,25 @@ import type {
   LspDiagnostic,
 } from "@/features/source-control/types";
 import type {
+  DiffHunkAction,
   DiffHunkActionAnnotation,
   DiffHunkActionPayload,
   DiffHunkOperation,
+  DiffLinesActionAnnotation,
+  DiffLinesActionPayload,
 } from "@/features/source-control/hunkOperations";
 import { DiffViewer, type DiffViewerHandle } from "@/features/diff-view/components/DiffViewer";
 import { useDiffCommentAnnotations } from "@/features/diff-view/hooks/useDiffCommentAnnotations";
 import { useDiffDiagnostics } from "@/features/diff-view/hooks/useDiffDiagnostics";
 import { useDiffAnnotationRenderer } from "@/features/diff-view/hooks/useDiffAnnotationRenderer";
+import { resolveDiffInteraction } from "@/features/diff-view/diffInteraction";
+import {
+  blameRevision,
+  diffLineReference,
+  type DiffLineSource,
+} from "@/features/diff-view/services/lineReference";
+import { openLineBlameAction } from "@/features/source-control/actions";
+import { WORKTREE_REF } from "@/platform/desktop/gitRefs";
 import { type DiffLineAnnotation, type FileDiffOptions } from "@pierre/diffs";
 
 type Props = {
@@ -52,8 +65,84 @@ type Props = {
   hideHeaderMetadataControls?: boolean;
   hunkOperations?: DiffHunkOperation[];
   onHunkAction?: (operation: DiffHunkOperation, payload: DiffHunkActionPayload) => void;
+  // Stage, unstage or discard just the changed lines inside the current line selection.
+  onLinesAction?: (operation: DiffHunkOperation, payload: DiffLinesActionPayload) => void;
+  readOnly?: boolean;
+  // Where copied line references point; defaults to the bucket or review head being shown.
+  lineReferenceSource?: DiffLineSource;
+  // Alt-click opens the commit that last touched a line; off for diffs outside the repo.
+  canBlame?: boolean;
 };
 
+const HUNK_ACTION_META = {
+  stage: { label: "Stage hunk", Icon: Plus },
+  unstage: { label: "Unstage hunk", Icon: Minus },
+  discard: { label: "Discard hunk", Icon: Trash2 },
+  copy: { label: "Copy hunk as patch", Icon: Copy },
+} as const;
+
+const LINES_ACTION_META = {
+  stage: { label: "Stage selected lines", Icon: Plus },
+  unstage: { label: "Unstage selected lines", Icon: Minus },
+  discard: { label: "Discard selected lines", Icon: Trash2 },
+} as const;
+
+function DiffActionButtons<Action extends DiffHunkAction>({
+  operations,
+  meta,
+  onAction,
+}: {
+  operations: Action[];
+  meta: Record<Action, { label: string; Icon: LucideIcon }>;
+  onAction: (operation: Action) => void;
+}) {
+  return (
+    <div className="flex justify-end gap-1 px-2 py-0">
+      {operations.map((operation) => {
+        const { label, Icon } = meta[operation];
+
+        return (
+          <button
+            key={operation}
+            type="button"
+            title={label}
+            aria-label={label}
+            className="inline-flex h-5 w-5 items-center justify-center rounded-xs border border-border/60 bg-background/90 text-muted-foreground shadow-sm transition-[background-color,color,scale] hover:bg-surface-1 hover:text-foreground active:scale-[0.96]"
+            onClick={(event) => {
+              event.preventDefault();
+              event.stopPropagation();
+              onAction(operation);
+            }}
+          >
+            <Icon className="h-3 w-3" />
+          </button>
+        );
+      })}
+    </div>
+  );
+}
+
+function defaultLineReferenceSource(
+  commentContext: CommentContext,
+  activeBucket: "staged" | "unstaged" | "untracked",
+): DiffLineSource {
+  if (commentContext.kind === "review" && commentContext.headRef !== WORKTREE_REF) {
+    return { kind: "commit", commitId: commentContext.headRef };
+  }
+
+  return commentContext.kind === "changes" && activeBucket === "staged"
+    ? { kind: "index" }
+    : { kind: "worktree" };
+}
+
+function isLineReferenceClick(event: MouseEvent) {
+  return event.shiftKey && !event.metaKey && !event.ctrlKey && !event.altKey;
+}
+
+function isBlameClick(event: MouseEvent) {
+  return event.altKey && !event.shiftKey && !event.metaKey && !event.ctrlKey;
+}
+
 function buildReturnToDiffTarget(
   jumpContextKind: "changes" | "review" | "pull-request",
   source: { lineNumber: number; lineIndex: string | null },
@@ -122,7 +211,13 @@ export function DiffWorkspace({
   hideHeaderMetadataControls = false,
   hunkOperations = [],
   onHunkAction,
+  onLinesAction,
+  readOnly = false,
+  lineReferenceSource,
+  canBlame = true,
 }: Props) {
+  const dispatch = useAppDispatch();
+  const navigate = useNavigate();
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const activeBucket = useAppSelector((state) => state.sourceControl.activeBucket);
   const jumpContext = lspJumpContextKind ?? commentContext.kind;
@@ -160,7 +255,7 @@ export function DiffWorkspace({
   const comments = useDiffCommentAnnotations({
     activePath,
     commentContext,
-    canComment,
+    canComment: canComment && !readOnly,
     includeCurrentFileComments,
     commentMentions,
   });
@@ -169,48 +264,68 @@ export function DiffWorkspace({
     NonNullable<FileDiffOptions<DiffAnnotationItem>["onTokenClick"]>
   >(
     (props, event) => {
+      const source =
+        lineReferenceSource ?? defaultLineReferenceSource(commentContext, activeBucket);
+      if (isLineReferenceClick(event)) {
+        event.preventDefault();
+        const reference = diffLineReference(source, activePath, props.lineNumber, props.side);
+        navigator.clipboard.writeText(reference).then(
+          () => toast.success(`Copied ${reference}`),
+          () => toast.error("Failed to copy line reference"),
+        );
+        return;
+      }
+
+      if (canBlame && isBlameClick(event)) {
+        event.preventDefault();
+        const revision = blameRevision(source, props.side);
+        void dispatch(openLineBlameAction(activePath, props.lineNumber, revision)).then(
+          (opened) => {
+            if (opened) navigate("/history");
+          },
+        );
+        return;
+      }
+
       if (onHoverTokenClick(props, event)) {
         return;
       }
 
       onNavigationTokenClick(props, event);
     },
-    [onHoverTokenClick, onNavigationTokenClick],
+    [
+      activeBucket,
+      activePath,
+      canBlame,
+      commentContext,
+      dispatch,
+      lineReferenceSource,
+      navigate,
+      onHoverTokenClick,
+      onNavigationTokenClick,
+    ],
   );
 
   const renderAnnotation = useDiffAnnotationRenderer({
-    "hunk-action": (data: DiffHunkActionAnnotation) => {
-      const actionMeta = {
-        stage: { label: "Stage hunk", Icon: Plus },
-        unstage: { label: "Unstage hunk", Icon: Minus },
-        discard: { label: "Discard hunk", Icon: Trash2 },
-      } as const;
-
-      return (
-        <div className="flex justify-end gap-1 px-2 py-0">
-          {data.operations.map((operation) => {
-            const { label, Icon } = actionMeta[operation];
-
-            return (
-              <button
-                key={operation}
-                type="button"
-                title={label}
-                aria-label={label}
-                className="inline-flex h-5 w-5 items-center justify-center rounded-xs border border-border/60 bg-background/90 text-muted-foreground shadow-sm transition-[background-color,color,scale] hover:bg-surface-1 hover:text-foreground active:scale-[0.96]"
-                onClick={(event) => {
-                  event.preventDefault();
-                  event.stopPropagation();
-                  data.onAction(operation, { fileDiff: data.fileDiff, hunkIndex: data.hunkIndex });
-                }}
-              >
-                <Icon className="h-3 w-3" />
-              </button>
-            );
-          })}
-        </div>
-      );
-    },
+    "hunk-action": (data: DiffHunkActionAnnotation) => (
+      <DiffActionButtons
+        operations={data.operations}
+        meta={HUNK_ACTION_META}
+        onAction={(operation) =>
+          data.onAction(operation, { fileDiff: data.fileDiff, hunkIndex: data.hunkIndex })
+        }
+      />
+    ),
+    "line-action": (data: DiffLinesActionAnnotation) => (
+      <DiffActionButtons
+        operations={data.operations}
+        meta={LINES_ACTION_META}
+        onAction={(operation) => {
+          data.onAction(operation, { fileDiff: data.fileDiff, range: data.range });
+          comments.onCloseCommentComposer();
+        }}
+      />
+    ),
     composer: comments.renderCommentAnnotation,
     "pull-request-anchor": (data) => (
       <PullRequestInlineAnchorAnnotation
@@ -239,22 +354,33 @@ export function DiffWorkspace({
     [comments.annotations, annotationItems],
   );
 
+  const interaction = resolveDiffInteraction({
+    readOnly,
+    canComment,
+    hunkOperations,
+    onHunkAction,
+    onLinesAction,
+    onLineSelected: comments.onLineSelected,
+    onLineSelectionEnd: comments.onLineSelectionEnd,
+  });
+
   const options = useMemo<Partial<FileDiffOptions<DiffAnnotationItem>>>(
     () => ({
       disableFileHeader,
-      enableLineSelection: canComment,
-      enableGutterUtility: canComment,
+      enableLineSelection: interaction.enableLineSelection,
+      enableGutterUtility: interaction.enableGutterUtility,
       onTokenClick: handleTokenClick,
       onTokenEnter: diagnostics.onTokenEnter,
       onTokenLeave: diagnostics.onTokenLeave,
-      onLineSelected: canComment ? comments.onLineSelected : undefined,
-      onLineSelectionEnd: canComment ? comments.onLineSelectionEnd : undefined,
+      onLineSelected: interaction.onLineSelected,
+      onLineSelectionEnd: interaction.onLineSelectionEnd,
       onPostRender: diagnostics.onPostRender,
     }),
     [
-      canComment,
-      comments.onLineSelected,
-      comments.onLineSelectionEnd,
+      interaction.enableLineSelection,
+      interaction.enableGutterUtility,
+      interaction.onLineSelected,
+      interaction.onLineSelectionEnd,
       diagnostics.onPostRender,
       diagnostics.onTokenEnter,
       diagnostics.onTokenLeave,
@@ -272,7 +398,7 @@ export function DiffWorkspace({
       return (
         <DiffHeaderMetadataControls
           activePath={activePath}
-          canComment={canComment}
+          canComment={interaction.canComment}
           commentContext={commentContext}
           expandUnchanged={controls.expandUnchanged}
           fileViewerRevision={fileViewerRevision}
@@ -280,7 +406,13 @@ export function DiffWorkspace({
         />
       );
     },
-    [activePath, canComment, commentContext, fileViewerRevision, hideHeaderMetadataControls],
+    [
+      activePath,
+      interaction.canComment,
+      commentContext,
+      fileViewerRevision,
+      hideHeaderMetadataControls,
+    ],
   );
 
   return (
@@ -305,8 +437,9 @@ export function DiffWorkspace({
         focusedLineNumber={focusedLineNumber}
         focusedLineIndex={focusedLineIndex}
         focusedLineKey={focusedLineKey}
-        hunkOperations={hunkOperations}
-        onHunkAction={onHunkAction}
+        hunkOperations={interaction.hunkOperations}
+        onHunkAction={interaction.onHunkAction}
+        onLinesAction={interaction.onLinesAction}
       >
         <LspSymbolPeekContainer
           document={lspHoverDocument}
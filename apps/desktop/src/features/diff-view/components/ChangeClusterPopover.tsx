@@ -0,0 +1,196 @@
+import { forwardRef, useImperativeHandle, useRef, useState } from "react";
+import { Copy } from "lucide-react";
+import { toast } from "sonner";
+import type { DiffTokenEventBaseProps, FileDiffMetadata } from "@pierre/diffs";
+
+import { useAppSelector } from "@/app/hooks";
+import { Button } from "@/components/ui/button";
+import { Popover, PopoverAnchor, PopoverContent } from "@/components/ui/popover";
+import { findChangeCluster, type LineCluster } from "@/features/diff-view/services/changeClusters";
+
+type AnchorRect = {
+  top: number;
+  left: number;
+  width: number;
+  height: number;
+};
+
+type Props = {
+  open: boolean;
+  anchorRect: AnchorRect | null;
+  cluster: LineCluster | null;
+  onClose: () => void;
+  onPointerEnter: () => void;
+  onPointerLeave: () => void;
+};
+
+function readLineAnchorRect(tokenElement: HTMLElement): AnchorRect {
+  const lineElement = tokenElement.closest<HTMLElement>("[data-line]") ?? tokenElement;
+  const rect = lineElement.getBoundingClientRect();
+  return {
+    top: rect.top,
+    left: rect.left,
+    width: rect.width,
+    height: rect.height,
+  };
+}
+
+export type ChangeClusterPreviewHandle = {
+  onTokenEnter: (props: DiffTokenEventBaseProps) => void;
+  onTokenLeave: () => void;
+};
+
+// Owns the hover state, so moving over the diff re-renders this popover instead of the diff.
+export const ChangeClusterPreview = forwardRef<
+  ChangeClusterPreviewHandle,
+  { fileDiff: FileDiffMetadata | null }
+>(function ChangeClusterPreview({ fileDiff }, ref) {
+  const enabled = useAppSelector((state) => state.sourceControl.diffClusterPreview);
+  const closeTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
+  const isPopoverHoveredRef = useRef(false);
+  const [state, setState] = useState<{
+    open: boolean;
+    cluster: LineCluster | null;
+    anchorRect: AnchorRect | null;
+  }>({
+    open: false,
+    cluster: null,
+    anchorRect: null,
+  });
+
+  const clearCloseTimer = () => {
+    if (closeTimerRef.current) {
+      clearTimeout(closeTimerRef.current);
+      closeTimerRef.current = null;
+    }
+  };
+
+  const closePopover = () => {
+    clearCloseTimer();
+    setState({ open: false, cluster: null, anchorRect: null });
+  };
+
+  const onTokenEnter = (props: DiffTokenEventBaseProps) => {
+    if (!enabled || !fileDiff) return;
+    clearCloseTimer();
+
+    const cluster = findChangeCluster(fileDiff, props.side, props.lineNumber);
+    if (!cluster) {
+      closePopover();
+      return;
+    }
+
+    // Moving within the same cluster keeps the popover where it first opened.
+    setState((current) =>
+      current.cluster?.kind === cluster.kind && current.cluster.startLine === cluster.startLine
+        ? current
+        : { open: true, cluster, anchorRect: readLineAnchorRect(props.tokenElement) },
+    );
+  };
+
+  const onTokenLeave = () => {
+    clearCloseTimer();
+    closeTimerRef.current = setTimeout(() => {
+      if (!isPopoverHoveredRef.current) {
+        closePopover();
+      }
+    }, 120);
+  };
+
+  const onPopoverEnter = () => {
+    isPopoverHoveredRef.current = true;
+    clearCloseTimer();
+  };
+
+  const onPopoverLeave = () => {
+    isPopoverHoveredRef.current = false;
+    closePopover();
+  };
+
+  useImperativeHandle(ref, () => ({ onTokenEnter, onTokenLeave }));
+
+  return (
+    <ChangeClusterPopover
+      open={state.open}
+      anchorRect={state.anchorRect}
+      cluster={state.cluster}
+      onClose={closePopover}
+      onPointerEnter={onPopoverEnter}
+      onPointerLeave={onPopoverLeave}
+    />
+  );
+});
+
+function clusterLabel(cluster: LineCluster) {
+  const lineCount = cluster.endLine - cluster.startLine + 1;
+  const range =
+    lineCount === 1 ? `line ${cluster.startLine}` : `lines ${cluster.startLine}-${cluster.endLine}`;
+  return `${cluster.kind === "deletion" ? "Removed" : "Added"} ${range}`;
+}
+
+// The removed or added run under the pointer as one plain block, free of the interleaved
+// other side, so it can be read and copied on its own.
+function ChangeClusterPopover({
+  open,
+  anchorRect,
+  cluster,
+  onClose,
+  onPointerEnter,
+  onPointerLeave,
+}: Props) {
+  if (!anchorRect || !cluster) {
+    return null;
+  }
+
+  const onCopy = () => {
+    navigator.clipboard.writeText(cluster.text).then(
+      () => toast.success(`Copied ${clusterLabel(cluster).toLowerCase()}`),
+      () => toast.error("Failed to copy lines"),
+    );
+  };
+
+  return (
+    <Popover
+      open={open}
+      onOpenChange={(nextOpen) => {
+        if (!nextOpen) {
+          onClose();
+        }
+      }}
+    >
+      <PopoverAnchor asChild>
+        <div
+          aria-hidden
+          className="pointer-events-none fixed"
+          style={{
+            top: anchorRect.top,
+            left: anchorRect.left,
+            width: Math.max(anchorRect.width, 1),
+            height: Math.max(anchorRect.height, 1),
+          }}
+        />
+      </PopoverAnchor>
+      <PopoverContent
+        align="start"
+        side="bottom"
+        sideOffset={4}
+        collisionPadding={8}
+        className="flex w-[560px] max-h-[360px] flex-col rounded-none p-0"
+        style={{ maxWidth: "calc(100vw - 16px)" }}
+        onPointerEnter={onPointerEnter}
+        onPointerLeave={onPointerLeave}
+        onOpenAutoFocus={(event) => event.preventDefault()}
+      >
+        <div className="border-border flex items-center justify-between gap-2 border-b px-2 py-1">
+          <span className="text-muted-foreground text-[11px]">{clusterLabel(cluster)}</span>
+          <Button size="icon-xs" variant="ghost" onClick={onCopy} aria-label="Copy lines">
+            <Copy />
+          </Button>
+        </div>
+        <pre className="min-h-0 flex-1 overflow-auto p-2 font-mono text-xs select-text">
+          {cluster.text}
+        </pre>
+      </PopoverContent>
+    </Popover>
+  );
+}
@@ -1,15 +1,36 @@
-import { BookOpenText, Columns2, Copy, FoldVertical, Rows3, UnfoldVertical } from "lucide-react";
+import {
+  BookOpenText,
+  Columns2,
+  Copy,
+  FoldVertical,
+  ListCollapse,
+  RotateCcw,
+  Rows3,
+  TextSelect,
+  UnfoldVertical,
+  WrapText,
+} from "lucide-react";
 import { useHotkey } from "@tanstack/react-hotkeys";
 import { toast } from "sonner";
 import { useNavigate } from "react-router";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
 import { Button } from "@/components/ui/button";
+import { Checkbox } from "@/components/ui/checkbox";
 import { Tooltip, TooltipContent, TooltipProvider, TooltipTrigger } from "@/components/ui/tooltip";
-import { copyComments, fileComments } from "@/features/comments/actions";
+import { copyComments, fileComments, setFileReviewedAction } from "@/features/comments/actions";
+import { isFileReviewed } from "@/features/comments/reviewedFilesSlice";
 import { compactComments } from "@/features/comments/selectors";
+import { useGetGitSnapshotQuery } from "@/features/source-control/api";
 import { OpenInExternalEditor } from "@/features/source-control/components/OpenInExternalEditor";
-import { setDiffStyleValue } from "@/features/source-control/actions";
+import {
+  restoreDeletedFileAction,
+  setDiffStyleValue,
+  toggleDiffChangesOnly,
+  toggleDiffClusterPreview,
+  toggleDiffStyle,
+  toggleDiffWrap,
+} from "@/features/source-control/actions";
 import { openFileViewer } from "@/features/source-control/sourceControlSlice";
 import type { CommentContext } from "@/features/source-control/types";
 
@@ -38,7 +59,25 @@ export function DiffHeaderMetadataControls({
   const navigate = useNavigate();
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const diffStyle = useAppSelector((state) => state.sourceControl.diffStyle);
+  const diffWrap = useAppSelector((state) => state.sourceControl.diffWrap);
+  const diffChangesOnly = useAppSelector((state) => state.sourceControl.diffChangesOnly);
+  const diffClusterPreview = useAppSelector((state) => state.sourceControl.diffClusterPreview);
+  const activeBucket = useAppSelector((state) => state.sourceControl.activeBucket);
+  const hasRunningAction = useAppSelector((state) => state.sourceControl.runningAction !== "");
+  const { isDeleted } = useGetGitSnapshotQuery(activeRepo, {
+    skip: commentContext.kind !== "changes" || !activeRepo || activeBucket === "untracked",
+    selectFromResult: ({ data }) => ({
+      isDeleted:
+        activeBucket !== "untracked" &&
+        !!data?.[activeBucket].some(
+          (file) => file.path === activePath && file.status === "deleted",
+        ),
+    }),
+  });
   const comments = useAppSelector((state) => state.comments);
+  const isReviewed = useAppSelector((state) =>
+    isFileReviewed(state.reviewedFiles, activeRepo, activePath),
+  );
   const expandUnchangedLabel = expandUnchanged
     ? "Collapse unchanged sections"
     : "Expand unchanged sections";
@@ -63,6 +102,8 @@ export function DiffHeaderMetadataControls({
     },
   );
 
+  useHotkey("Mod+Alt+D", () => dispatch(toggleDiffStyle()), { enabled: !!activePath });
+
   return (
     <TooltipProvider>
       <div className="flex items-center gap-0.5">
@@ -77,7 +118,7 @@ export function DiffHeaderMetadataControls({
               <Columns2 />
             </Button>
           </TooltipTrigger>
-          <TooltipContent side="bottom">Split diff</TooltipContent>
+          <TooltipContent side="bottom">Split diff (⌘⌥D)</TooltipContent>
         </Tooltip>
 
         <Tooltip>
@@ -91,7 +132,52 @@ export function DiffHeaderMetadataControls({
               <Rows3 />
             </Button>
           </TooltipTrigger>
-          <TooltipContent side="bottom">Unified diff</TooltipContent>
+          <TooltipContent side="bottom">Unified diff (⌘⌥D)</TooltipContent>
+        </Tooltip>
+
+        <Tooltip>
+          <TooltipTrigger asChild>
+            <Button
+              size="icon-xs"
+              variant={diffWrap ? "secondary" : "ghost"}
+              onClick={() => dispatch(toggleDiffWrap())}
+              aria-label="Wrap long lines"
+              aria-pressed={diffWrap}
+            >
+              <WrapText />
+            </Button>
+          </TooltipTrigger>
+          <TooltipContent side="bottom">Wrap long lines</TooltipContent>
+        </Tooltip>
+
+        <Tooltip>
+          <TooltipTrigger asChild>
+            <Button
+              size="icon-xs"
+              variant={diffChangesOnly ? "secondary" : "ghost"}
+              onClick={() => dispatch(toggleDiffChangesOnly())}
+              aria-label="Show only changed lines"
+              aria-pressed={diffChangesOnly}
+            >
+              <ListCollapse />
+            </Button>
+          </TooltipTrigger>
+          <TooltipContent side="bottom">Show only changed lines</TooltipContent>
+        </Tooltip>
+
+        <Tooltip>
+          <TooltipTrigger asChild>
+            <Button
+              size="icon-xs"
+              variant={diffClusterPreview ? "secondary" : "ghost"}
+              onClick={() => dispatch(toggleDiffClusterPreview())}
+              aria-label="Preview changed blocks on hover"
+              aria-pressed={diffClusterPreview}
+            >
+              <TextSelect />
+            </Button>
+          </TooltipTrigger>
+          <TooltipContent side="bottom">Preview changed blocks on hover</TooltipContent>
         </Tooltip>
 
         <Tooltip>
@@ -146,6 +232,40 @@ export function DiffHeaderMetadataControls({
           </Tooltip>
         ) : null}
 
+        {commentContext.kind === "changes" && isDeleted ? (
+          <Tooltip>
+            <TooltipTrigger asChild>
+              <Button
+                size="icon-xs"
+                variant="ghost"
+                onClick={() => {
+                  void dispatch(restoreDeletedFileAction(activeBucket, activePath));
+                }}
+                disabled={hasRunningAction}
+                aria-label="Restore deleted file"
+              >
+                <RotateCcw />
+              </Button>
+            </TooltipTrigger>
+            <TooltipContent side="bottom">
+              {activeBucket === "staged" ? "Restore from HEAD" : "Restore from the index"}
+            </TooltipContent>
+          </Tooltip>
+        ) : null}
+
+        {commentContext.kind === "review" && activePath ? (
+          <label className="text-muted-foreground hover:text-foreground ml-1 flex cursor-pointer items-center gap-1.5 px-1 text-xs">
+            <Checkbox
+              checked={isReviewed}
+              onCheckedChange={(checked) => {
+                dispatch(setFileReviewedAction(activeRepo, activePath, checked === true));
+              }}
+              aria-label="Mark file as viewed"
+            />
+            Viewed
+          </label>
+        ) : null}
+
         {canComment ? (
           <Tooltip>
             <TooltipTrigger asChild>
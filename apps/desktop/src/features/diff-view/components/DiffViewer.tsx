@@ -8,8 +8,9 @@ import {
   type RefObject,
 } from "react";
 import { FileDiff as PierreFileDiff, Virtualizer } from "@pierre/diffs/react";
-import { FileWarning } from "lucide-react";
+import { FileWarning, FoldVertical, HardDrive, ShieldAlert } from "lucide-react";
 import { useTheme } from "next-themes";
+import { toast } from "sonner";
 
 import { useAppSelector } from "@/app/hooks";
 import { Button } from "@/components/ui/button";
@@ -22,18 +23,34 @@ import {
   EmptyTitle,
 } from "@/components/ui/empty";
 import type { DiffAnnotationItem, DiffFile, SelectionRange } from "@/features/source-control/types";
-import type {
-  DiffHunkActionAnnotation,
-  DiffHunkActionPayload,
-  DiffHunkOperation,
+import {
+  formatHunkPatch,
+  selectedChangeLines,
+  type DiffHunkAction,
+  type DiffHunkActionAnnotation,
+  type DiffHunkActionPayload,
+  type DiffHunkOperation,
+  type DiffLinesActionAnnotation,
+  type DiffLinesActionPayload,
 } from "@/features/source-control/hunkOperations";
+import {
+  ChangeClusterPreview,
+  type ChangeClusterPreviewHandle,
+} from "@/features/diff-view/components/ChangeClusterPopover";
 import {
   getDiffTheme,
   getDiffThemeCacheSalt,
   getDiffThemeType,
 } from "@/features/diff-view/diffRenderConfig";
 import { useParsedDiff } from "@/features/diff-view/hooks/useParsedDiff";
+import { useRepoPrimaryLanguage } from "@/features/diff-view/hooks/useRepoPrimaryLanguage";
 import { MAX_DIFF_LINE_LENGTH } from "@/features/diff-view/services/diffRenderLimits";
+import { changesOnlyCSS } from "@/features/diff-view/services/changesOnly";
+import { isCollapsedByDefault } from "@/features/diff-view/services/collapsedPaths";
+import { formatLfsPointer, parseLfsPointer } from "@/features/diff-view/services/lfsPointer";
+import { diffWrapCSS, diffWrapModeForPath } from "@/features/diff-view/services/wrapMode";
+import { findOverlongAddedLines, overlongLineCSS } from "@/features/diff-view/services/lineLength";
+import { scanAddedLines } from "@/features/diff-view/services/secretScan";
 import { useDiffLineFocus, DIFF_LINE_FOCUS_CSS } from "@/features/source-control/diffLineFocus";
 import {
   type DiffLineAnnotation,
@@ -41,6 +58,9 @@ import {
   type FileDiffOptions,
 } from "@pierre/diffs";
 
+type TokenEnterHandler = NonNullable<FileDiffOptions<DiffAnnotationItem>["onTokenEnter"]>;
+type TokenLeaveHandler = NonNullable<FileDiffOptions<DiffAnnotationItem>["onTokenLeave"]>;
+
 export type DiffViewerHandle = {
   getViewportElement: () => HTMLDivElement | null;
 };
@@ -62,6 +82,7 @@ export type DiffViewerProps = {
   focusedLineKey?: number | string | null;
   hunkOperations?: DiffHunkOperation[];
   onHunkAction?: (operation: DiffHunkOperation, payload: DiffHunkActionPayload) => void;
+  onLinesAction?: (operation: DiffHunkOperation, payload: DiffLinesActionPayload) => void;
   children?: React.ReactNode;
 };
 
@@ -181,15 +202,28 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
     focusedLineKey = null,
     hunkOperations = [],
     onHunkAction,
+    onLinesAction,
     children,
   },
   ref,
 ) {
   const { resolvedTheme } = useTheme();
   const diffStyle = useAppSelector((state) => state.sourceControl.diffStyle);
+  const diffWrap = useAppSelector((state) => state.sourceControl.diffWrap);
+  const diffChangesOnly = useAppSelector((state) => state.sourceControl.diffChangesOnly);
+  const maxHighlightBytes = useAppSelector(
+    (state) => state.settings.appSettings.diffView.maxHighlightBytes,
+  );
+  const maxLineLength = useAppSelector(
+    (state) => state.settings.appSettings.diffView.maxLineLength,
+  );
+  const collapsedPaths = useAppSelector(
+    (state) => state.settings.appSettings.diffView.collapsedPaths,
+  );
   const diffThemeType = getDiffThemeType(resolvedTheme);
   const diffTheme = useMemo(() => getDiffTheme(), []);
   const diffThemeCacheSalt = getDiffThemeCacheSalt(diffThemeType);
+  const repoPrimaryLanguage = useRepoPrimaryLanguage();
   const viewportRef = useRef<HTMLDivElement | null>(null);
 
   useImperativeHandle(ref, () => ({
@@ -197,20 +231,32 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
   }));
 
   const [expandUnchanged, setExpandUnchanged] = useState(false);
-  const activeDiffIdentity = `${activePath}:${diffFileIdentity(oldFile)}:${diffFileIdentity(newFile)}:${expandUnchanged ? "expanded" : "collapsed"}`;
+  const activeFileIdentity = `${activePath}:${diffFileIdentity(oldFile)}:${diffFileIdentity(newFile)}`;
+  const activeDiffIdentity = `${activeFileIdentity}:${expandUnchanged ? "expanded" : "collapsed"}`;
   const [forceShowLargeDiffIdentity, setForceShowLargeDiffIdentity] = useState<string | null>(null);
   const forceShowLargeDiff = forceShowLargeDiffIdentity === activeDiffIdentity;
+  const [expandedFileIdentity, setExpandedFileIdentity] = useState<string | null>(null);
+  const collapsed =
+    expandedFileIdentity !== activeFileIdentity &&
+    isCollapsedByDefault(activePath, collapsedPaths);
+  const oldLfsPointer = oldFile ? parseLfsPointer(oldFile.contents) : null;
+  const newLfsPointer = newFile ? parseLfsPointer(newFile.contents) : null;
+  // A pointer diff only shows two hashes changing, so LFS files get a summary instead.
+  const isLfsDiff = oldLfsPointer !== null || newLfsPointer !== null;
+  const skipParse = collapsed || isLfsDiff;
 
   const onToggleExpandUnchanged = useCallback(() => {
     setExpandUnchanged((prev) => !prev);
   }, []);
 
-  const { currentFileDiff, diffRenderGate, isParsingDiff } = useParsedDiff({
+  const { currentFileDiff, diffRenderGate, highlightSkipped, isParsingDiff } = useParsedDiff({
     activePath,
-    oldFile,
-    newFile,
+    oldFile: skipParse ? null : oldFile,
+    newFile: skipParse ? null : newFile,
     cacheSalt: diffThemeCacheSalt,
     allowLargeDiff: forceShowLargeDiff,
+    maxHighlightBytes,
+    fallbackLang: repoPrimaryLanguage,
   });
 
   useDiffLineFocus({
@@ -221,19 +267,40 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
     enabled: Boolean(currentFileDiff),
   });
 
-  const mergedOptions = useMemo<FileDiffOptions<DiffAnnotationItem>>(
-    () => ({
-      diffStyle,
-      theme: diffTheme,
-      themeType: diffThemeType,
-      unsafeCSS: STICKY_HEADER_CSS,
-      maxLineDiffLength: MAX_DIFF_LINE_LENGTH,
-      expansionLineCount: 20,
-      expandUnchanged,
-      ...options,
-    }),
-    [diffStyle, diffTheme, diffThemeType, expandUnchanged, options],
-  );
+  const overlongLinesCSS = currentFileDiff
+    ? overlongLineCSS(findOverlongAddedLines(currentFileDiff, maxLineLength))
+    : "";
+
+  const wrapMode = diffWrapModeForPath(activePath);
+
+  const clusterPreviewRef = useRef<ChangeClusterPreviewHandle | null>(null);
+  const { onTokenEnter: onOptionsTokenEnter, onTokenLeave: onOptionsTokenLeave } = options;
+  const onTokenEnter: TokenEnterHandler = (...args) => {
+    clusterPreviewRef.current?.onTokenEnter(args[0]);
+    onOptionsTokenEnter?.(...args);
+  };
+  const onTokenLeave: TokenLeaveHandler = (...args) => {
+    clusterPreviewRef.current?.onTokenLeave();
+    onOptionsTokenLeave?.(...args);
+  };
+
+  const mergedOptions: FileDiffOptions<DiffAnnotationItem> = {
+    diffStyle,
+    overflow: diffWrap ? "wrap" : "scroll",
+    theme: diffTheme,
+    themeType: diffThemeType,
+    unsafeCSS:
+      STICKY_HEADER_CSS +
+      overlongLinesCSS +
+      changesOnlyCSS(diffChangesOnly) +
+      (diffWrap ? diffWrapCSS(wrapMode) : ""),
+    maxLineDiffLength: MAX_DIFF_LINE_LENGTH,
+    expansionLineCount: 20,
+    expandUnchanged,
+    ...options,
+    onTokenEnter,
+    onTokenLeave,
+  };
 
   const headerMetadataNode = useMemo(() => {
     if (!renderHeaderMetadata) return undefined;
@@ -243,59 +310,38 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
     });
   }, [expandUnchanged, onToggleExpandUnchanged, renderHeaderMetadata]);
 
-  const scrollbarMarkers = useMemo(
-    () => (currentFileDiff ? buildDiffScrollbarMarkers(currentFileDiff, diffStyle) : []),
-    [currentFileDiff, diffStyle],
-  );
+  const hunkActions: DiffHunkAction[] = [...(onHunkAction ? hunkOperations : []), "copy"];
 
-  const hunkActionAnnotations = useMemo<DiffLineAnnotation<DiffAnnotationItem>[]>(() => {
-    if (!currentFileDiff || hunkOperations.length === 0 || !onHunkAction) return [];
+  const handleHunkAction = (action: DiffHunkAction, payload: DiffHunkActionPayload) => {
+    if (action !== "copy") {
+      onHunkAction?.(action, payload);
+      return;
+    }
 
-    return currentFileDiff.hunks.map((hunk, hunkIndex) => {
-      let additionOffset = 0;
-      let deletionOffset = 0;
+    const change = !oldFile ? "added" : !newFile ? "deleted" : "modified";
+    const patch = formatHunkPatch(payload.fileDiff, payload.hunkIndex, change);
+    if (!patch) return;
+    navigator.clipboard.writeText(patch).then(
+      () => toast.success("Copied hunk as a patch"),
+      () => toast.error("Failed to copy hunk"),
+    );
+  };
 
-      for (const content of hunk.hunkContent) {
-        if (content.type === "context") {
-          additionOffset += content.lines;
-          deletionOffset += content.lines;
-          continue;
-        }
+  const hunkActionAnnotations = currentFileDiff
+    ? buildHunkActionAnnotations(currentFileDiff, hunkActions, handleHunkAction)
+    : [];
 
-        const side = content.additions > 0 ? "additions" : "deletions";
-        const firstChangedLine =
-          side === "additions"
-            ? hunk.additionStart + additionOffset
-            : hunk.deletionStart + deletionOffset;
-        const hunkStartLine = side === "additions" ? hunk.additionStart : hunk.deletionStart;
-        const lineNumber =
-          firstChangedLine > hunkStartLine ? firstChangedLine - 1 : firstChangedLine;
-        const metadata: DiffHunkActionAnnotation = {
-          type: "hunk-action",
-          operations: hunkOperations,
-          fileDiff: currentFileDiff,
-          hunkIndex,
-          onAction: onHunkAction,
-        };
-
-        return { side, lineNumber, metadata };
-      }
+  // Sits with the comment composer under a selection that covers changed lines.
+  const lineActionAnnotations =
+    currentFileDiff && selectedLines && onLinesAction && hunkOperations.length > 0
+      ? buildLineActionAnnotations(currentFileDiff, selectedLines, hunkOperations, onLinesAction)
+      : [];
 
-      const metadata: DiffHunkActionAnnotation = {
-        type: "hunk-action",
-        operations: hunkOperations,
-        fileDiff: currentFileDiff,
-        hunkIndex,
-        onAction: onHunkAction,
-      };
-      return { side: "additions", lineNumber: hunk.additionStart, metadata };
-    });
-  }, [currentFileDiff, hunkOperations, onHunkAction]);
-
-  const mergedLineAnnotations = useMemo(
-    () => [...hunkActionAnnotations, ...lineAnnotations],
-    [hunkActionAnnotations, lineAnnotations],
-  );
+  const mergedLineAnnotations = [
+    ...hunkActionAnnotations,
+    ...lineActionAnnotations,
+    ...lineAnnotations,
+  ];
 
   const renderLargeDiffWarning = () => {
     return (
@@ -319,6 +365,47 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
     );
   };
 
+  const renderCollapsedDiff = () => {
+    return (
+      <Empty className="border-0 rounded-none h-full gap-4">
+        <EmptyHeader>
+          <EmptyMedia variant="icon">
+            <FoldVertical />
+          </EmptyMedia>
+          <EmptyTitle>Collapsed by default</EmptyTitle>
+          <EmptyDescription>
+            {activePath} matches <code>diffView.collapsedPaths</code>, so its diff is hidden until
+            you expand it.
+          </EmptyDescription>
+        </EmptyHeader>
+        <EmptyContent>
+          <Button onClick={() => setExpandedFileIdentity(activeFileIdentity)}>Show diff</Button>
+        </EmptyContent>
+      </Empty>
+    );
+  };
+
+  const renderLfsSummary = () => {
+    return (
+      <Empty className="border-0 rounded-none h-full gap-4">
+        <EmptyHeader>
+          <EmptyMedia variant="icon">
+            <HardDrive />
+          </EmptyMedia>
+          <EmptyTitle>Git LFS file</EmptyTitle>
+          <EmptyDescription>
+            {oldLfsPointer ? (
+              <span className="block">Before: {formatLfsPointer(oldLfsPointer)}</span>
+            ) : null}
+            {newLfsPointer ? (
+              <span className="block">After: {formatLfsPointer(newLfsPointer)}</span>
+            ) : null}
+          </EmptyDescription>
+        </EmptyHeader>
+      </Empty>
+    );
+  };
+
   return (
     <div
       ref={viewportRef}
@@ -326,7 +413,11 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
       className="relative min-h-0 min-w-0 flex-1 overflow-hidden"
     >
       <style>{DIFF_SCROLLBAR_CSS}</style>
-      {currentFileDiff ? (
+      {isLfsDiff ? (
+        renderLfsSummary()
+      ) : collapsed ? (
+        renderCollapsedDiff()
+      ) : currentFileDiff ? (
         <Virtualizer
           config={{
             overscrollSize: 600,
@@ -353,7 +444,18 @@ export const DiffViewer = forwardRef<DiffViewerHandle, DiffViewerProps>(function
       ) : (
         <div className="text-muted-foreground p-3 text-xs">No diff content.</div>
       )}
-      <DiffScrollbarMarkers markers={scrollbarMarkers} viewportRef={viewportRef} />
+      {currentFileDiff && highlightSkipped ? (
+        <div className="text-muted-foreground bg-background/90 border-border/60 pointer-events-none absolute bottom-2 left-2 z-20 rounded-sm border px-2 py-0.5 text-[11px]">
+          Highlighting skipped (large file)
+        </div>
+      ) : null}
+      {currentFileDiff ? (
+        <>
+          <DiffSecretFindings fileDiff={currentFileDiff} />
+          <DiffScrollbarMarkers fileDiff={currentFileDiff} viewportRef={viewportRef} />
+        </>
+      ) : null}
+      <ChangeClusterPreview ref={clusterPreviewRef} fileDiff={currentFileDiff} />
       {children}
     </div>
   );
@@ -388,11 +490,23 @@ const DIFF_SCROLLBAR_CSS = `
 
 type DiffScrollbarMarker = {
   key: string;
-  type: "addition" | "deletion";
+  type: "addition" | "deletion" | "secret";
   top: number;
   height: number;
 };
 
+const SCROLLBAR_MARKER_LABELS: Record<DiffScrollbarMarker["type"], string> = {
+  addition: "Scroll to addition",
+  deletion: "Scroll to deletion",
+  secret: "Scroll to possible secret",
+};
+
+const SCROLLBAR_MARKER_CLASSES: Record<DiffScrollbarMarker["type"], string> = {
+  addition: "bg-emerald-500/40",
+  deletion: "bg-red-500/40",
+  secret: "bg-amber-500",
+};
+
 function clampPercent(value: number) {
   return Math.min(100, Math.max(0, value));
 }
@@ -420,20 +534,24 @@ function getDiffTotalLines(fileDiff: FileDiffMetadata, diffStyle: "unified" | "s
 function buildDiffScrollbarMarkers(
   fileDiff: FileDiffMetadata,
   diffStyle: "unified" | "split",
+  secretLines: ReadonlySet<number>,
 ): DiffScrollbarMarker[] {
   const totalLines = getDiffTotalLines(fileDiff, diffStyle);
   if (totalLines <= 0) return [];
 
   const markers: DiffScrollbarMarker[] = [];
+  const secretMarkers: DiffScrollbarMarker[] = [];
 
   fileDiff.hunks.forEach((hunk, hunkIndex) => {
     let splitOffset = 0;
     let unifiedOffset = 0;
+    let additionOffset = 0;
 
     hunk.hunkContent.forEach((content, contentIndex) => {
       if (content.type === "context") {
         splitOffset += content.lines;
         unifiedOffset += content.lines;
+        additionOffset += content.lines;
         return;
       }
 
@@ -461,23 +579,119 @@ function buildDiffScrollbarMarkers(
           top: clampPercent((start / totalLines) * 100),
           height: clampPercent((Math.max(1, rows) / totalLines) * 100),
         });
+
+        for (let index = 0; index < content.additions; index += 1) {
+          if (!secretLines.has(hunk.additionStart + additionOffset + index)) continue;
+
+          secretMarkers.push({
+            key: `${hunkIndex}-${contentIndex}-secret-${index}`,
+            type: "secret",
+            top: clampPercent(((start + index) / totalLines) * 100),
+            height: clampPercent((1 / totalLines) * 100),
+          });
+        }
       }
 
+      additionOffset += content.additions;
       splitOffset += splitRows;
       unifiedOffset += content.deletions + content.additions;
     });
   });
 
-  return markers;
+  return [...markers, ...secretMarkers];
+}
+
+function buildHunkActionAnnotations(
+  fileDiff: FileDiffMetadata,
+  operations: DiffHunkAction[],
+  onAction: DiffHunkActionAnnotation["onAction"],
+): DiffLineAnnotation<DiffAnnotationItem>[] {
+  return fileDiff.hunks.map((hunk, hunkIndex) => {
+    let additionOffset = 0;
+    let deletionOffset = 0;
+
+    for (const content of hunk.hunkContent) {
+      if (content.type === "context") {
+        additionOffset += content.lines;
+        deletionOffset += content.lines;
+        continue;
+      }
+
+      const side = content.additions > 0 ? "additions" : "deletions";
+      const firstChangedLine =
+        side === "additions"
+          ? hunk.additionStart + additionOffset
+          : hunk.deletionStart + deletionOffset;
+      const hunkStartLine = side === "additions" ? hunk.additionStart : hunk.deletionStart;
+      const lineNumber = firstChangedLine > hunkStartLine ? firstChangedLine - 1 : firstChangedLine;
+      const metadata: DiffHunkActionAnnotation = {
+        type: "hunk-action",
+        operations,
+        fileDiff,
+        hunkIndex,
+        onAction,
+      };
+
+      return { side, lineNumber, metadata };
+    }
+
+    const metadata: DiffHunkActionAnnotation = {
+      type: "hunk-action",
+      operations,
+      fileDiff,
+      hunkIndex,
+      onAction,
+    };
+    return { side: "additions", lineNumber: hunk.additionStart, metadata };
+  });
+}
+
+function buildLineActionAnnotations(
+  fileDiff: FileDiffMetadata,
+  range: SelectionRange,
+  operations: DiffHunkOperation[],
+  onAction: DiffLinesActionAnnotation["onAction"],
+): DiffLineAnnotation<DiffAnnotationItem>[] {
+  const selected = selectedChangeLines(fileDiff, range);
+  if (selected.deletions.size === 0 && selected.additions.size === 0) return [];
+
+  const metadata: DiffLinesActionAnnotation = {
+    type: "line-action",
+    operations,
+    fileDiff,
+    range,
+    onAction,
+  };
+  return [{ side: range.side ?? "deletions", lineNumber: range.end, metadata }];
+}
+
+function DiffSecretFindings({ fileDiff }: { fileDiff: FileDiffMetadata }) {
+  const findings = scanAddedLines(fileDiff);
+  if (findings.length === 0) return null;
+
+  return (
+    <div
+      role="status"
+      title={findings.map((finding) => `Line ${finding.lineNumber}: ${finding.label}`).join("\n")}
+      className="bg-background/90 pointer-events-auto absolute right-6 bottom-2 z-20 flex items-center gap-1 rounded-sm border border-amber-500/60 px-2 py-0.5 text-[11px] text-amber-600 dark:text-amber-400"
+    >
+      <ShieldAlert className="size-3" />
+      {findings.length} possible secret{findings.length === 1 ? "" : "s"} in added lines
+    </div>
+  );
 }
 
 function DiffScrollbarMarkers({
-  markers,
+  fileDiff,
   viewportRef,
 }: {
-  markers: DiffScrollbarMarker[];
+  fileDiff: FileDiffMetadata;
   viewportRef: RefObject<HTMLDivElement | null>;
 }) {
+  const diffStyle = useAppSelector((state) => state.sourceControl.diffStyle);
+  const secretLines = new Set(scanAddedLines(fileDiff).map((finding) => finding.lineNumber));
+  const markers = buildDiffScrollbarMarkers(fileDiff, diffStyle, secretLines);
+
   const scrollToPercent = useCallback(
     (percent: number) => {
       const scrollElement =
@@ -516,9 +730,9 @@ function DiffScrollbarMarkers({
         <button
           key={marker.key}
           type="button"
-          aria-label={marker.type === "addition" ? "Scroll to addition" : "Scroll to deletion"}
+          aria-label={SCROLLBAR_MARKER_LABELS[marker.type]}
           className={`absolute left-0 w-full transition-[scale,opacity] cursor-pointer ${
-            marker.type === "addition" ? "bg-emerald-500/40" : "bg-red-500/40"
+            SCROLLBAR_MARKER_CLASSES[marker.type]
           }`}
           style={{
             top: `${marker.top}%`,
@@ -0,0 +1,132 @@
+import { useState } from "react";
+import { ClipboardPaste, Pencil } from "lucide-react";
+
+import { Button } from "@/components/ui/button";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { Textarea } from "@/components/ui/textarea";
+import { DiffWorkspace } from "@/features/diff-view/DiffWorkspace";
+import {
+  parseUnifiedDiff,
+  patchFileContents,
+  type UnifiedDiffFile,
+} from "@/features/diff-view/services/unifiedDiff";
+import { cn } from "@/lib/utils";
+
+type PasteDiffDialogProps = {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+};
+
+function pastedFilePath(file: UnifiedDiffFile) {
+  return file.newPath ?? file.oldPath ?? "(unnamed file)";
+}
+
+// Shows a patch received outside any repo (mail, chat, a CI log) in the read-only diff view.
+export function PasteDiffDialog({ open, onOpenChange }: PasteDiffDialogProps) {
+  const [patchText, setPatchText] = useState("");
+  const [reviewing, setReviewing] = useState(false);
+  const [selectedIndex, setSelectedIndex] = useState(0);
+  const files = parseUnifiedDiff(patchText);
+  const selectedFile = files[selectedIndex] ?? files[0] ?? null;
+  const hunkCount = files.reduce((count, file) => count + file.hunks.length, 0);
+  const fileSummary = `${files.length} file${files.length === 1 ? "" : "s"}`;
+  const hunkSummary = `${hunkCount} hunk${hunkCount === 1 ? "" : "s"}`;
+
+  const onShowDiff = () => {
+    setSelectedIndex(0);
+    setReviewing(true);
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="flex h-[80vh] flex-col gap-3 p-4 sm:max-w-6xl">
+        <DialogHeader>
+          <DialogTitle>Open pasted diff</DialogTitle>
+          <DialogDescription>
+            {files.length > 0
+              ? `${fileSummary}, ${hunkSummary}.`
+              : "Paste a unified diff, such as the output of git diff or git format-patch."}
+          </DialogDescription>
+        </DialogHeader>
+        {reviewing && selectedFile ? (
+          <div className="border-border flex min-h-0 flex-1 overflow-hidden border">
+            <div className="border-border w-56 shrink-0 overflow-y-auto border-r py-1">
+              {files.map((file, index) => (
+                <button
+                  key={`${index}:${pastedFilePath(file)}`}
+                  type="button"
+                  title={pastedFilePath(file)}
+                  className={cn(
+                    "block w-full truncate px-2 py-1 text-left text-xs",
+                    file === selectedFile
+                      ? "bg-accent text-accent-foreground"
+                      : "text-muted-foreground hover:bg-surface-1 hover:text-foreground",
+                  )}
+                  onClick={() => setSelectedIndex(index)}
+                >
+                  {pastedFilePath(file)}
+                </button>
+              ))}
+            </div>
+            <PastedFileDiff file={selectedFile} />
+          </div>
+        ) : (
+          <Textarea
+            autoFocus
+            spellCheck={false}
+            value={patchText}
+            placeholder={"diff --git a/file.txt b/file.txt\n--- a/file.txt\n+++ b/file.txt\n@@ ..."}
+            className="min-h-0 flex-1 resize-none font-mono text-xs field-sizing-fixed"
+            onChange={(event) => setPatchText(event.target.value)}
+          />
+        )}
+        <DialogFooter>
+          {reviewing ? (
+            <Button type="button" variant="outline" onClick={() => setReviewing(false)}>
+              <Pencil className="h-3.5 w-3.5" />
+              Edit patch
+            </Button>
+          ) : (
+            <Button type="button" disabled={files.length === 0} onClick={onShowDiff}>
+              <ClipboardPaste className="h-3.5 w-3.5" />
+              Show diff
+            </Button>
+          )}
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
+
+function PastedFileDiff({ file }: { file: UnifiedDiffFile }) {
+  const path = pastedFilePath(file);
+  const { oldContents, newContents } = patchFileContents(file);
+
+  return (
+    <div className="flex min-h-0 min-w-0 flex-1 flex-col">
+      {file.isBinary ? (
+        <div className="text-muted-foreground p-3 text-sm">Binary file, no preview.</div>
+      ) : file.hunks.length === 0 ? (
+        <div className="text-muted-foreground p-3 text-sm">No content changes.</div>
+      ) : (
+        <DiffWorkspace
+          oldFile={file.oldPath === null ? null : { name: path, contents: oldContents }}
+          newFile={file.newPath === null ? null : { name: path, contents: newContents }}
+          activePath={path}
+          commentContext={{ kind: "changes" }}
+          canComment={false}
+          canBlame={false}
+          hideHeaderMetadataControls
+          readOnly
+        />
+      )}
+    </div>
+  );
+}
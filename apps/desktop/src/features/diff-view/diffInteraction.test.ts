@@ -0,0 +1,51 @@
+import { describe, expect, it, vi } from "vitest";
+
+import { resolveDiffInteraction } from "@/features/diff-view/diffInteraction";
+
+describe("resolveDiffInteraction", () => {
+  it("keeps line selection and hunk actions for editable diffs", () => {
+    const onLineSelected = vi.fn();
+    const onHunkAction = vi.fn();
+
+    const interaction = resolveDiffInteraction({
+      readOnly: false,
+      canComment: true,
+      hunkOperations: ["stage", "discard"],
+      onHunkAction,
+      onLineSelected,
+    });
+
+    expect(interaction.enableLineSelection).toBe(true);
+    expect(interaction.onLineSelected).toBe(onLineSelected);
+    expect(interaction.hunkOperations).toEqual(["stage", "discard"]);
+    expect(interaction.onHunkAction).toBe(onHunkAction);
+  });
+
+  it("suppresses comment and staging actions on line clicks when read-only", () => {
+    const onLineSelected = vi.fn();
+    const onLineSelectionEnd = vi.fn();
+    const onHunkAction = vi.fn();
+    const onLinesAction = vi.fn();
+
+    const interaction = resolveDiffInteraction({
+      readOnly: true,
+      canComment: true,
+      hunkOperations: ["unstage"],
+      onHunkAction,
+      onLinesAction,
+      onLineSelected,
+      onLineSelectionEnd,
+    });
+
+    expect(interaction).toEqual({
+      canComment: false,
+      enableLineSelection: false,
+      enableGutterUtility: false,
+      onLineSelected: undefined,
+      onLineSelectionEnd: undefined,
+      hunkOperations: [],
+      onHunkAction: undefined,
+      onLinesAction: undefined,
+    });
+  });
+});
@@ -0,0 +1,57 @@
+import type { FileDiffOptions } from "@pierre/diffs";
+
+import type { DiffAnnotationItem } from "@/features/source-control/types";
+import type {
+  DiffHunkActionPayload,
+  DiffHunkOperation,
+  DiffLinesActionPayload,
+} from "@/features/source-control/hunkOperations";
+
+type DiffOptions = Partial<FileDiffOptions<DiffAnnotationItem>>;
+
+type DiffInteractionInput = {
+  readOnly: boolean;
+  canComment: boolean;
+  hunkOperations: DiffHunkOperation[];
+  onHunkAction?: (operation: DiffHunkOperation, payload: DiffHunkActionPayload) => void;
+  onLinesAction?: (operation: DiffHunkOperation, payload: DiffLinesActionPayload) => void;
+  onLineSelected?: DiffOptions["onLineSelected"];
+  onLineSelectionEnd?: DiffOptions["onLineSelectionEnd"];
+};
+
+export type DiffInteraction = {
+  canComment: boolean;
+  enableLineSelection: boolean;
+  enableGutterUtility: boolean;
+  onLineSelected: DiffOptions["onLineSelected"];
+  onLineSelectionEnd: DiffOptions["onLineSelectionEnd"];
+  hunkOperations: DiffHunkOperation[];
+  onHunkAction?: (operation: DiffHunkOperation, payload: DiffHunkActionPayload) => void;
+  onLinesAction?: (operation: DiffHunkOperation, payload: DiffLinesActionPayload) => void;
+};
+
+const NO_HUNK_OPERATIONS: DiffHunkOperation[] = [];
+
+// Read-only diffs (e.g. the commit preview) never emit comment or staging actions.
+export function resolveDiffInteraction({
+  readOnly,
+  canComment,
+  hunkOperations,
+  onHunkAction,
+  onLinesAction,
+  onLineSelected,
+  onLineSelectionEnd,
+}: DiffInteractionInput): DiffInteraction {
+  const commentsEnabled = canComment && !readOnly;
+
+  return {
+    canComment: commentsEnabled,
+    enableLineSelection: commentsEnabled,
+    enableGutterUtility: commentsEnabled,
+    onLineSelected: commentsEnabled ? onLineSelected : undefined,
+    onLineSelectionEnd: commentsEnabled ? onLineSelectionEnd : undefined,
+    hunkOperations: readOnly ? NO_HUNK_OPERATIONS : hunkOperations,
+    onHunkAction: readOnly ? undefined : onHunkAction,
+    onLinesAction: readOnly ? undefined : onLinesAction,
+  };
+}
@@ -13,6 +13,7 @@ type AnnotationRenderers = {
   diagnostic?: (data: Extract<DiffAnnotationItem, { type: "diagnostic" }>) => React.ReactNode;
   annotation?: (data: Extract<DiffAnnotationItem, { type: "annotation" }>) => React.ReactNode;
   "hunk-action"?: (data: Extract<DiffAnnotationItem, { type: "hunk-action" }>) => React.ReactNode;
+  "line-action"?: (data: Extract<DiffAnnotationItem, { type: "line-action" }>) => React.ReactNode;
 };
 
 export function useDiffAnnotationRenderer(renderers: AnnotationRenderers) {
@@ -34,6 +35,8 @@ export function useDiffAnnotationRenderer(renderers: AnnotationRenderers) {
           return renderers.annotation?.(data) ?? null;
         case "hunk-action":
           return renderers["hunk-action"]?.(data) ?? null;
+        case "line-action":
+          return renderers["line-action"]?.(data) ?? null;
         default:
           return null;
       }
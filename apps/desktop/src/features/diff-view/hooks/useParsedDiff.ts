@@ -9,6 +9,7 @@ import {
   peekCachedParsedDiff,
   type ParsedDiff,
 } from "@/features/diff-view/services/parsedDiffCache";
+import type { HighlightLanguage } from "@/features/diff-view/services/repoLanguage";
 import type { DiffFile } from "@/features/source-control/types";
 
 type ParsedDiffState = { key: string; diff: ParsedDiff | null };
@@ -19,6 +20,8 @@ type UseParsedDiffArgs = {
   newFile: DiffFile | null;
   cacheSalt?: string;
   allowLargeDiff?: boolean;
+  maxHighlightBytes?: number;
+  fallbackLang?: HighlightLanguage | null;
 };
 
 export function useParsedDiff({
@@ -27,6 +30,8 @@ export function useParsedDiff({
   newFile,
   cacheSalt = "",
   allowLargeDiff = false,
+  maxHighlightBytes,
+  fallbackLang = null,
 }: UseParsedDiffArgs) {
   const parseRequestTokenRef = useRef(0);
   const [parsedState, setParsedState] = useState<ParsedDiffState | null>(null);
@@ -34,6 +39,8 @@ export function useParsedDiff({
   const diffRenderGate = getDiffRenderGate(activePath, oldFile, newFile);
   const requestPayload = getParsedDiffRequest(activePath, oldFile, newFile, cacheSalt, {
     allowLargeDiff,
+    maxHighlightBytes,
+    fallbackLang,
   });
 
   useEffect(() => {
@@ -42,6 +49,8 @@ export function useParsedDiff({
 
     const nextRequestPayload = getParsedDiffRequest(activePath, oldFile, newFile, cacheSalt, {
       allowLargeDiff,
+      maxHighlightBytes,
+      fallbackLang,
     });
 
     if (!nextRequestPayload) {
@@ -57,7 +66,7 @@ export function useParsedDiff({
       if (parseRequestTokenRef.current !== requestToken) return;
       setParsedState({ key: nextRequestPayload.key, diff: parsedDiff });
     });
-  }, [activePath, allowLargeDiff, cacheSalt, newFile, oldFile]);
+  }, [activePath, allowLargeDiff, cacheSalt, fallbackLang, maxHighlightBytes, newFile, oldFile]);
 
   const requestKey = requestPayload?.key ?? null;
   const cachedDiff = requestKey ? peekCachedParsedDiff(requestKey) : undefined;
@@ -72,5 +81,7 @@ export function useParsedDiff({
     cachedDiff === undefined &&
     (isParsedDiffInFlight(requestKey) || parsedState?.key !== requestKey);
 
-  return { currentFileDiff, diffRenderGate, isParsingDiff };
+  const highlightSkipped = requestPayload?.highlightSkipped ?? false;
+
+  return { currentFileDiff, diffRenderGate, highlightSkipped, isParsingDiff };
 }
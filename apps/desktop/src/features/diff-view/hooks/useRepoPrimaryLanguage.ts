@@ -0,0 +1,16 @@
+import { skipToken } from "@reduxjs/toolkit/query";
+
+import { useAppSelector } from "@/app/hooks";
+import { detectRepoPrimaryLanguage } from "@/features/diff-view/services/repoLanguage";
+import { useGetRepoFilesQuery } from "@/features/source-control/api";
+
+export function useRepoPrimaryLanguage() {
+  const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
+  const { primaryLanguage } = useGetRepoFilesQuery(activeRepo || skipToken, {
+    selectFromResult: ({ data }) => ({
+      primaryLanguage: data ? detectRepoPrimaryLanguage(data) : null,
+    }),
+  });
+
+  return primaryLanguage;
+}
@@ -0,0 +1,38 @@
+import { parseDiffFromFile } from "@pierre/diffs";
+import { describe, expect, it } from "vitest";
+
+import { clusterLines, findChangeCluster, type DiffLine } from "./changeClusters";
+
+describe("clusterLines", () => {
+  it("joins consecutive removed lines and splits them at a context line", () => {
+    const lines: DiffLine[] = [
+      { kind: "deletion", lineNumber: 1, text: "a\n" },
+      { kind: "deletion", lineNumber: 2, text: "b\n" },
+      { kind: "context", lineNumber: 2, text: "c\n" },
+      { kind: "deletion", lineNumber: 4, text: "d\n" },
+      { kind: "addition", lineNumber: 3, text: "D\n" },
+    ];
+
+    expect(clusterLines(lines)).toEqual([
+      { kind: "deletion", startLine: 1, endLine: 2, text: "a\nb\n" },
+      { kind: "deletion", startLine: 4, endLine: 4, text: "d\n" },
+      { kind: "addition", startLine: 3, endLine: 3, text: "D\n" },
+    ]);
+  });
+});
+
+describe("findChangeCluster", () => {
+  it("finds the run a changed line belongs to on its own side", () => {
+    const fileDiff = parseDiffFromFile(
+      { name: "notes.ts", contents: "keep\nold 1\nold 2\nkeep\n" },
+      { name: "notes.ts", contents: "keep\nnew 1\nkeep\n" },
+    );
+
+    expect(findChangeCluster(fileDiff, "deletions", 3)).toMatchObject({
+      startLine: 2,
+      text: "old 1\nold 2\n",
+    });
+    expect(findChangeCluster(fileDiff, "additions", 2)?.text).toBe("new 1\n");
+    expect(findChangeCluster(fileDiff, "additions", 1)).toBeNull();
+  });
+});
@@ -0,0 +1,100 @@
+import type { FileDiffMetadata } from "@pierre/diffs";
+
+export type DiffLineKind = "context" | "deletion" | "addition";
+
+// Line numbers count on the side the line lives on: old for deletions, new otherwise.
+export type DiffLine = {
+  kind: DiffLineKind;
+  lineNumber: number;
+  text: string;
+};
+
+export type LineCluster = {
+  kind: Exclude<DiffLineKind, "context">;
+  startLine: number;
+  endLine: number;
+  text: string;
+};
+
+export function hunkDiffLines(fileDiff: FileDiffMetadata, hunkIndex: number): DiffLine[] {
+  const hunk = fileDiff.hunks[hunkIndex];
+  if (!hunk) return [];
+
+  let deletionLine = hunk.deletionStart;
+  let additionLine = hunk.additionStart;
+  const lines: DiffLine[] = [];
+  const push = (kind: DiffLineKind, lineNumber: number, text: string | undefined) => {
+    lines.push({ kind, lineNumber, text: text ?? "" });
+  };
+
+  for (const content of hunk.hunkContent) {
+    if (content.type === "context") {
+      for (let index = 0; index < content.lines; index += 1) {
+        push("context", additionLine, fileDiff.additionLines[additionLine - 1]);
+        deletionLine += 1;
+        additionLine += 1;
+      }
+      continue;
+    }
+
+    for (let index = 0; index < content.deletions; index += 1) {
+      push("deletion", deletionLine, fileDiff.deletionLines[deletionLine - 1]);
+      deletionLine += 1;
+    }
+    for (let index = 0; index < content.additions; index += 1) {
+      push("addition", additionLine, fileDiff.additionLines[additionLine - 1]);
+      additionLine += 1;
+    }
+  }
+
+  return lines;
+}
+
+// Runs of consecutive removed or added lines; context lines and a change of kind end a run.
+export function clusterLines(lines: readonly DiffLine[]): LineCluster[] {
+  const clusters: LineCluster[] = [];
+  let current: LineCluster | null = null;
+
+  for (const line of lines) {
+    if (line.kind === "context") {
+      current = null;
+      continue;
+    }
+
+    if (current && current.kind === line.kind && current.endLine + 1 === line.lineNumber) {
+      current.endLine = line.lineNumber;
+      current.text += line.text;
+      continue;
+    }
+
+    current = {
+      kind: line.kind,
+      startLine: line.lineNumber,
+      endLine: line.lineNumber,
+      text: line.text,
+    };
+    clusters.push(current);
+  }
+
+  return clusters;
+}
+
+export function findChangeCluster(
+  fileDiff: FileDiffMetadata,
+  side: "additions" | "deletions",
+  lineNumber: number,
+): LineCluster | null {
+  const kind = side === "additions" ? "addition" : "deletion";
+
+  for (let hunkIndex = 0; hunkIndex < fileDiff.hunks.length; hunkIndex += 1) {
+    const cluster = clusterLines(hunkDiffLines(fileDiff, hunkIndex)).find(
+      (candidate) =>
+        candidate.kind === kind &&
+        candidate.startLine <= lineNumber &&
+        lineNumber <= candidate.endLine,
+    );
+    if (cluster) return cluster;
+  }
+
+  return null;
+}
@@ -0,0 +1,28 @@
+import { describe, expect, it } from "vitest";
+
+import { changesOnlyCSS } from "./changesOnly";
+
+function hiddenSelectors(css: string) {
+  const hiddenBlock = css.split("{")[0] ?? "";
+  return hiddenBlock
+    .split(",")
+    .map((selector) => selector.trim())
+    .filter(Boolean);
+}
+
+describe("changesOnlyCSS", () => {
+  it("adds nothing when changes-only mode is off", () => {
+    expect(changesOnlyCSS(false)).toBe("");
+  });
+
+  it("hides context rows but keeps changed rows and their annotations", () => {
+    const hidden = hiddenSelectors(changesOnlyCSS(true));
+
+    expect(hidden).toEqual([
+      "[data-line-type='context']",
+      "[data-line-type='context-expanded']",
+    ]);
+    expect(hidden.some((selector) => selector.includes("change"))).toBe(false);
+    expect(hidden.some((selector) => selector.includes("annotation"))).toBe(false);
+  });
+});
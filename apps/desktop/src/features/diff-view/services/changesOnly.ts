@@ -0,0 +1,22 @@
+const CONTEXT_LINE_TYPES = ["context", "context-expanded"];
+
+// Hides unchanged rows in both the gutter and the code column. Annotation rows (comments,
+// hunk actions) are not line rows, so they stay under the changed line they are anchored to.
+// The first changed row after a hidden run gets a thin rule to mark the gap.
+export function changesOnlyCSS(enabled: boolean): string {
+  if (!enabled) return "";
+
+  const contextSelectors = CONTEXT_LINE_TYPES.map((type) => `[data-line-type='${type}']`);
+  const gapSelectors = contextSelectors.map(
+    (selector) => `${selector} + [data-line-type^='change']`,
+  );
+  return `
+${contextSelectors.join(",\n")} {
+  display: none;
+}
+
+${gapSelectors.join(",\n")} {
+  border-top: 1px dashed color-mix(in lab, var(--diffs-bg) 70%, var(--diffs-fg));
+}
+`;
+}
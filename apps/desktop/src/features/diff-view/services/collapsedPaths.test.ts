@@ -0,0 +1,31 @@
+import { describe, expect, it } from "vitest";
+
+import { createAppSettings, DEFAULT_COLLAPSED_PATHS } from "@/platform/desktop/appSettings";
+import { isProtectedPath } from "@/platform/desktop/protectedPaths";
+import { isCollapsedByDefault } from "./collapsedPaths";
+
+describe("isCollapsedByDefault", () => {
+  it("collapses lockfiles at any depth with the default patterns", () => {
+    expect(isCollapsedByDefault("Cargo.lock", DEFAULT_COLLAPSED_PATHS)).toBe(true);
+    expect(isCollapsedByDefault("apps/web/package-lock.json", DEFAULT_COLLAPSED_PATHS)).toBe(true);
+    expect(isCollapsedByDefault("pnpm-lock.yaml", DEFAULT_COLLAPSED_PATHS)).toBe(true);
+    expect(isCollapsedByDefault("src/lock.ts", DEFAULT_COLLAPSED_PATHS)).toBe(false);
+    expect(isCollapsedByDefault("", DEFAULT_COLLAPSED_PATHS)).toBe(false);
+  });
+
+  it("keeps a collapsed lockfile reviewable instead of excluding it", () => {
+    const settings = createAppSettings({
+      sourceControl: { protectedPaths: ["dist"] },
+      diffView: { collapsedPaths: ["**/*.lock", "generated/**"] },
+    });
+
+    expect(isCollapsedByDefault("Cargo.lock", settings.diffView.collapsedPaths)).toBe(true);
+    expect(isProtectedPath("Cargo.lock", settings.sourceControl.protectedPaths)).toBe(false);
+  });
+
+  it("turns collapsing off with an explicit empty list", () => {
+    const settings = createAppSettings({ diffView: { collapsedPaths: [] } });
+
+    expect(isCollapsedByDefault("Cargo.lock", settings.diffView.collapsedPaths)).toBe(false);
+  });
+});
@@ -0,0 +1,7 @@
+import { matchesPathPatterns } from "@/platform/desktop/protectedPaths";
+
+// Collapsed files stay in the file list and can be expanded on demand; they only skip the
+// automatic parse and render, which is what makes lockfile churn expensive to look at.
+export function isCollapsedByDefault(path: string, collapsedPaths: readonly string[]): boolean {
+  return path.length > 0 && matchesPathPatterns(path, collapsedPaths);
+}
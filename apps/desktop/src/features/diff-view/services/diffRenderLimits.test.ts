@@ -0,0 +1,83 @@
+import { describe, expect, it } from "vitest";
+
+import { shouldSkipHighlighting } from "./diffRenderLimits";
+import { getParsedDiffRequest } from "./parsedDiffCache";
+
+describe("shouldSkipHighlighting", () => {
+  it("skips highlighting when the combined contents exceed the byte limit", () => {
+    const oldFile = { name: "bundle.js", contents: "a".repeat(600) };
+    const newFile = { name: "bundle.js", contents: "b".repeat(600) };
+
+    expect(shouldSkipHighlighting(oldFile, newFile, 1000)).toBe(true);
+    expect(shouldSkipHighlighting(oldFile, newFile, 2000)).toBe(false);
+  });
+
+  it("skips highlighting for pathological single lines", () => {
+    const minified = { name: "min.js", contents: "x".repeat(6000) };
+
+    expect(shouldSkipHighlighting(null, minified, 1_000_000)).toBe(true);
+  });
+});
+
+describe("getParsedDiffRequest", () => {
+  it("requests plain-text parsing above the highlight limit", () => {
+    const oldFile = { name: "data.json", contents: "1\n".repeat(400) };
+    const newFile = { name: "data.json", contents: "2\n".repeat(400) };
+
+    const plain = getParsedDiffRequest("data.json", oldFile, newFile, "", {
+      maxHighlightBytes: 1000,
+    });
+    const highlighted = getParsedDiffRequest("data.json", oldFile, newFile, "", {
+      maxHighlightBytes: 10_000,
+    });
+
+    expect(plain?.highlightSkipped).toBe(true);
+    expect(plain?.newFile.lang).toBe("text");
+    expect(highlighted?.highlightSkipped).toBe(false);
+    expect(highlighted?.newFile.lang).toBeUndefined();
+    expect(plain?.key).not.toEqual(highlighted?.key);
+  });
+
+  it("renders plain-text formats without asking the highlighter for a grammar", () => {
+    const oldFile = { name: "notes.txt", contents: "first\n" };
+    const newFile = { name: "notes.txt", contents: "second\n" };
+
+    const request = getParsedDiffRequest("notes.txt", oldFile, newFile, "", {
+      fallbackLang: "rust",
+    });
+
+    expect(request?.highlightSkipped).toBe(false);
+    expect(request?.oldFile.lang).toBe("text");
+    expect(request?.newFile.lang).toBe("text");
+  });
+
+  it("highlights extensionless files with the repo's primary language", () => {
+    const oldFile = { name: "bin/setup", contents: "fn main() {}\n" };
+    const newFile = { name: "bin/setup", contents: "fn main() { run(); }\n" };
+
+    const request = getParsedDiffRequest("bin/setup", oldFile, newFile, "", {
+      fallbackLang: "rust",
+    });
+    const named = getParsedDiffRequest("src/main.rs", oldFile, newFile, "", {
+      fallbackLang: "python",
+    });
+
+    expect(request?.oldFile.lang).toBe("rust");
+    expect(request?.newFile.lang).toBe("rust");
+    expect(named?.newFile.lang).toBeUndefined();
+  });
+
+  it("shares one highlight entry between files with identical contents and language", () => {
+    const contents = "export const answer = 42;\n";
+    const highlightKeys = new Set<string | undefined>();
+    const requestFor = (path: string) =>
+      getParsedDiffRequest(path, null, { name: path, contents }, "");
+
+    for (const path of ["src/a.ts", "lib/b.ts", "src/a.rs"]) {
+      highlightKeys.add(requestFor(path)?.newFile.cacheKey);
+    }
+
+    expect(highlightKeys.size).toBe(2);
+    expect(requestFor("src/a.ts")?.key).not.toEqual(requestFor("lib/b.ts")?.key);
+  });
+});
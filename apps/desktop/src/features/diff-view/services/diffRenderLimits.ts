@@ -37,6 +37,23 @@ function exceedsMaxLineLength(contents: string): boolean {
   return false;
 }
 
+export function shouldSkipHighlighting(
+  oldFile: DiffFile | null,
+  newFile: DiffFile | null,
+  maxHighlightBytes: number,
+): boolean {
+  const totalDiffSize = getDiffContentSize(oldFile) + getDiffContentSize(newFile);
+  if (totalDiffSize > maxHighlightBytes) {
+    return true;
+  }
+
+  if (oldFile && exceedsMaxLineLength(oldFile.contents)) {
+    return true;
+  }
+
+  return newFile !== null && exceedsMaxLineLength(newFile.contents);
+}
+
 export function getDiffRenderGate(
   activePath: string | null,
   oldFile: DiffFile | null,
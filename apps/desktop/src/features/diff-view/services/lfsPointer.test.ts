@@ -0,0 +1,30 @@
+import { describe, expect, it } from "vitest";
+
+import { formatLfsPointer, parseLfsPointer } from "./lfsPointer";
+
+const OID = "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+const POINTER = `version https://git-lfs.github.com/spec/v1\noid sha256:${OID}\nsize 12345\n`;
+
+describe("parseLfsPointer", () => {
+  it("recognizes an LFS pointer file", () => {
+    expect(parseLfsPointer(POINTER)).toEqual({ oid: OID, size: 12345 });
+  });
+
+  it("leaves ordinary text alone", () => {
+    expect(parseLfsPointer("hello\n")).toBeNull();
+    expect(parseLfsPointer(`# version https://git-lfs.github.com/spec/v1\n`)).toBeNull();
+    expect(parseLfsPointer("version https://git-lfs.github.com/spec/v1\nsize 12\n")).toBeNull();
+    expect(parseLfsPointer(`${POINTER}${"x".repeat(2048)}`)).toBeNull();
+  });
+});
+
+describe("formatLfsPointer", () => {
+  it("summarizes the object instead of the raw pointer", () => {
+    expect(formatLfsPointer({ oid: OID, size: 12345 })).toBe(
+      "Git LFS object (oid 4d7a214614ab…, size 12.1 KB)",
+    );
+    expect(formatLfsPointer({ oid: OID, size: 512 })).toBe(
+      "Git LFS object (oid 4d7a214614ab…, size 512 B)",
+    );
+  });
+});
@@ -0,0 +1,43 @@
+// Git LFS keeps large files out of the repository and commits a small pointer in their place:
+// https://github.com/git-lfs/git-lfs/blob/main/docs/spec.md
+const LFS_POINTER_VERSION = "version https://git-lfs.github.com/spec/v1";
+const LFS_POINTER_MAX_BYTES = 1024;
+
+export type LfsPointer = {
+  oid: string;
+  size: number;
+};
+
+export function parseLfsPointer(contents: string): LfsPointer | null {
+  if (contents.length > LFS_POINTER_MAX_BYTES || !contents.startsWith(LFS_POINTER_VERSION)) {
+    return null;
+  }
+
+  let oid: string | null = null;
+  let size: number | null = null;
+  for (const line of contents.split("\n").slice(1)) {
+    const oidMatch = /^oid sha256:([0-9a-f]{64})$/.exec(line);
+    if (oidMatch) oid = oidMatch[1]!;
+
+    const sizeMatch = /^size (\d+)$/.exec(line);
+    if (sizeMatch) size = Number(sizeMatch[1]);
+  }
+
+  return oid && size !== null ? { oid, size } : null;
+}
+
+function formatLfsSize(size: number): string {
+  const units = ["B", "KB", "MB", "GB", "TB"];
+  let value = size;
+  let unitIndex = 0;
+  while (value >= 1024 && unitIndex < units.length - 1) {
+    value /= 1024;
+    unitIndex += 1;
+  }
+
+  return unitIndex === 0 ? `${size} B` : `${value.toFixed(1)} ${units[unitIndex]}`;
+}
+
+export function formatLfsPointer(pointer: LfsPointer): string {
+  return `Git LFS object (oid ${pointer.oid.slice(0, 12)}…, size ${formatLfsSize(pointer.size)})`;
+}
@@ -0,0 +1,27 @@
+import { parseDiffFromFile } from "@pierre/diffs";
+import { describe, expect, it } from "vitest";
+
+import { exceedsLineLength, findOverlongAddedLines } from "./lineLength";
+
+describe("exceedsLineLength", () => {
+  it("flags a line over the limit but not one at it", () => {
+    expect(exceedsLineLength(`${"x".repeat(81)}\n`, 80)).toBe(true);
+    expect(exceedsLineLength(`${"x".repeat(80)}\r\n`, 80)).toBe(false);
+  });
+
+  it("never flags when the limit is off", () => {
+    expect(exceedsLineLength("x".repeat(500), 0)).toBe(false);
+  });
+});
+
+describe("findOverlongAddedLines", () => {
+  it("reports only added lines over the limit", () => {
+    const longLine = "y".repeat(12);
+    const fileDiff = parseDiffFromFile(
+      { name: "notes.ts", contents: `${longLine}\nshort\n` },
+      { name: "notes.ts", contents: `${longLine}\nshort\n${longLine}\nok\n` },
+    );
+
+    expect(findOverlongAddedLines(fileDiff, 10)).toEqual([3]);
+  });
+});
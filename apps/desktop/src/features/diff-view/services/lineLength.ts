@@ -0,0 +1,54 @@
+import type { FileDiffMetadata } from "@pierre/diffs";
+
+const LINE_ENDING_PATTERN = /\r?\n$/;
+
+export function exceedsLineLength(text: string, maxLineLength: number): boolean {
+  if (maxLineLength <= 0) return false;
+  return text.replace(LINE_ENDING_PATTERN, "").length > maxLineLength;
+}
+
+// New-side line numbers of added lines over the limit; context and removed lines are not the
+// author's to fix in this change.
+export function findOverlongAddedLines(
+  fileDiff: FileDiffMetadata,
+  maxLineLength: number,
+): number[] {
+  if (maxLineLength <= 0) return [];
+
+  const lineNumbers: number[] = [];
+  for (const hunk of fileDiff.hunks) {
+    let additionOffset = 0;
+
+    for (const content of hunk.hunkContent) {
+      if (content.type === "context") {
+        additionOffset += content.lines;
+        continue;
+      }
+
+      for (let index = 0; index < content.additions; index += 1) {
+        const lineNumber = hunk.additionStart + additionOffset + index;
+        const text = fileDiff.additionLines[lineNumber - 1];
+        if (text !== undefined && exceedsLineLength(text, maxLineLength)) {
+          lineNumbers.push(lineNumber);
+        }
+      }
+      additionOffset += content.additions;
+    }
+  }
+
+  return lineNumbers;
+}
+
+export function overlongLineCSS(lineNumbers: readonly number[]): string {
+  if (lineNumbers.length === 0) return "";
+
+  const selectors = lineNumbers.map(
+    (lineNumber) => `[data-line-type='change-addition'][data-line='${lineNumber}']`,
+  );
+  return `
+${selectors.join(",\n")} {
+  background-image: linear-gradient(rgb(217 119 6 / 0.16), rgb(217 119 6 / 0.16));
+  box-shadow: inset 2px 0 0 rgb(217 119 6 / 0.9);
+}
+`;
+}
@@ -0,0 +1,34 @@
+import { describe, expect, it } from "vitest";
+
+import { blameRevision, diffLineReference } from "./lineReference";
+
+describe("diffLineReference", () => {
+  it("encodes the working tree, index, or commit the line belongs to", () => {
+    const commitId = "3f2a9c1d0e8b7a6f5e4d3c2b1a0f9e8d7c6b5a49";
+
+    expect(diffLineReference({ kind: "worktree" }, "src/main.ts", 12)).toBe("src/main.ts:12");
+    expect(diffLineReference({ kind: "index" }, "src/main.ts", 12)).toBe(":src/main.ts:12");
+    expect(diffLineReference({ kind: "commit", commitId }, "src/main.ts", 12)).toBe(
+      `${commitId}:src/main.ts:12`,
+    );
+  });
+
+  it("points removed lines at the old side of the diff", () => {
+    expect(diffLineReference({ kind: "worktree" }, "a.ts", 3, "deletions")).toBe(":a.ts:3");
+    expect(diffLineReference({ kind: "index" }, "a.ts", 3, "deletions")).toBe("HEAD:a.ts:3");
+    expect(diffLineReference({ kind: "commit", commitId: "abc1234" }, "a.ts", 3, "deletions")).toBe(
+      "abc1234^:a.ts:3",
+    );
+  });
+});
+
+describe("blameRevision", () => {
+  it("blames the side of the diff the line was clicked on", () => {
+    const source = { kind: "commit", commitId: "abc1234" } as const;
+
+    expect(blameRevision(source)).toBe("abc1234");
+    expect(blameRevision(source, "deletions")).toBe("abc1234^");
+    expect(blameRevision({ kind: "worktree" })).toBeUndefined();
+    expect(blameRevision({ kind: "worktree" }, "deletions")).toBe("HEAD");
+  });
+});
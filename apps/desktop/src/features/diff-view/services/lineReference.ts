@@ -0,0 +1,42 @@
+export type DiffLineSource =
+  | { kind: "worktree" }
+  | { kind: "index" }
+  | { kind: "commit"; commitId: string };
+
+function revisionPrefix(source: DiffLineSource, side: "additions" | "deletions") {
+  if (source.kind === "commit") {
+    return side === "additions" ? `${source.commitId}:` : `${source.commitId}^:`;
+  }
+
+  // The old side of an unstaged diff is the index; the old side of a staged diff is HEAD.
+  if (source.kind === "index") {
+    return side === "additions" ? ":" : "HEAD:";
+  }
+
+  return side === "additions" ? "" : ":";
+}
+
+// References reuse git's revision syntax, so `path` is the working tree, `:path` the index,
+// and `<commit>:path` a commit, each followed by the line number on that side of the diff.
+export function diffLineReference(
+  source: DiffLineSource,
+  filePath: string,
+  lineNumber: number,
+  side: "additions" | "deletions" = "additions",
+) {
+  return `${revisionPrefix(source, side)}${filePath}:${lineNumber}`;
+}
+
+// The revision to blame for a line, or undefined for the working tree. git cannot blame the
+// index directly, so staged lines are blamed in the working tree and the old side of an
+// unstaged diff at HEAD; both agree unless the file has both staged and unstaged edits.
+export function blameRevision(
+  source: DiffLineSource,
+  side: "additions" | "deletions" = "additions",
+): string | undefined {
+  if (source.kind === "commit") {
+    return side === "additions" ? source.commitId : `${source.commitId}^`;
+  }
+
+  return side === "additions" ? undefined : "HEAD";
+}
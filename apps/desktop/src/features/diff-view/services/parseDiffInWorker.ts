@@ -4,7 +4,7 @@ import type { parseDiffFromFile } from "@pierre/diffs";
 import type { DiffFile } from "@/features/source-control/types";
 
 type ParsedDiff = ReturnType<typeof parseDiffFromFile>;
-type ParseWorkerFile = DiffFile & { cacheKey?: string };
+type ParseWorkerFile = DiffFile & { cacheKey?: string; lang?: "text" };
 export type ParsePriority = "high" | "low";
 
 type ParseResponseMessage =
@@ -1,16 +1,27 @@
 import type { ParsePriority } from "@/features/diff-view/services/parseDiffInWorker";
-import { getDiffRenderGate } from "@/features/diff-view/services/diffRenderLimits";
+import {
+  getDiffRenderGate,
+  shouldSkipHighlighting,
+} from "@/features/diff-view/services/diffRenderLimits";
+import { DEFAULT_MAX_HIGHLIGHT_BYTES } from "@/platform/desktop/appSettings";
 import { parseDiffInWorker } from "@/features/diff-view/services/parseDiffInWorker";
+import {
+  isPlainTextPath,
+  languageForPath,
+  needsFallbackLanguage,
+  type HighlightLanguage,
+} from "@/features/diff-view/services/repoLanguage";
 import type { DiffFile } from "@/features/source-control/types";
 
 export type ParsedDiff = Awaited<ReturnType<typeof parseDiffInWorker>>;
 
-type ParseWorkerFile = DiffFile & { cacheKey?: string };
+type ParseWorkerFile = DiffFile & { cacheKey?: string; lang?: HighlightLanguage };
 
-type ParsedDiffRequest = {
+export type ParsedDiffRequest = {
   key: string;
   oldFile: ParseWorkerFile;
   newFile: ParseWorkerFile;
+  highlightSkipped: boolean;
 };
 
 const MAX_PARSED_DIFF_CACHE_SIZE = 64;
@@ -40,19 +51,27 @@ function hashStringFNV1a(value: string): string {
   return (hash >>> 0).toString(36);
 }
 
-function getFileCacheKey(file: DiffFile): string {
-  const nameHash = hashStringFNV1a(file.name);
+// The highlighter's output depends only on the contents and the grammar, so a file whose grammar is
+// known is keyed by that grammar and identical contents share one highlight across paths. Otherwise
+// the grammar is inferred from the name, which then has to stay in the key.
+function getFileCacheKey(file: DiffFile, lang: HighlightLanguage | null): string {
+  const identity = lang ? `l-${lang}` : `f-${hashStringFNV1a(file.name)}`;
   const contentsHash = hashStringFNV1a(file.contents);
 
-  return `f-${nameHash}-${file.contents.length}-${contentsHash}`;
+  return `${identity}-${file.contents.length}-${contentsHash}`;
 }
 
-function withCacheKey(file: DiffFile, salt = ""): ParseWorkerFile {
-  const baseCacheKey = getFileCacheKey(file);
-  return {
-    ...file,
-    cacheKey: salt ? `${baseCacheKey}:${salt}` : baseCacheKey,
-  };
+function withCacheKey(
+  file: DiffFile,
+  salt = "",
+  plainText = false,
+  fallbackLang: HighlightLanguage | null = null,
+): ParseWorkerFile {
+  const lang: HighlightLanguage | null = plainText ? "text" : fallbackLang;
+  const baseCacheKey = getFileCacheKey(file, lang ?? languageForPath(file.name));
+  const cacheKey = salt ? `${baseCacheKey}:${salt}` : baseCacheKey;
+
+  return lang ? { ...file, cacheKey, lang } : { ...file, cacheKey };
 }
 
 function touchParsedDiff(key: string, diff: ParsedDiff | null) {
@@ -71,7 +90,11 @@ export function getParsedDiffRequest(
   oldFile: DiffFile | null,
   newFile: DiffFile | null,
   cacheSalt = "",
-  options: { allowLargeDiff?: boolean } = {},
+  options: {
+    allowLargeDiff?: boolean;
+    maxHighlightBytes?: number;
+    fallbackLang?: HighlightLanguage | null;
+  } = {},
 ): ParsedDiffRequest | null {
   const diffRenderGate = getDiffRenderGate(activePath, oldFile, newFile);
   if (!diffRenderGate || diffRenderGate === "unrenderable") return null;
@@ -80,13 +103,25 @@ export function getParsedDiffRequest(
   const fallbackPath = activePath ?? "";
   const oldTargetFile = oldFile ?? { name: fallbackPath, contents: "" };
   const newTargetFile = newFile ?? { name: fallbackPath, contents: "" };
-  const oldFileWithCacheKey = withCacheKey(oldTargetFile, cacheSalt);
-  const newFileWithCacheKey = withCacheKey(newTargetFile, cacheSalt);
+  const highlightSkipped = shouldSkipHighlighting(
+    oldFile,
+    newFile,
+    options.maxHighlightBytes ?? DEFAULT_MAX_HIGHLIGHT_BYTES,
+  );
+  const plainText = highlightSkipped || isPlainTextPath(fallbackPath);
+  const fallbackLang =
+    options.fallbackLang && needsFallbackLanguage(fallbackPath) ? options.fallbackLang : null;
+  const oldFileWithCacheKey = withCacheKey(oldTargetFile, cacheSalt, plainText, fallbackLang);
+  const newFileWithCacheKey = withCacheKey(newTargetFile, cacheSalt, plainText, fallbackLang);
+
+  // Parsed diffs carry the file names, so unlike the per-file highlight keys this one includes them.
+  const namesKey = `${hashStringFNV1a(oldTargetFile.name)}-${hashStringFNV1a(newTargetFile.name)}`;
 
   return {
-    key: `${oldFileWithCacheKey.cacheKey}:${newFileWithCacheKey.cacheKey}`,
+    key: `${oldFileWithCacheKey.cacheKey}:${newFileWithCacheKey.cacheKey}:n-${namesKey}`,
     oldFile: oldFileWithCacheKey,
     newFile: newFileWithCacheKey,
+    highlightSkipped,
   };
 }
 
@@ -138,6 +173,7 @@ export async function loadParsedDiff(
         return null;
       }
 
+      console.warn(`Failed to parse diff for ${request.newFile.name}`, error);
       touchParsedDiff(request.key, null);
       return null;
     })
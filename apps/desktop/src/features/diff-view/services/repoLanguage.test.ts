@@ -0,0 +1,51 @@
+import { describe, expect, it } from "vitest";
+
+import {
+  detectRepoPrimaryLanguage,
+  isProsePath,
+  languageForPath,
+  needsFallbackLanguage,
+} from "./repoLanguage";
+
+function filesOf(paths: string[]) {
+  return paths.map((path) => ({ path }));
+}
+
+describe("detectRepoPrimaryLanguage", () => {
+  it("picks the language with the most tracked files", () => {
+    const files = filesOf([
+      "Cargo.toml",
+      "README.md",
+      "src/main.rs",
+      "src/lib.rs",
+      "src/parser.rs",
+      "scripts/release.py",
+      "bin/setup",
+    ]);
+
+    expect(detectRepoPrimaryLanguage(files)).toBe("rust");
+  });
+
+  it("returns null when no file has a recognized extension", () => {
+    expect(detectRepoPrimaryLanguage(filesOf(["README.md", "LICENSE"]))).toBeNull();
+  });
+});
+
+describe("needsFallbackLanguage", () => {
+  it("only falls back for extensionless files the highlighter can't place by name", () => {
+    expect(needsFallbackLanguage("bin/setup")).toBe(true);
+    expect(needsFallbackLanguage("Makefile")).toBe(false);
+    expect(needsFallbackLanguage(".envrc")).toBe(false);
+    expect(needsFallbackLanguage("src/main.rs")).toBe(false);
+    expect(languageForPath("src/main.rs")).toBe("rust");
+  });
+});
+
+describe("isProsePath", () => {
+  it("treats markup, plain text, and well-known prose files as prose", () => {
+    expect(isProsePath("docs/intro.mdx")).toBe(true);
+    expect(isProsePath("CHANGELOG")).toBe(true);
+    expect(isProsePath("src/App.tsx")).toBe(false);
+    expect(isProsePath("Makefile")).toBe(false);
+  });
+});
@@ -0,0 +1,120 @@
+import type { FileContents } from "@pierre/diffs";
+
+export type HighlightLanguage = NonNullable<FileContents["lang"]>;
+
+const LANGUAGE_BY_EXTENSION: Record<string, HighlightLanguage> = {
+  c: "c",
+  cc: "cpp",
+  cpp: "cpp",
+  cs: "csharp",
+  css: "css",
+  go: "go",
+  h: "c",
+  hpp: "cpp",
+  java: "java",
+  js: "javascript",
+  jsx: "jsx",
+  kt: "kotlin",
+  lua: "lua",
+  mjs: "javascript",
+  php: "php",
+  py: "python",
+  rb: "ruby",
+  rs: "rust",
+  scala: "scala",
+  sh: "shellscript",
+  swift: "swift",
+  ts: "typescript",
+  tsx: "tsx",
+  zig: "zig",
+};
+
+// Extensionless files the highlighter already recognizes by name, or that are prose.
+const NAMED_FILES = new Set([
+  "authors",
+  "changelog",
+  "codeowners",
+  "copying",
+  "dockerfile",
+  "gemfile",
+  "justfile",
+  "license",
+  "makefile",
+  "procfile",
+  "rakefile",
+  "readme",
+]);
+
+// Prose and log formats have no grammar by design, so they are never sent to the highlighter.
+const PLAIN_TEXT_EXTENSIONS = new Set(["log", "out", "text", "txt"]);
+
+// Formats read as running text, where a wrapped word is harder to read than a ragged edge.
+const PROSE_EXTENSIONS = new Set(["adoc", "markdown", "md", "mdx", "org", "rst", "tex"]);
+const PROSE_FILES = new Set(["authors", "changelog", "copying", "license", "readme"]);
+
+const MAX_SAMPLED_PATHS = 5000;
+
+// Keyed by the cached repo file list, so each repo is sampled once per file-list refresh.
+const primaryLanguageCache = new WeakMap<readonly { path: string }[], HighlightLanguage | null>();
+
+function fileName(path: string) {
+  return path.slice(path.lastIndexOf("/") + 1);
+}
+
+function extensionOf(path: string) {
+  const name = fileName(path);
+  const dotIndex = name.lastIndexOf(".");
+  return dotIndex > 0 ? name.slice(dotIndex + 1).toLowerCase() : null;
+}
+
+export function languageForPath(path: string): HighlightLanguage | null {
+  const extension = extensionOf(path);
+  return extension ? (LANGUAGE_BY_EXTENSION[extension] ?? null) : null;
+}
+
+export function isPlainTextPath(path: string) {
+  const extension = extensionOf(path);
+  return extension !== null && PLAIN_TEXT_EXTENSIONS.has(extension);
+}
+
+export function isProsePath(path: string) {
+  const extension = extensionOf(path);
+  if (extension === null) {
+    return PROSE_FILES.has(fileName(path).toLowerCase());
+  }
+
+  return PROSE_EXTENSIONS.has(extension) || PLAIN_TEXT_EXTENSIONS.has(extension);
+}
+
+// Only extensionless scripts fall back; unfamiliar extensions are left to the highlighter.
+export function needsFallbackLanguage(path: string) {
+  const name = fileName(path);
+  return (
+    !!name && !name.startsWith(".") && !name.includes(".") && !NAMED_FILES.has(name.toLowerCase())
+  );
+}
+
+export function detectRepoPrimaryLanguage(
+  files: readonly { path: string }[],
+): HighlightLanguage | null {
+  const cached = primaryLanguageCache.get(files);
+  if (cached !== undefined) return cached;
+
+  const counts = new Map<HighlightLanguage, number>();
+  for (const { path } of files.slice(0, MAX_SAMPLED_PATHS)) {
+    const language = languageForPath(path);
+    if (language) counts.set(language, (counts.get(language) ?? 0) + 1);
+  }
+
+  let primary: HighlightLanguage | null = null;
+  let primaryCount = 0;
+  for (const [language, count] of counts) {
+    if (count > primaryCount) {
+      primary = language;
+      primaryCount = count;
+    }
+  }
+
+  primaryLanguageCache.set(files, primary);
+  return primary;
+}
@@ -0,0 +1,123 @@
+import type { FileDiffMetadata } from "@pierre/diffs";
+
+export type SecretKind = "aws-access-key" | "private-key" | "github-token" | "slack-token" | "base64";
+
+export type SecretFinding = {
+  lineNumber: number;
+  kind: SecretKind;
+  label: string;
+};
+
+type SecretPattern = {
+  kind: SecretKind;
+  label: string;
+  pattern: RegExp;
+  accept?: (match: string, before: string) => boolean;
+};
+
+const MIN_BASE64_TOKEN_LENGTH = 40;
+// Random base64 sits near 5 bits per character; identifiers and paths repeat letters and fall
+// well under this.
+const MIN_BASE64_ENTROPY_BITS = 4.5;
+
+// Subresource integrity values (`sha512-…`) and lockfile checksums are hashes, not secrets.
+const HASH_PREFIX = /^sha(?:1|256|384|512)-/i;
+const HASH_CONTEXT = /\b(?:integrity|checksum|hash|digest)\b["']?\s*[:=]?\s*["']?$/i;
+const URL_CONTEXT = /[a-z][a-z0-9+.-]*:\/\/\S*$/i;
+
+function shannonEntropy(value: string) {
+  const counts = new Map<string, number>();
+  for (const char of value) {
+    counts.set(char, (counts.get(char) ?? 0) + 1);
+  }
+
+  let entropy = 0;
+  for (const count of counts.values()) {
+    const probability = count / value.length;
+    entropy -= probability * Math.log2(probability);
+  }
+  return entropy;
+}
+
+function looksLikeEncodedBlob(match: string, before: string) {
+  if (HASH_PREFIX.test(match) || HASH_CONTEXT.test(before) || URL_CONTEXT.test(before)) {
+    return false;
+  }
+
+  const body = match.replace(/=+$/, "");
+  // Base64 uses either `+/` or the URL-safe `-_`, never both; paths and slugs mix them.
+  if (/[+/]/.test(body) && /[-_]/.test(body)) return false;
+  // Hex digests and identifiers are long too; real key material mixes cases and digits.
+  if (!/[a-z]/.test(body) || !/[A-Z]/.test(body) || !/\d/.test(body)) return false;
+
+  return shannonEntropy(body) >= MIN_BASE64_ENTROPY_BITS;
+}
+
+export const SECRET_PATTERNS: readonly SecretPattern[] = [
+  {
+    kind: "private-key",
+    label: "Private key",
+    pattern: /-----BEGIN (?:[A-Z0-9]+ )*PRIVATE KEY-----/g,
+  },
+  {
+    kind: "aws-access-key",
+    label: "AWS access key",
+    pattern: /\b(?:AKIA|ASIA)[0-9A-Z]{16}\b/g,
+  },
+  {
+    kind: "github-token",
+    label: "GitHub token",
+    pattern: /\bgh[pousr]_[A-Za-z0-9]{36,}\b/g,
+  },
+  {
+    kind: "slack-token",
+    label: "Slack token",
+    pattern: /\bxox[abprs]-[A-Za-z0-9-]{10,}/g,
+  },
+  {
+    kind: "base64",
+    label: "Long encoded token",
+    pattern: new RegExp(`[A-Za-z0-9+/_-]{${MIN_BASE64_TOKEN_LENGTH},}={0,2}`, "g"),
+    accept: looksLikeEncodedBlob,
+  },
+];
+
+export function findSecretInLine(text: string): Omit<SecretFinding, "lineNumber"> | null {
+  for (const { kind, label, pattern, accept } of SECRET_PATTERNS) {
+    for (const match of text.matchAll(pattern)) {
+      if (!accept || accept(match[0], text.slice(0, match.index))) {
+        return { kind, label };
+      }
+    }
+  }
+
+  return null;
+}
+
+// Only added lines are scanned: removing a leaked key is exactly what a reviewer wants to see.
+export function scanAddedLines(fileDiff: FileDiffMetadata): SecretFinding[] {
+  const findings: SecretFinding[] = [];
+
+  for (const hunk of fileDiff.hunks) {
+    let additionOffset = 0;
+
+    for (const content of hunk.hunkContent) {
+      if (content.type === "context") {
+        additionOffset += content.lines;
+        continue;
+      }
+
+      for (let index = 0; index < content.additions; index += 1) {
+        const lineNumber = hunk.additionStart + additionOffset + index;
+        const text = fileDiff.additionLines[lineNumber - 1];
+        const secret = text ? findSecretInLine(text) : null;
+        if (secret) {
+          findings.push({ lineNumber, ...secret });
+        }
+      }
+      additionOffset += content.additions;
+    }
+  }
+
+  return findings;
+}
@@ -0,0 +1,147 @@
+import { parseDiffFromFile } from "@pierre/diffs";
+import { describe, expect, it } from "vitest";
+
+import { formatUnifiedDiff, parseUnifiedDiff, patchFileContents } from "./unifiedDiff";
+
+const NO_NEWLINE_DIFF = [
+  "diff --git a/notes.txt b/notes.txt",
+  "index 3b18e51..a9c2f0b 100644",
+  "--- a/notes.txt",
+  "+++ b/notes.txt",
+  "@@ -1,3 +1,4 @@",
+  " alpha",
+  " beta",
+  "-gamma",
+  "\\ No newline at end of file",
+  "+gamma",
+  "+delta",
+  "\\ No newline at end of file",
+  "",
+].join("\n");
+
+describe("parseUnifiedDiff", () => {
+  it("keeps line numbers intact across no-newline markers and flags the affected side", () => {
+    const [file] = parseUnifiedDiff(NO_NEWLINE_DIFF);
+    const hunk = file?.hunks[0];
+
+    expect(file?.oldPath).toBe("notes.txt");
+    expect(file?.newPath).toBe("notes.txt");
+    expect(hunk?.noNewlineOld).toBe(true);
+    expect(hunk?.noNewlineNew).toBe(true);
+    expect(
+      hunk?.lines.map((line) => [line.kind, line.oldLineNumber, line.newLineNumber, line.content]),
+    ).toEqual([
+      ["context", 1, 1, "alpha"],
+      ["context", 2, 2, "beta"],
+      ["remove", 3, null, "gamma"],
+      ["add", null, 3, "gamma"],
+      ["add", null, 4, "delta"],
+    ]);
+    expect(hunk?.lines.filter((line) => line.noNewlineAtEof).map((line) => line.kind)).toEqual([
+      "remove",
+      "add",
+    ]);
+  });
+
+  it("splits multi-file diffs and recognizes binary changes", () => {
+    const files = parseUnifiedDiff(
+      [
+        "--- a/one.txt",
+        "+++ b/one.txt",
+        "@@ -1 +1 @@",
+        "-old",
+        "+new",
+        "diff --git a/logo.png b/logo.png",
+        "index 1111111..2222222 100644",
+        "Binary files a/logo.png and b/logo.png differ",
+        "--- /dev/null",
+        "+++ b/added.txt",
+        "@@ -0,0 +1,2 @@",
+        "+first",
+        "+second",
+      ].join("\n"),
+    );
+
+    expect(
+      files.map((file) => [file.oldPath, file.newPath, file.isBinary, file.hunks.length]),
+    ).toEqual([
+      ["one.txt", "one.txt", false, 1],
+      ["logo.png", "logo.png", true, 0],
+      [null, "added.txt", false, 1],
+    ]);
+    expect(files[2]?.hunks[0]?.lines.map((line) => line.newLineNumber)).toEqual([1, 2]);
+  });
+});
+
+describe("formatUnifiedDiff", () => {
+  it("round-trips git's patch output, headers included", () => {
+    const patch = [
+      "diff --git a/src/app.ts b/src/app.ts",
+      "index 83db48f..bf269f4 100644",
+      "--- a/src/app.ts",
+      "+++ b/src/app.ts",
+      "@@ -1,2 +1,2 @@ export function start() {",
+      "-const port = 3000;",
+      "+const port = 8080;",
+      " start(port);",
+      "@@ -10 +10,2 @@ function stop() {",
+      " stop();",
+      "+cleanup();",
+      "diff --git a/old.txt b/new.txt",
+      "similarity index 100%",
+      "rename from old.txt",
+      "rename to new.txt",
+      "",
+    ].join("\n");
+
+    expect(formatUnifiedDiff(parseUnifiedDiff(patch))).toBe(patch);
+    expect(formatUnifiedDiff(parseUnifiedDiff(NO_NEWLINE_DIFF))).toBe(NO_NEWLINE_DIFF);
+  });
+});
+
+describe("patchFileContents", () => {
+  it("rebuilds a pasted multi-file diff into the same files and hunks", () => {
+    const files = parseUnifiedDiff(
+      [
+        "diff --git a/src/app.ts b/src/app.ts",
+        "index 1111111..2222222 100644",
+        "--- a/src/app.ts",
+        "+++ b/src/app.ts",
+        "@@ -2,3 +2,3 @@ import { a } from './a';",
+        " const one = 1;",
+        "-const two = 2;",
+        "+const two = 22;",
+        " const three = 3;",
+        "@@ -20,3 +20,4 @@ export function run() {",
+        "   start();",
+        "+  log();",
+        "   stop();",
+        " }",
+        "diff --git a/README.md b/README.md",
+        "--- a/README.md",
+        "+++ b/README.md",
+        "@@ -1,2 +1,2 @@",
+        "-# Old title",
+        "+# New title",
+        " Intro",
+        "",
+      ].join("\n"),
+    );
+
+    const rendered = files.map((file) => {
+      const { oldContents, newContents } = patchFileContents(file);
+      const name = file.newPath ?? file.oldPath ?? "";
+      const fileDiff = parseDiffFromFile(
+        { name, contents: oldContents },
+        { name, contents: newContents },
+      );
+      return [name, fileDiff.hunks.length];
+    });
+
+    expect(rendered).toEqual([
+      ["src/app.ts", 2],
+      ["README.md", 1],
+    ]);
+    expect(patchFileContents(files[0]!).newContents.split("\n")[20]).toBe("  log();");
+  });
+});
@@ -0,0 +1,250 @@
+export type UnifiedDiffLineKind = "context" | "add" | "remove";
+
+export type UnifiedDiffLine = {
+  kind: UnifiedDiffLineKind;
+  content: string;
+  oldLineNumber: number | null;
+  newLineNumber: number | null;
+  noNewlineAtEof?: boolean;
+};
+
+export type UnifiedDiffHunk = {
+  oldStart: number;
+  oldLines: number;
+  newStart: number;
+  newLines: number;
+  section: string;
+  lines: UnifiedDiffLine[];
+  noNewlineOld: boolean;
+  noNewlineNew: boolean;
+};
+
+export type UnifiedDiffFile = {
+  oldPath: string | null;
+  newPath: string | null;
+  headerLines: string[];
+  hunks: UnifiedDiffHunk[];
+  isBinary: boolean;
+};
+
+const HUNK_HEADER_PATTERN = /^@@ -(\d+)(?:,(\d+))? \+(\d+)(?:,(\d+))? @@ ?(.*)$/;
+const NO_NEWLINE_MARKER_PREFIX = "\\";
+
+function parseHeaderPath(line: string): string | null {
+  const rawPath = line.slice(4).split("\t")[0] ?? "";
+  if (rawPath === "/dev/null") {
+    return null;
+  }
+
+  return rawPath.replace(/^[ab]\//, "");
+}
+
+function parseGitHeaderPaths(line: string): { oldPath: string; newPath: string } | null {
+  const match = /^diff --git a\/(.+) b\/(.+)$/.exec(line);
+  return match ? { oldPath: match[1]!, newPath: match[2]! } : null;
+}
+
+function createFile(headerLine: string | null): UnifiedDiffFile {
+  const gitPaths = headerLine ? parseGitHeaderPaths(headerLine) : null;
+
+  return {
+    oldPath: gitPaths?.oldPath ?? null,
+    newPath: gitPaths?.newPath ?? null,
+    headerLines: headerLine ? [headerLine] : [],
+    hunks: [],
+    isBinary: false,
+  };
+}
+
+function applyNoNewlineMarker(hunk: UnifiedDiffHunk) {
+  const previousLine = hunk.lines[hunk.lines.length - 1];
+  if (!previousLine) {
+    return;
+  }
+
+  previousLine.noNewlineAtEof = true;
+  if (previousLine.kind !== "add") {
+    hunk.noNewlineOld = true;
+  }
+  if (previousLine.kind !== "remove") {
+    hunk.noNewlineNew = true;
+  }
+}
+
+export function parseUnifiedDiff(text: string): UnifiedDiffFile[] {
+  const files: UnifiedDiffFile[] = [];
+  const lines = text.split("\n");
+  if (lines[lines.length - 1] === "") {
+    lines.pop();
+  }
+
+  let file: UnifiedDiffFile | null = null;
+  let fileHasPathHeader = false;
+  let hunk: UnifiedDiffHunk | null = null;
+  let oldLineNumber = 0;
+  let newLineNumber = 0;
+  let oldRemaining = 0;
+  let newRemaining = 0;
+
+  for (let index = 0; index < lines.length; index += 1) {
+    const line = lines[index]!.replace(/\r$/, "");
+    const inHunkBody = hunk !== null && (oldRemaining > 0 || newRemaining > 0);
+
+    if (hunk && line.startsWith(NO_NEWLINE_MARKER_PREFIX)) {
+      // The marker describes the previous line and never occupies a line number.
+      applyNoNewlineMarker(hunk);
+      continue;
+    }
+
+    if (inHunkBody && hunk) {
+      const marker = line[0] ?? " ";
+      const content = line.slice(1);
+
+      if (marker === "+") {
+        hunk.lines.push({ kind: "add", content, oldLineNumber: null, newLineNumber });
+        newLineNumber += 1;
+        newRemaining -= 1;
+        continue;
+      }
+
+      if (marker === "-") {
+        hunk.lines.push({ kind: "remove", content, oldLineNumber, newLineNumber: null });
+        oldLineNumber += 1;
+        oldRemaining -= 1;
+        continue;
+      }
+
+      if (marker === " " || line === "") {
+        hunk.lines.push({ kind: "context", content, oldLineNumber, newLineNumber });
+        oldLineNumber += 1;
+        newLineNumber += 1;
+        oldRemaining -= 1;
+        newRemaining -= 1;
+        continue;
+      }
+    }
+
+    if (line.startsWith("diff --git ")) {
+      file = createFile(line);
+      files.push(file);
+      fileHasPathHeader = false;
+      hunk = null;
+      continue;
+    }
+
+    if (line.startsWith("--- ") && lines[index + 1]?.startsWith("+++ ")) {
+      if (!file || fileHasPathHeader || file.isBinary) {
+        file = createFile(null);
+        files.push(file);
+      }
+      fileHasPathHeader = true;
+      file.oldPath = parseHeaderPath(line);
+      file.newPath = parseHeaderPath(lines[index + 1]!.replace(/\r$/, ""));
+      file.headerLines.push(line, lines[index + 1]!.replace(/\r$/, ""));
+      hunk = null;
+      index += 1;
+      continue;
+    }
+
+    const hunkMatch = HUNK_HEADER_PATTERN.exec(line);
+    if (hunkMatch && file) {
+      const oldStart = Number(hunkMatch[1]);
+      const oldLines = hunkMatch[2] === undefined ? 1 : Number(hunkMatch[2]);
+      const newStart = Number(hunkMatch[3]);
+      const newLines = hunkMatch[4] === undefined ? 1 : Number(hunkMatch[4]);
+
+      hunk = {
+        oldStart,
+        oldLines,
+        newStart,
+        newLines,
+        section: hunkMatch[5] ?? "",
+        lines: [],
+        noNewlineOld: false,
+        noNewlineNew: false,
+      };
+      file.hunks.push(hunk);
+      oldLineNumber = oldStart;
+      newLineNumber = newStart;
+      oldRemaining = oldLines;
+      newRemaining = newLines;
+      continue;
+    }
+
+    if (file && file.hunks.length === 0) {
+      file.headerLines.push(line);
+      if (line.startsWith("Binary files ") || line === "GIT binary patch") {
+        file.isBinary = true;
+      }
+    }
+  }
+
+  return files;
+}
+
+const LINE_MARKER: Record<UnifiedDiffLineKind, string> = {
+  context: " ",
+  add: "+",
+  remove: "-",
+};
+
+function formatHunkRange(start: number, count: number) {
+  // git omits the count when it is 1; keeping that form lets patches round-trip byte for byte.
+  return count === 1 ? String(start) : `${start},${count}`;
+}
+
+// Inverse of parseUnifiedDiff: header lines (diff --git, index, mode, ---/+++) are kept verbatim,
+// so the result stays applicable with `git apply`.
+export function formatUnifiedDiff(files: readonly UnifiedDiffFile[]): string {
+  const rows: string[] = [];
+
+  for (const file of files) {
+    rows.push(...file.headerLines);
+
+    for (const hunk of file.hunks) {
+      const oldRange = formatHunkRange(hunk.oldStart, hunk.oldLines);
+      const newRange = formatHunkRange(hunk.newStart, hunk.newLines);
+      const section = hunk.section ? ` ${hunk.section}` : "";
+      rows.push(`@@ -${oldRange} +${newRange} @@${section}`);
+
+      for (const line of hunk.lines) {
+        rows.push(`${LINE_MARKER[line.kind]}${line.content}`);
+        if (line.noNewlineAtEof) {
+          rows.push("\\ No newline at end of file");
+        }
+      }
+    }
+  }
+
+  return rows.length > 0 ? `${rows.join("\n")}\n` : "";
+}
+
+function joinPatchLines(lines: string[], noNewlineAtEof: boolean) {
+  if (lines.length === 0) return "";
+  const text = Array.from(lines, (line) => line ?? "").join("\n");
+  return noNewlineAtEof ? text : `${text}\n`;
+}
+
+// A pasted patch only carries its hunks, not the files. Lines outside the hunks are left blank,
+// which keeps every hunk at its original line numbers and diffs the two sides back into the same
+// hunks. The unchanged gaps between hunks are the same length on both sides, so they line up.
+export function patchFileContents(file: UnifiedDiffFile): {
+  oldContents: string;
+  newContents: string;
+} {
+  const oldLines: string[] = [];
+  const newLines: string[] = [];
+
+  for (const hunk of file.hunks) {
+    for (const line of hunk.lines) {
+      if (line.oldLineNumber !== null) oldLines[line.oldLineNumber - 1] = line.content;
+      if (line.newLineNumber !== null) newLines[line.newLineNumber - 1] = line.content;
+    }
+  }
+
+  const lastHunk = file.hunks[file.hunks.length - 1];
+  return {
+    oldContents: joinPatchLines(oldLines, lastHunk?.noNewlineOld ?? false),
+    newContents: joinPatchLines(newLines, lastHunk?.noNewlineNew ?? false),
+  };
+}
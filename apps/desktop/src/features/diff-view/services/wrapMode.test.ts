@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+
+import { diffWrapCSS, diffWrapModeForPath } from "./wrapMode";
+
+describe("diffWrapModeForPath", () => {
+  it("wraps prose at whitespace and code anywhere", () => {
+    expect(diffWrapModeForPath("docs/guide.md")).toBe("whitespace");
+    expect(diffWrapModeForPath("notes/todo.txt")).toBe("whitespace");
+    expect(diffWrapModeForPath("README")).toBe("whitespace");
+    expect(diffWrapModeForPath("src/main.rs")).toBe("anywhere");
+    expect(diffWrapModeForPath("bin/setup")).toBe("anywhere");
+  });
+});
+
+describe("diffWrapCSS", () => {
+  it("keeps words whole for prose and lets code break mid-token", () => {
+    expect(diffWrapCSS("whitespace")).toContain("word-break: normal;");
+    expect(diffWrapCSS("whitespace")).not.toContain("break-all");
+    expect(diffWrapCSS("anywhere")).toContain("word-break: break-all;");
+  });
+});
@@ -0,0 +1,22 @@
+import { isProsePath } from "./repoLanguage";
+
+// "whitespace" only breaks between words unless a single word is wider than the view;
+// "anywhere" breaks at the column edge, which keeps code rows compact.
+export type DiffWrapMode = "whitespace" | "anywhere";
+
+export function diffWrapModeForPath(path: string): DiffWrapMode {
+  return isProsePath(path) ? "whitespace" : "anywhere";
+}
+
+export function diffWrapCSS(mode: DiffWrapMode): string {
+  const rules =
+    mode === "whitespace"
+      ? "word-break: normal;\n  overflow-wrap: break-word;"
+      : "word-break: break-all;\n  overflow-wrap: anywhere;";
+
+  return `
+[data-line] {
+  ${rules}
+}
+`;
+}
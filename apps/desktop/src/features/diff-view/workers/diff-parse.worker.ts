@@ -6,6 +6,7 @@ type DiffFile = {
   name: string;
   contents: string;
   cacheKey?: string;
+  lang?: "text";
 };
 
 type ParseRequestMessage = {
@@ -7,6 +7,7 @@ import { Button } from "@/components/ui/button";
 import { Tooltip, TooltipContent, TooltipProvider, TooltipTrigger } from "@/components/ui/tooltip";
 import { useGetPullRequestConversationQuery } from "@/features/hosted-repos/api";
 import { usePullRequestPendingReviewActions } from "@/features/pull-requests/hooks/usePullRequestPendingReviewActions";
+import { PULL_REQUEST_CONVERSATION_POLLING } from "@/features/pull-requests/polling";
 import { buildPullRequestReviewCommentsPayload } from "@/features/pull-requests/utils/reviewCommentsPayload";
 import { isTypingTarget } from "@/features/source-control/utils";
 
@@ -43,9 +44,7 @@ function ReviewCommentsCopyToolbar({
     selectFromResult: ({ data }) => ({
       reviewThreads: data?.reviewThreads ?? [],
     }),
-    pollingInterval: 10000,
-    refetchOnFocus: true,
-    refetchOnReconnect: true,
+    ...PULL_REQUEST_CONVERSATION_POLLING,
   });
 
   const filePendingPayload = activePath ? pendingActions.getPendingPayloadForFile(activePath) : "";
@@ -0,0 +1,8 @@
+// Conversation polling pauses while the window is unfocused; focus triggers an immediate refetch,
+// so an idle app in the background stops hitting the provider API every few seconds.
+export const PULL_REQUEST_CONVERSATION_POLLING = {
+  pollingInterval: 10000,
+  skipPollingIfUnfocused: true,
+  refetchOnFocus: true,
+  refetchOnReconnect: true,
+} as const;
@@ -13,6 +13,7 @@ import {
 } from "@/components/ui/dropdown-menu";
 import { countCommentsForPathInRepoContext } from "@/features/comments/selectors";
 import { useGetPullRequestConversationQuery } from "@/features/hosted-repos/api";
+import { PULL_REQUEST_CONVERSATION_POLLING } from "@/features/pull-requests/polling";
 import { FileList } from "@/features/source-control/components/FileList";
 import { useSimpleFileListKeyboardNav } from "@/features/source-control/hooks/useSimpleFileListKeyboardNav";
 import { countPullRequestThreadsForFile } from "@/features/pull-requests/utils/reviewThreadAnnotations";
@@ -66,9 +67,7 @@ export default function PullRequestFileList({
       selectFromResult: ({ data }) => ({
         reviewThreads: data?.reviewThreads ?? EMPTY_REVIEW_THREADS,
       }),
-      pollingInterval: 10000,
-      refetchOnFocus: true,
-      refetchOnReconnect: true,
+      ...PULL_REQUEST_CONVERSATION_POLLING,
     },
   );
   const pendingCommentCountByPath = useMemo(() => {
@@ -24,6 +24,7 @@ import { PullRequestPreviewHeader } from "@/features/pull-requests/components/Pu
 import { usePullRequestMentionCandidates } from "@/features/pull-requests/hooks/usePullRequestMentionCandidates";
 import { usePullRequestPendingReviewActions } from "@/features/pull-requests/hooks/usePullRequestPendingReviewActions";
 import { usePullRequestReviewAnchors } from "@/features/pull-requests/hooks/usePullRequestReviewAnchors";
+import { PULL_REQUEST_CONVERSATION_POLLING } from "@/features/pull-requests/polling";
 import {
   buildPreviewTabPath,
   type PreviewTab,
@@ -363,9 +364,7 @@ export const PullRequestOverview = () => {
       conversation: data ?? null,
       loadingConversation: isLoading || isFetching,
     }),
-    pollingInterval: 10000,
-    refetchOnFocus: true,
-    refetchOnReconnect: true,
+    ...PULL_REQUEST_CONVERSATION_POLLING,
   });
 
   const { files } = useGetPullRequestFilesQuery(queryArg, {
@@ -12,6 +12,7 @@ import ReviewCommentsCopyToolbar from "@/features/pull-requests/components/Revie
 import { PullRequestFilesSidebar } from "@/features/pull-requests/components/PullRequestFilesSidebar";
 import { usePullRequestMentionCandidates } from "@/features/pull-requests/hooks/usePullRequestMentionCandidates";
 import { usePullRequestReviewAnchors } from "@/features/pull-requests/hooks/usePullRequestReviewAnchors";
+import { PULL_REQUEST_CONVERSATION_POLLING } from "@/features/pull-requests/polling";
 import {
   clearPullRequestFileJumpTarget,
   setPullRequestFilesViewMode,
@@ -214,9 +215,7 @@ export function PullRequestReviewFilesScreen() {
         conversation: data ?? null,
         reviewThreads: data?.reviewThreads ?? [],
       }),
-      pollingInterval: 10000,
-      refetchOnFocus: true,
-      refetchOnReconnect: true,
+      ...PULL_REQUEST_CONVERSATION_POLLING,
     },
   );
 
@@ -8,10 +8,13 @@ import { desktop } from "@/platform/desktop";
 
 import {
   closeRepo,
+  moveRepoTab,
   navigateBackToDiffFromFileViewer,
   openRepo,
+  refreshActiveRepo,
   restoreWorkspaceSession,
 } from "./actions";
+import { gitApi } from "./api";
 import {
   hydrateWorkspaceSession,
   openFileViewer,
@@ -24,6 +27,10 @@ import {
   sourceControlReducer,
 } from "./sourceControlSlice";
 
+const mocks = vi.hoisted(() => ({ toastError: vi.fn() }));
+
+vi.mock("sonner", () => ({ toast: Object.assign(vi.fn(), { error: mocks.toastError }) }));
+
 vi.mock("@/platform/desktop", () => ({
   desktop: {
     selectFolder: vi.fn(),
@@ -32,6 +39,7 @@ vi.mock("@/platform/desktop", () => ({
     loadAppSettings: vi.fn(),
     saveAppSettings: vi.fn(),
     getAppSettingsPath: vi.fn(),
+    getLaunchBucket: vi.fn(),
     confirm: vi.fn(),
     checkAppExists: vi.fn(),
     openPath: vi.fn(),
@@ -77,6 +85,7 @@ describe("source control workspace actions", () => {
   beforeEach(() => {
     vi.clearAllMocks();
     vi.mocked(desktop.saveWorkspaceSession).mockImplementation(async (session) => session);
+    vi.mocked(desktop.getLaunchBucket).mockResolvedValue("unstaged");
   });
 
   it("restores a saved session, drops invalid repos, and persists the sanitized state", async () => {
@@ -123,6 +132,58 @@ describe("source control workspace actions", () => {
     });
   });
 
+  it("lists a deleted active repo as unavailable instead of failing the restore", async () => {
+    const store = createTestStore();
+
+    vi.mocked(desktop.loadWorkspaceSession).mockResolvedValue({
+      openRepos: ["/repo/a", "/deleted"],
+      activeRepo: "/deleted",
+      recentRepos: [],
+    });
+    vi.mocked(desktop.getGitSnapshot).mockImplementation(async (repoPath: string) => {
+      if (repoPath === "/repo/a") {
+        return {
+          repoRoot: "/repo/a",
+          branch: "main",
+          staged: [],
+          unstaged: [],
+          untracked: [],
+        };
+      }
+
+      throw new Error("ENOENT: no such file or directory");
+    });
+
+    await store.dispatch(restoreWorkspaceSession());
+
+    expect(store.getState().sourceControl.repos).toEqual(["/repo/a"]);
+    expect(store.getState().sourceControl.activeRepo).toBe("/repo/a");
+    expect(store.getState().sourceControl.unavailableRepos).toEqual(["/deleted"]);
+  });
+
+  it("opens the restored repo on its first staged file when launched in staged mode", async () => {
+    const store = createTestStore();
+
+    vi.mocked(desktop.getLaunchBucket).mockResolvedValue("staged");
+    vi.mocked(desktop.loadWorkspaceSession).mockResolvedValue({
+      openRepos: ["/repo/a"],
+      activeRepo: "/repo/a",
+      recentRepos: ["/repo/a"],
+    });
+    vi.mocked(desktop.getGitSnapshot).mockResolvedValue({
+      repoRoot: "/repo/a",
+      branch: "main",
+      staged: [{ path: "src/staged.ts", previousPath: null, status: "modified" }],
+      unstaged: [{ path: "src/unstaged.ts", previousPath: null, status: "modified" }],
+      untracked: [],
+    });
+
+    await store.dispatch(restoreWorkspaceSession());
+
+    expect(store.getState().sourceControl.activeBucket).toBe("staged");
+    expect(store.getState().sourceControl.activePath).toBe("src/staged.ts");
+  });
+
   it("does not clear selection when reopening the current active repo", async () => {
     const store = createTestStore();
 
@@ -153,6 +214,35 @@ describe("source control workspace actions", () => {
     expect(store.getState().sourceControl.recentRepos).toEqual(["/repo/a", "/repo/b"]);
   });
 
+  it("ignores a repo lookup that resolves after a newer repo switch", async () => {
+    const store = createTestStore();
+    let resolveSlowRepo: (repoRoot: string) => void = () => {};
+
+    vi.mocked(desktop.getGitSnapshot).mockImplementation((repoPath: string) => {
+      const snapshot = (repoRoot: string) => ({
+        repoRoot,
+        branch: "main",
+        staged: [],
+        unstaged: [],
+        untracked: [],
+      });
+      if (repoPath === "/repo/slow") {
+        return new Promise((resolve) => {
+          resolveSlowRepo = (repoRoot) => resolve(snapshot(repoRoot));
+        });
+      }
+      return Promise.resolve(snapshot(repoPath));
+    });
+
+    const slowOpen = store.dispatch(openRepo("/repo/slow"));
+    await store.dispatch(openRepo("/repo/fast"));
+    resolveSlowRepo("/repo/slow");
+    await slowOpen;
+
+    expect(store.getState().sourceControl.activeRepo).toBe("/repo/fast");
+    expect(store.getState().sourceControl.repos).toEqual(["/repo/fast"]);
+  });
+
   it("resets repo-scoped tab state when closing the active repo", async () => {
     const store = createTestStore();
 
@@ -181,6 +271,25 @@ describe("source control workspace actions", () => {
     expect(store.getState().sourceControl.reviewHeadRef).toBe("");
   });
 
+  it("persists a moved repo tab and reports a failed save", async () => {
+    const store = createTestStore();
+    const session = { openRepos: ["/repo/a", "/repo/b"], activeRepo: "/repo/a", recentRepos: [] };
+    store.dispatch(hydrateWorkspaceSession(session));
+
+    await store.dispatch(moveRepoTab(0, 1));
+
+    expect(store.getState().sourceControl.repos).toEqual(["/repo/b", "/repo/a"]);
+    expect(desktop.saveWorkspaceSession).toHaveBeenCalledWith(
+      expect.objectContaining({ openRepos: ["/repo/b", "/repo/a"] }),
+    );
+
+    vi.mocked(desktop.saveWorkspaceSession).mockRejectedValueOnce(new Error("disk full"));
+    await store.dispatch(moveRepoTab(0, 1));
+
+    expect(store.getState().sourceControl.repos).toEqual(["/repo/a", "/repo/b"]);
+    expect(mocks.toastError).toHaveBeenCalledWith("Failed to save tab order: disk full");
+  });
+
   it("returns from file viewer to changes diff and restores origin focus", async () => {
     const store = createTestStore();
 
@@ -259,3 +368,86 @@ describe("source control workspace actions", () => {
     );
   });
 });
+
+describe("refreshActiveRepo", () => {
+  const versionsArgs = { repoPath: "/repo/a", bucket: "unstaged" as const, relPath: "a.txt" };
+  const snapshot = (unstagedPaths: string[]) => ({
+    repoRoot: "/repo/a",
+    branch: "main",
+    staged: [],
+    unstaged: unstagedPaths.map((path) => ({
+      path,
+      previousPath: null,
+      status: "modified" as const,
+    })),
+    untracked: [],
+  });
+
+  function createRepoStore() {
+    const store = configureStore({
+      reducer: {
+        settings: settingsReducer,
+        sourceControl: sourceControlReducer,
+        pullRequests: pullRequestsReducer,
+        comments: commentsReducer,
+        [gitApi.reducerPath]: gitApi.reducer,
+      },
+      middleware: (getDefaultMiddleware) => getDefaultMiddleware().concat(gitApi.middleware),
+    });
+    store.dispatch(
+      hydrateWorkspaceSession({
+        openRepos: ["/repo/a"],
+        activeRepo: "/repo/a",
+        recentRepos: ["/repo/a"],
+      }),
+    );
+    return store;
+  }
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    vi.mocked(desktop.getFileVersions).mockResolvedValue({
+      oldFile: { name: "a.txt", contents: "one\n" },
+      newFile: { name: "a.txt", contents: "two\n" },
+    });
+  });
+
+  it("empties cached Changes diffs instead of reloading them when the tree is clean", async () => {
+    const store = createRepoStore();
+    vi.mocked(desktop.getGitSnapshot).mockResolvedValue(snapshot([]));
+    await store.dispatch(gitApi.endpoints.getFileVersions.initiate(versionsArgs));
+
+    await store.dispatch(refreshActiveRepo());
+
+    expect(desktop.getGitSnapshot).toHaveBeenCalledTimes(1);
+    expect(desktop.getFileVersions).toHaveBeenCalledTimes(1);
+    expect(gitApi.endpoints.getFileVersions.select(versionsArgs)(store.getState()).data).toEqual({
+      oldFile: null,
+      newFile: null,
+    });
+  });
+
+  it("reloads Changes diffs when the refreshed snapshot has changes", async () => {
+    const store = createRepoStore();
+    vi.mocked(desktop.getGitSnapshot).mockResolvedValue(snapshot(["a.txt"]));
+    await store.dispatch(gitApi.endpoints.getFileVersions.initiate(versionsArgs));
+
+    await store.dispatch(refreshActiveRepo());
+
+    await vi.waitFor(() => expect(desktop.getFileVersions).toHaveBeenCalledTimes(2));
+    expect(desktop.getGitSnapshot).toHaveBeenCalledTimes(1);
+  });
+
+  it("reports a failed snapshot instead of treating the tree as changed", async () => {
+    const store = createRepoStore();
+    vi.mocked(desktop.getGitSnapshot).mockRejectedValue(new Error("not a git repository"));
+    await store.dispatch(gitApi.endpoints.getFileVersions.initiate(versionsArgs));
+
+    await store.dispatch(refreshActiveRepo());
+
+    expect(mocks.toastError).toHaveBeenCalledWith(
+      "Failed to refresh repository: not a git repository",
+    );
+    expect(desktop.getFileVersions).toHaveBeenCalledTimes(1);
+  });
+});
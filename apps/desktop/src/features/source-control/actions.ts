@@ -1,6 +1,7 @@
 import type { AppThunk, RootState } from "@/app/store";
 import { toast } from "sonner";
 import { desktop } from "@/platform/desktop";
+import { WORKTREE_REF } from "@/platform/desktop/gitRefs";
 import type { DiffHunkOperation } from "@/features/source-control/hunkOperations";
 import {
   addRecentRepo,
@@ -16,19 +17,38 @@ import {
 } from "@/features/pull-requests/pullRequestsSlice";
 import { createFileViewerFocusKey } from "@/features/source-control/fileViewerNavigation";
 import { gitApi } from "./api";
-import type { Bucket, BucketedFile, GitSnapshot, RunningAction, SelectedFile } from "./types";
+import { formatCommitChangelog } from "./commitChangelog";
+import { gitignorePatternForPath } from "./gitignorePattern";
+import { confirmBulkIndexOperation } from "./indexPreview";
+import type {
+  Bucket,
+  BucketedFile,
+  GitSnapshot,
+  HistoryCommit,
+  PushStashOptions,
+  RunningAction,
+  SelectedFile,
+  StashSelector,
+} from "./types";
+import { errorMessageFrom } from "./shared-utils/errorMessage";
 import { findExistingBucket } from "./utils";
 import {
   closeFileViewer,
+  dismissUnavailableRepo,
   hydrateWorkspaceSession as hydrateWorkspaceSessionState,
+  markFileViewed,
+  moveRepo,
   removeRepo,
   resetRepoViewState,
   setActiveBucket,
   setActivePath,
   setActiveRepo,
   setCommitMessage,
+  setDiffChangesOnly,
+  setDiffClusterPreview,
   setDiffFocusTarget,
   setDiffStyle,
+  setDiffWrap,
   setHistoryCommitId,
   setHistoryNavTarget,
   setLastCommitId,
@@ -40,6 +60,7 @@ import {
   setSelectedFiles,
   setSelectionAnchor,
   setRunningAction,
+  setUnavailableRepos,
 } from "./sourceControlSlice";
 
 function nextChangedFileAfterStage(snapshot: GitSnapshot | null | undefined, filePath: string) {
@@ -117,6 +138,10 @@ function buildDiscardPayload(
   return [...byPath.entries()].map(([relPath, bucket]) => ({ relPath, bucket }));
 }
 
+// Bumped on every repo switch. Lookups started before a newer switch drop their result instead
+// of applying it to whichever repo is active by the time they resolve.
+let repoSwitchGeneration = 0;
+
 const resetRepoScopedState = (): AppThunk => (dispatch) => {
   dispatch(resetRepoViewState());
   dispatch(clearCurrentPullRequestReview());
@@ -145,10 +170,32 @@ async function restoreRepoPaths(repoPaths: string[]): Promise<string[]> {
   return normalizeRepoPaths(resolvedPaths);
 }
 
+// `--mode staged` or OPEN_WARDEN_MODE=staged opens the restored repo on its first staged file.
+const focusLaunchBucket =
+  (repoPath: string): AppThunk<Promise<void>> =>
+  async (dispatch, getState) => {
+    const generation = repoSwitchGeneration;
+    const bucket = await desktop.getLaunchBucket();
+    if (bucket === getState().sourceControl.activeBucket) return;
+
+    const snapshot = await desktop.getGitSnapshot(repoPath);
+    if (generation !== repoSwitchGeneration) return;
+    const firstFile = snapshot[bucket][0];
+    if (firstFile) {
+      await dispatch(selectFile(bucket, firstFile.path));
+    } else {
+      dispatch(setActiveBucket(bucket));
+    }
+  };
+
 export const restoreWorkspaceSession = (): AppThunk<Promise<void>> => async (dispatch) => {
   try {
     const storedSession = await desktop.loadWorkspaceSession();
-    const restoredOpenRepos = await restoreRepoPaths(storedSession.openRepos);
+    const storedOpenRepos = normalizeRepoPaths(storedSession.openRepos);
+    const resolvedOpenRepos = await Promise.all(
+      storedOpenRepos.map((repoPath) => resolveRepoPath(repoPath)),
+    );
+    const restoredOpenRepos = normalizeRepoPaths(resolvedOpenRepos);
     const restoredRecentRepos = await restoreRepoPaths(storedSession.recentRepos);
     const restoredActiveRepo = await resolveRepoPath(storedSession.activeRepo);
     const workspaceSession = createWorkspaceSession({
@@ -158,12 +205,20 @@ export const restoreWorkspaceSession = (): AppThunk<Promise<void>> => async (dis
     });
 
     dispatch(hydrateWorkspaceSessionState(workspaceSession));
+    // A deleted or unmounted repo stays listed as unavailable instead of silently vanishing.
+    dispatch(
+      setUnavailableRepos(storedOpenRepos.filter((_, index) => !resolvedOpenRepos[index])),
+    );
 
     if (!workspaceSession.activeRepo) {
       dispatch(resetRepoScopedState());
     }
 
     await desktop.saveWorkspaceSession(workspaceSession);
+
+    if (workspaceSession.activeRepo) {
+      await dispatch(focusLaunchBucket(workspaceSession.activeRepo));
+    }
   } catch (error) {
     dispatch(hydrateWorkspaceSessionState(createWorkspaceSession()));
     const message = error instanceof Error ? error.message : String(error);
@@ -171,10 +226,24 @@ export const restoreWorkspaceSession = (): AppThunk<Promise<void>> => async (dis
   }
 };
 
+export const reconnectRepo =
+  (repoPath: string): AppThunk<Promise<void>> =>
+  async (dispatch) => {
+    if (!(await resolveRepoPath(repoPath))) {
+      toast.error(`Repository is still unavailable: ${repoPath}`);
+      return;
+    }
+
+    dispatch(dismissUnavailableRepo(repoPath));
+    await dispatch(openRepo(repoPath));
+  };
+
 export const openRepo =
   (repoPath: string): AppThunk<Promise<void>> =>
   async (dispatch, getState) => {
+    const generation = ++repoSwitchGeneration;
     const resolvedRepoPath = await resolveRepoPath(repoPath);
+    if (generation !== repoSwitchGeneration) return;
 
     if (!resolvedRepoPath) {
       toast.error(`Could not open repository: ${repoPath}`);
@@ -220,6 +289,20 @@ export const selectRepo =
     await dispatch(openRepo(repo));
   };
 
+export const moveRepoTab =
+  (from: number, to: number): AppThunk<Promise<void>> =>
+  async (dispatch, getState) => {
+    const { repos } = getState().sourceControl;
+    dispatch(moveRepo({ from, to }));
+    if (getState().sourceControl.repos === repos) return;
+
+    try {
+      await persistWorkspaceSession(getState);
+    } catch (error) {
+      toast.error(`Failed to save tab order: ${errorMessageFrom(error, "unknown error")}`);
+    }
+  };
+
 export const closeRepo =
   (repo: string): AppThunk<Promise<{ closedActiveRepo: boolean; nextActiveRepo: string }>> =>
   async (dispatch, getState) => {
@@ -229,6 +312,7 @@ export const closeRepo =
     dispatch(removeCommentsForRepo(repo));
 
     if (closingActiveRepo) {
+      repoSwitchGeneration += 1;
       dispatch(resetRepoScopedState());
     }
 
@@ -240,7 +324,7 @@ export const closeRepo =
     };
   };
 
-export const refreshActiveRepo = (): AppThunk => async (dispatch, getState) => {
+export const refreshActiveRepo = (): AppThunk<Promise<void>> => async (dispatch, getState) => {
   const { activeRepo } = getState().sourceControl;
   if (!activeRepo) return;
 
@@ -249,6 +333,36 @@ export const refreshActiveRepo = (): AppThunk => async (dispatch, getState) => {
   dispatch(
     gitApi.util.invalidateTags([{ type: "HistoryCommits", id: activeRepo }, "HistoryFiles"]),
   );
+
+  // Joins the snapshot refetch started above rather than running a second status.
+  let snapshot: GitSnapshot;
+  try {
+    snapshot = await dispatch(
+      gitApi.endpoints.getGitSnapshot.initiate(activeRepo, {
+        subscribe: false,
+        forceRefetch: true,
+      }),
+    ).unwrap();
+  } catch (error) {
+    toast.error(`Failed to refresh repository: ${errorMessageFrom(error, "unknown error")}`);
+    return;
+  }
+
+  const hasChanges =
+    snapshot.staged.length > 0 || snapshot.unstaged.length > 0 || snapshot.untracked.length > 0;
+  if (hasChanges) {
+    dispatch(gitApi.util.invalidateTags(["ChangeFileVersions"]));
+    return;
+  }
+
+  // A clean tree has nothing to diff, so its Changes diffs are emptied in place instead of being
+  // reloaded; that also spares the diff pane a loading flash.
+  for (const args of gitApi.util.selectCachedArgsForQuery(getState(), "getFileVersions")) {
+    if (args.repoPath !== activeRepo) continue;
+    dispatch(
+      gitApi.util.upsertQueryData("getFileVersions", args, { oldFile: null, newFile: null }),
+    );
+  }
 };
 
 export const selectFile =
@@ -259,6 +373,7 @@ export const selectFile =
     dispatch(setActivePath(relPath));
     dispatch(setSelectedFiles([{ bucket, path: relPath }]));
     dispatch(setSelectionAnchor({ bucket, path: relPath }));
+    dispatch(markFileViewed(relPath));
   };
 
 export const toggleFileSelection =
@@ -350,6 +465,22 @@ export const setDiffStyleValue =
     dispatch(setDiffStyle(value));
   };
 
+export const toggleDiffStyle = (): AppThunk => (dispatch, getState) => {
+  dispatch(setDiffStyle(getState().sourceControl.diffStyle === "split" ? "unified" : "split"));
+};
+
+export const toggleDiffWrap = (): AppThunk => (dispatch, getState) => {
+  dispatch(setDiffWrap(!getState().sourceControl.diffWrap));
+};
+
+export const toggleDiffChangesOnly = (): AppThunk => (dispatch, getState) => {
+  dispatch(setDiffChangesOnly(!getState().sourceControl.diffChangesOnly));
+};
+
+export const toggleDiffClusterPreview = (): AppThunk => (dispatch, getState) => {
+  dispatch(setDiffClusterPreview(!getState().sourceControl.diffClusterPreview));
+};
+
 export const navigateBackToDiffFromFileViewer = (): AppThunk => (dispatch, getState) => {
   const returnToDiff = getState().sourceControl.fileViewerTarget?.returnToDiff;
   if (!returnToDiff) {
@@ -411,10 +542,19 @@ function repoActionLabel(action: RunningAction): string {
   if (action === "stage-files") return "stage files";
   if (action === "unstage-files") return "unstage files";
   if (action === "discard-changes") return "discard selected changes";
+  if (action === "discard-unstaged") return "discard unstaged changes";
   if (action === "commit") return "create commit";
+  if (action === "reword") return "reword last commit";
+  if (action === "amend") return "amend last commit";
+  if (action === "stash-push") return "stash changes";
+  if (action.startsWith("stash:apply:")) return "apply stash";
+  if (action.startsWith("stash:pop:")) return "pop stash";
   if (action.startsWith("file:stage:")) return "stage file";
   if (action.startsWith("file:unstage:")) return "unstage file";
   if (action.startsWith("file:discard:")) return "discard file changes";
+  if (action.startsWith("file:restore:")) return "restore file";
+  if (action.startsWith("folder:stage:")) return "stage folder";
+  if (action.startsWith("folder:unstage:")) return "unstage folder";
   return "run repository action";
 }
 
@@ -553,6 +693,38 @@ export const unstageFilesAction =
     );
   };
 
+export const stageDirectoryAction =
+  (dirPath: string): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo || !dirPath) return;
+
+    await dispatch(
+      runRepoAction(`folder:stage:${dirPath}`, async (innerDispatch) => {
+        const result = innerDispatch(
+          gitApi.endpoints.stageDirectory.initiate({ repoPath: activeRepo, dirRelPath: dirPath }),
+        );
+        await result.unwrap();
+      }),
+    );
+  };
+
+export const unstageDirectoryAction =
+  (dirPath: string): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo || !dirPath) return;
+
+    await dispatch(
+      runRepoAction(`folder:unstage:${dirPath}`, async (innerDispatch) => {
+        const result = innerDispatch(
+          gitApi.endpoints.unstageDirectory.initiate({ repoPath: activeRepo, dirRelPath: dirPath }),
+        );
+        await result.unwrap();
+      }),
+    );
+  };
+
 export const discardFileAction =
   (bucket: Bucket, filePath: string): AppThunk =>
   async (dispatch, getState) => {
@@ -579,6 +751,293 @@ export const discardFileAction =
     );
   };
 
+export const copyCommitPatchAction =
+  (commitId: string): AppThunk<Promise<void>> =>
+  async (_dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo || !commitId) return;
+
+    try {
+      const patch = await desktop.formatCommitPatch(activeRepo, commitId);
+      await navigator.clipboard.writeText(patch);
+      toast.success(`Copied ${commitId.slice(0, 7)} as a patch`);
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to copy commit patch: ${message}`);
+    }
+  };
+
+// Reviews what the commit's tree differs from the current files, untracked files included.
+export const compareCommitWithWorktreeAction =
+  (commitId: string): AppThunk =>
+  (dispatch) => {
+    if (!commitId) return;
+
+    dispatch(setReviewBaseRef(commitId));
+    dispatch(setReviewHeadRef(WORKTREE_REF));
+    dispatch(setReviewActivePath(""));
+  };
+
+export const reviewStashAction =
+  (stash: StashSelector): AppThunk<Promise<void>> =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    try {
+      const target = await desktop.resolveStash(activeRepo, stash);
+      dispatch(setReviewBaseRef(target.baseId));
+      dispatch(setReviewHeadRef(target.oid));
+      dispatch(setReviewActivePath(""));
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to open stash: ${message}`);
+    }
+  };
+
+// A message is matched once, up front, so the action and its toast name the same stash.
+async function stashIndexFor(repoPath: string, stash: StashSelector): Promise<number> {
+  if (typeof stash === "number") return stash;
+  const index = await desktop.findStashByMessage(repoPath, stash);
+  if (index === null) throw new Error(`no stash matches ${JSON.stringify(stash)}`);
+  return index;
+}
+
+export const applyStashAction =
+  (stash: StashSelector): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    await dispatch(
+      runRepoAction(`stash:apply:${stash}`, async (innerDispatch) => {
+        const index = await stashIndexFor(activeRepo, stash);
+        const result = innerDispatch(
+          gitApi.endpoints.applyStash.initiate({ repoPath: activeRepo, stash: index }),
+        );
+        await result.unwrap();
+        toast.success(`Applied stash@{${index}}`);
+      }),
+    );
+  };
+
+export const pushStashAction =
+  (options: PushStashOptions = {}): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    await dispatch(
+      runRepoAction("stash-push", async (innerDispatch) => {
+        const result = innerDispatch(
+          gitApi.endpoints.pushStash.initiate({ repoPath: activeRepo, options }),
+        );
+        await result.unwrap();
+        toast.success("Stashed local changes");
+      }),
+    );
+  };
+
+export const popStashAction =
+  (stash: StashSelector): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    await dispatch(
+      runRepoAction(`stash:pop:${stash}`, async (innerDispatch) => {
+        const index = await stashIndexFor(activeRepo, stash);
+        const result = innerDispatch(
+          gitApi.endpoints.popStash.initiate({ repoPath: activeRepo, stash: index }),
+        );
+        await result.unwrap();
+        toast.success(`Popped stash@{${index}}`);
+      }),
+    );
+  };
+
+// Git reports lines that are not committed yet with an all-zero commit id.
+function isUncommittedBlame(commitId: string) {
+  return /^0+$/.test(commitId);
+}
+
+// Resolves the commit that last touched a line and opens it in history. Resolves to whether
+// a commit was opened, so the caller can switch to the history view.
+export const openLineBlameAction =
+  (relPath: string, lineNumber: number, revision?: string): AppThunk<Promise<boolean>> =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo || !relPath || lineNumber <= 0) return false;
+
+    const request = dispatch(
+      gitApi.endpoints.blameFile.initiate({ repoPath: activeRepo, relPath, revision }),
+    );
+    try {
+      const commitId = (await request.unwrap())[lineNumber - 1];
+      if (!commitId) {
+        toast.error(`No blame information for ${relPath}:${lineNumber}`);
+        return false;
+      }
+      if (isUncommittedBlame(commitId)) {
+        toast.info(`${relPath}:${lineNumber} is not committed yet`);
+        return false;
+      }
+
+      await dispatch(selectHistoryCommit(commitId));
+      return true;
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to blame ${relPath}: ${message}`);
+      return false;
+    } finally {
+      request.unsubscribe();
+    }
+  };
+
+export const copyCommitChangelogAction =
+  (commits: HistoryCommit[]): AppThunk<Promise<void>> =>
+  async () => {
+    if (commits.length === 0) return;
+
+    try {
+      await navigator.clipboard.writeText(formatCommitChangelog(commits));
+      toast.success(
+        commits.length === 1
+          ? `Copied ${commits[0]!.shortId} as a changelog line`
+          : `Copied ${commits.length} commits as a changelog`,
+      );
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to copy changelog: ${message}`);
+    }
+  };
+
+// Untracked files have no diff until they are staged, so only tracked selections are included.
+export const copySelectionDiffAction =
+  (files: SelectedFile[]): AppThunk<Promise<void>> =>
+  async (_dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    const stagedPaths = files.filter((file) => file.bucket === "staged").map((file) => file.path);
+    const unstagedPaths = files
+      .filter((file) => file.bucket === "unstaged")
+      .map((file) => file.path);
+    if (stagedPaths.length === 0 && unstagedPaths.length === 0) return;
+
+    try {
+      const [stagedDiff, unstagedDiff] = await Promise.all([
+        desktop.getWorkingTreeDiffForPaths(activeRepo, true, stagedPaths),
+        desktop.getWorkingTreeDiffForPaths(activeRepo, false, unstagedPaths),
+      ]);
+      await navigator.clipboard.writeText(stagedDiff + unstagedDiff);
+      const count = stagedPaths.length + unstagedPaths.length;
+      toast.success(`Copied the diff of ${count} file${count === 1 ? "" : "s"}`);
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to copy diff: ${message}`);
+    }
+  };
+
+export const openActiveFileInDifftoolAction =
+  (): AppThunk<Promise<void>> => async (_dispatch, getState) => {
+    const { activeRepo, activeBucket, activePath } = getState().sourceControl;
+    if (!activeRepo || !activePath) return;
+
+    try {
+      await desktop.launchDifftool(activeRepo, activePath, activeBucket);
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to open external diff tool: ${message}`);
+    }
+  };
+
+export const revealInFileManagerAction =
+  (filePath?: string): AppThunk<Promise<void>> =>
+  async (_dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    try {
+      await desktop.revealInFileManager(activeRepo, filePath ?? null);
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      toast.error(`Failed to open file manager: ${message}`);
+    }
+  };
+
+export const restoreFileFromRefAction =
+  (filePath: string, ref: string): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo || !ref) return;
+
+    await dispatch(
+      runRepoAction(`file:restore:${filePath}`, async (innerDispatch) => {
+        const result = innerDispatch(
+          gitApi.endpoints.checkoutFileFromRef.initiate({
+            repoPath: activeRepo,
+            relPath: filePath,
+            ref,
+          }),
+        );
+        await result.unwrap();
+        toast.success(`Restored ${filePath} from ${ref}`);
+      }),
+    );
+  };
+
+// An unstaged deletion comes back from the index, so staged edits to the file survive. A staged
+// deletion comes back from HEAD into both the index and the worktree.
+export const restoreDeletedFileAction =
+  (bucket: Bucket, filePath: string): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo || bucket === "untracked") return;
+
+    await dispatch(
+      runRepoAction(`file:restore:${filePath}`, async (innerDispatch) => {
+        const result =
+          bucket === "staged"
+            ? innerDispatch(
+                gitApi.endpoints.checkoutFileFromRef.initiate({
+                  repoPath: activeRepo,
+                  relPath: filePath,
+                  ref: "HEAD",
+                  stage: true,
+                }),
+              )
+            : innerDispatch(
+                gitApi.endpoints.discardFile.initiate({
+                  repoPath: activeRepo,
+                  relPath: filePath,
+                  bucket,
+                }),
+              );
+        await result.unwrap();
+        toast.success(`Restored ${filePath}`);
+      }),
+    );
+  };
+
+export const addToGitignoreAction =
+  (filePath: string): AppThunk =>
+  async (dispatch, getState) => {
+    const { activeRepo } = getState().sourceControl;
+    if (!activeRepo) return;
+
+    const pattern = gitignorePatternForPath(filePath);
+    await dispatch(
+      runRepoAction(`file:ignore:${filePath}`, async (innerDispatch) => {
+        const result = innerDispatch(
+          gitApi.endpoints.addGitignorePattern.initiate({ repoPath: activeRepo, pattern }),
+        );
+        const added = await result.unwrap();
+        toast.success(added ? `Added ${pattern} to .gitignore` : `${pattern} is already ignored`);
+      }),
+    );
+  };
+
 export const stageOrUnstageSelectionAction = (): AppThunk => async (dispatch, getState) => {
   const { activeRepo, activeBucket, activePath, selectedFiles, runningAction } =
     getState().sourceControl;
@@ -603,6 +1062,7 @@ export const stageOrUnstageSelectionAction = (): AppThunk => async (dispatch, ge
 export const stageAllAction = (): AppThunk => async (dispatch, getState) => {
   const { activeRepo } = getState().sourceControl;
   if (!activeRepo) return;
+  if (!(await confirmBulkIndexOperation(activeRepo, "stage"))) return;
 
   await dispatch(
     runRepoAction("stage-all", async (innerDispatch) => {
@@ -615,6 +1075,7 @@ export const stageAllAction = (): AppThunk => async (dispatch, getState) => {
 export const unstageAllAction = (): AppThunk => async (dispatch, getState) => {
   const { activeRepo } = getState().sourceControl;
   if (!activeRepo) return;
+  if (!(await confirmBulkIndexOperation(activeRepo, "unstage"))) return;
 
   await dispatch(
     runRepoAction("unstage-all", async (innerDispatch) => {
@@ -648,6 +1109,20 @@ export const discardChangesGroupAction =
     );
   };
 
+export const discardUnstagedAction = (): AppThunk => async (dispatch, getState) => {
+  const { activeRepo } = getState().sourceControl;
+  if (!activeRepo) return;
+
+  await dispatch(
+    runRepoAction("discard-unstaged", async (innerDispatch) => {
+      const result = innerDispatch(
+        gitApi.endpoints.discardUnstaged.initiate({ repoPath: activeRepo }),
+      );
+      await result.unwrap();
+    }),
+  );
+};
+
 export const commitAction = (): AppThunk => async (dispatch, getState) => {
   const { activeRepo, commitMessage } = getState().sourceControl;
   if (!activeRepo) return;
@@ -665,3 +1140,48 @@ export const commitAction = (): AppThunk => async (dispatch, getState) => {
     }),
   );
 };
+
+export const rewordLastCommitAction = (): AppThunk => async (dispatch, getState) => {
+  const { activeRepo, commitMessage } = getState().sourceControl;
+  if (!activeRepo) return;
+  const trimmed = commitMessage.trim();
+  if (!trimmed) return;
+
+  await dispatch(
+    runRepoAction("reword", async (innerDispatch) => {
+      const result = innerDispatch(
+        gitApi.endpoints.rewordHead.initiate({ repoPath: activeRepo, message: trimmed }),
+      );
+      const commitId = await result.unwrap();
+      innerDispatch(setLastCommitId(commitId));
+      innerDispatch(setCommitMessage(""));
+      toast.success(`Reworded last commit (${commitId.slice(0, 7)})`);
+    }),
+  );
+};
+
+// Folds the staged changes into HEAD; an empty message box keeps the commit's message.
+export const amendLastCommitAction =
+  (): AppThunk<Promise<boolean>> => async (dispatch, getState) => {
+    const { activeRepo, commitMessage } = getState().sourceControl;
+    if (!activeRepo) return false;
+    const trimmed = commitMessage.trim();
+    let amended = false;
+
+    await dispatch(
+      runRepoAction("amend", async (innerDispatch) => {
+        const result = innerDispatch(
+          gitApi.endpoints.amendHead.initiate({
+            repoPath: activeRepo,
+            message: trimmed || undefined,
+          }),
+        );
+        const commitId = await result.unwrap();
+        innerDispatch(setLastCommitId(commitId));
+        innerDispatch(setCommitMessage(""));
+        toast.success(`Amended last commit (${commitId.slice(0, 7)})`);
+        amended = true;
+      }),
+    );
+    return amended;
+  };
@@ -0,0 +1,94 @@
+import { configureStore } from "@reduxjs/toolkit";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+
+import { getBranchFiles, getCommitFiles } from "./services/git";
+import { COMMIT_DIFF_CACHE_SECONDS, gitApi } from "./api";
+
+vi.mock("./services/git", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("./services/git")>()),
+  getBranchFiles: vi.fn(),
+  getCommitFiles: vi.fn(),
+}));
+
+function createTestStore() {
+  return configureStore({
+    reducer: { [gitApi.reducerPath]: gitApi.reducer },
+    middleware: (getDefaultMiddleware) => getDefaultMiddleware().concat(gitApi.middleware),
+  });
+}
+
+async function loadCommitFiles(store: ReturnType<typeof createTestStore>, commitId: string) {
+  const request = store.dispatch(
+    gitApi.endpoints.getCommitFiles.initiate({ repoPath: "/repo", commitId, parentIndex: 0 }),
+  );
+  await request;
+  request.unsubscribe();
+}
+
+describe("gitApi commit diff cache", () => {
+  beforeEach(() => {
+    vi.mocked(getCommitFiles).mockReset();
+    vi.mocked(getCommitFiles).mockResolvedValue([
+      { path: "a.txt", previousPath: null, status: "modified" },
+    ]);
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it("reuses a commit's files when stepping back to it", async () => {
+    vi.useFakeTimers();
+    const store = createTestStore();
+
+    await loadCommitFiles(store, "aaa");
+    await loadCommitFiles(store, "bbb");
+    // Past RTK Query's default 60s, which would already have dropped the unused entry.
+    await vi.advanceTimersByTimeAsync(61_000);
+    await loadCommitFiles(store, "aaa");
+
+    expect(vi.mocked(getCommitFiles).mock.calls.map(([, commitId]) => commitId)).toEqual([
+      "aaa",
+      "bbb",
+    ]);
+
+    await vi.advanceTimersByTimeAsync(COMMIT_DIFF_CACHE_SECONDS * 1000 + 1000);
+    await loadCommitFiles(store, "aaa");
+
+    expect(getCommitFiles).toHaveBeenCalledTimes(3);
+  });
+
+  it("refetches a cached commit after a history refresh", async () => {
+    const store = createTestStore();
+
+    await loadCommitFiles(store, "aaa");
+    store.dispatch(gitApi.util.invalidateTags(["HistoryFiles"]));
+    await loadCommitFiles(store, "aaa");
+
+    expect(getCommitFiles).toHaveBeenCalledTimes(2);
+  });
+
+  it("diffs a commit against the range base when one is given", async () => {
+    vi.mocked(getBranchFiles).mockResolvedValue([
+      { path: "a.txt", previousPath: null, status: "modified" },
+      { path: "b.txt", previousPath: null, status: "added" },
+    ]);
+    const store = createTestStore();
+
+    await loadCommitFiles(store, "ccc");
+    const request = store.dispatch(
+      gitApi.endpoints.getCommitFiles.initiate({
+        repoPath: "/repo",
+        commitId: "ccc",
+        parentIndex: 0,
+        baseRef: "base",
+      }),
+    );
+    const { data } = await request;
+    request.unsubscribe();
+
+    expect(getBranchFiles).toHaveBeenCalledWith("/repo", "base", "ccc");
+    expect(data?.map((file) => file.path)).toEqual(["a.txt", "b.txt"]);
+    expect(getCommitFiles).toHaveBeenCalledTimes(1);
+  });
+});
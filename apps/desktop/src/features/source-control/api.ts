@@ -1,31 +1,61 @@
 import { createApi, fakeBaseQuery } from "@reduxjs/toolkit/query/react";
 
+import { DEFAULT_HISTORY_PAGE_SIZE } from "@/platform/desktop/appSettings";
+import { isWorktreeRef } from "@/platform/desktop/gitRefs";
+
 import type {
   Bucket,
-  DiffFile,
+  CommitStats,
   FileItem,
   FileVersions,
   GitSnapshot,
   HistoryCommit,
+  PushStashOptions,
+  ReflogEntry,
+  RefResolution,
+  StashEntry,
+  StashSelector,
+  RepoFile,
   RepoFileItem,
+  WorkingCopySummary,
 } from "./types";
 import {
+  addGitignorePattern,
   getBranches,
   getBranchFiles,
   getBranchFileVersions,
   commitStaged,
+  rewordHead,
+  amendHead,
+  checkoutFileFromRef,
   discardFile,
   discardFiles,
+  discardUnstaged,
   getCommitFiles,
   getCommitFileVersions,
   getCommitHistory,
+  getCommitStats,
+  getParentSummary,
+  getChangedFileHashes,
+  getDefaultBranch,
+  getReflog,
   getFileVersions,
   getRepoFiles,
   getRepoFile,
   getGitSnapshot,
+  getWorkingCopySummary,
+  getRecentlyModifiedFiles,
+  blameFile,
+  resolveRefInfo,
+  applyStash,
+  listStashes,
+  pushStash,
+  popStash,
   stageAll,
+  stageDirectory,
   stageFile,
   unstageAll,
+  unstageDirectory,
   unstageFile,
   updateIndexFileContents,
   updateWorktreeFileContents,
@@ -33,15 +63,35 @@ import {
 
 type ErrorResult = { message: string };
 
-type CommitHistoryArgs = { repoPath: string; limit?: number };
+type CommitHistoryArgs = { repoPath: string; pageSize?: number };
 type BranchFilesArgs = { repoPath: string; baseRef: string; headRef: string };
-type CommitFilesArgs = { repoPath: string; commitId: string };
-type RepoFileArgs = { repoPath: string; relPath: string; revision?: string | null };
+// A baseRef replaces the parent, so a history commit can show everything since a range start.
+type CommitFilesArgs = {
+  repoPath: string;
+  commitId: string;
+  parentIndex?: number;
+  baseRef?: string;
+};
+type CommitStatsArgs = { repoPath: string; commitId: string };
+type ParentSummaryArgs = { repoPath: string; commitId: string };
+type RefInfoArgs = { repoPath: string; ref: string };
+type ReflogArgs = { repoPath: string; refName?: string; limit?: number };
+type RepoFileArgs = {
+  repoPath: string;
+  relPath: string;
+  revision?: string | null;
+  maxBytes?: number;
+};
+type AllRepoFilesArgs = { repoPath: string; includeIgnored: boolean };
+type RecentFilesArgs = { repoPath: string; limit?: number };
+type BlameFileArgs = { repoPath: string; relPath: string; revision?: string };
 type CommitFileVersionsArgs = {
   repoPath: string;
   commitId: string;
   relPath: string;
   previousPath?: string;
+  parentIndex?: number;
+  baseRef?: string;
 };
 type FileVersionsArgs = { repoPath: string; bucket: Bucket; relPath: string };
 type BranchFileVersionsArgs = {
@@ -54,11 +104,14 @@ type BranchFileVersionsArgs = {
 
 type StageFileArgs = { repoPath: string; relPath: string };
 type UnstageFileArgs = { repoPath: string; relPath: string };
+type DirectoryArgs = { repoPath: string; dirRelPath: string };
 type UpdateIndexFileContentsArgs = { repoPath: string; relPath: string; contents: string };
 type UpdateWorktreeFileContentsArgs = { repoPath: string; relPath: string; contents: string };
 type DiscardFileArgs = { repoPath: string; relPath: string; bucket: Bucket };
 type DiscardFilesArgs = { repoPath: string; files: Array<{ relPath: string; bucket: Bucket }> };
+type CheckoutFileFromRefArgs = { repoPath: string; relPath: string; ref: string; stage?: boolean };
 type CommitStagedArgs = { repoPath: string; message: string };
+type AmendHeadArgs = { repoPath: string; message?: string };
 
 function toErrorResult(error: unknown): ErrorResult {
   return { message: error instanceof Error ? error.message : String(error) };
@@ -89,6 +142,14 @@ function normalizeGitSnapshot(snapshot: GitSnapshot): GitSnapshot {
   };
 }
 
+// Commits are immutable, so their file lists and diffs stay cached well past RTK's 60s default.
+// Stepping back through history reuses them; refreshes invalidate HistoryFiles/FileVersions.
+export const COMMIT_DIFF_CACHE_SECONDS = 10 * 60;
+
+export function loadedHistoryCommits(data: { pages: HistoryCommit[][] } | undefined) {
+  return data?.pages.flat() ?? [];
+}
+
 export const gitApi = createApi({
   reducerPath: "gitApi",
   baseQuery: fakeBaseQuery<ErrorResult>(),
@@ -100,6 +161,8 @@ export const gitApi = createApi({
     "Branches",
     "BranchFiles",
     "FileVersions",
+    // Working tree and index diffs in Changes, split out so a clean refresh can skip them.
+    "ChangeFileVersions",
   ],
   endpoints: (builder) => ({
     getGitSnapshot: builder.query<GitSnapshot, string>({
@@ -112,6 +175,31 @@ export const gitApi = createApi({
       },
       providesTags: (_result, _error, repoPath) => [{ type: "Snapshot", id: repoPath }],
     }),
+    getWorkingCopySummary: builder.query<WorkingCopySummary, string>({
+      async queryFn(repoPath) {
+        try {
+          return { data: await getWorkingCopySummary(repoPath) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, repoPath) => [{ type: "Snapshot", id: repoPath }],
+    }),
+    getChangedFileHashes: builder.query<Record<string, string>, string>({
+      async queryFn(repoPath) {
+        try {
+          const hashes = await getChangedFileHashes(repoPath);
+          return {
+            data: Object.fromEntries(
+              Object.entries(hashes).map(([path, hash]) => [normalizeFilePath(path), hash]),
+            ),
+          };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, repoPath) => [{ type: "Snapshot", id: repoPath }],
+    }),
     getRepoFiles: builder.query<RepoFileItem[], string>({
       async queryFn(repoPath) {
         try {
@@ -122,16 +210,104 @@ export const gitApi = createApi({
       },
       providesTags: (_result, _error, repoPath) => [{ type: "RepoFiles", id: repoPath }],
     }),
-    getCommitHistory: builder.query<HistoryCommit[], CommitHistoryArgs>({
+    getAllRepoFiles: builder.query<RepoFileItem[], AllRepoFilesArgs>({
+      async queryFn({ repoPath, includeIgnored }) {
+        try {
+          return {
+            data: (await getRepoFiles(repoPath, { includeIgnored })).map(normalizeRepoFileItem),
+          };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, { repoPath }) => [{ type: "RepoFiles", id: repoPath }],
+    }),
+    getRecentlyModifiedFiles: builder.query<string[], RecentFilesArgs>({
       async queryFn({ repoPath, limit }) {
         try {
-          return { data: await getCommitHistory(repoPath, limit) };
+          return { data: await getRecentlyModifiedFiles(repoPath, limit) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, { repoPath }) => [{ type: "Snapshot", id: repoPath }],
+    }),
+    // Cached per file and revision, so blaming more lines of the same file reuses one run.
+    blameFile: builder.query<string[], BlameFileArgs>({
+      async queryFn({ repoPath, relPath, revision }) {
+        try {
+          return { data: await blameFile(repoPath, relPath, revision) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      keepUnusedDataFor: COMMIT_DIFF_CACHE_SECONDS,
+      providesTags: (_result, _error, { repoPath }) => [{ type: "Snapshot", id: repoPath }],
+    }),
+    getCommitHistory: builder.infiniteQuery<HistoryCommit[], CommitHistoryArgs, number>({
+      infiniteQueryOptions: {
+        initialPageParam: 0,
+        // A short page means git ran out of commits.
+        getNextPageParam: (
+          lastPage,
+          _allPages,
+          lastPageParam,
+          _allPageParams,
+          { pageSize = DEFAULT_HISTORY_PAGE_SIZE },
+        ) => (lastPage.length < pageSize ? undefined : lastPageParam + lastPage.length),
+      },
+      async queryFn({ queryArg: { repoPath, pageSize = DEFAULT_HISTORY_PAGE_SIZE }, pageParam }) {
+        try {
+          return { data: await getCommitHistory(repoPath, pageSize, pageParam) };
         } catch (error) {
           return { error: toErrorResult(error) };
         }
       },
       providesTags: (_result, _error, { repoPath }) => [{ type: "HistoryCommits", id: repoPath }],
     }),
+    getCommitStats: builder.query<CommitStats, CommitStatsArgs>({
+      async queryFn({ repoPath, commitId }) {
+        try {
+          return { data: await getCommitStats(repoPath, commitId) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, { repoPath }) => [{ type: "HistoryCommits", id: repoPath }],
+    }),
+    getParentSummary: builder.query<string | null, ParentSummaryArgs>({
+      async queryFn({ repoPath, commitId }) {
+        try {
+          return { data: await getParentSummary(repoPath, commitId) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, { repoPath }) => [{ type: "HistoryCommits", id: repoPath }],
+    }),
+    getReflog: builder.query<ReflogEntry[], ReflogArgs>({
+      async queryFn({ repoPath, refName, limit }) {
+        try {
+          return { data: await getReflog(repoPath, refName, limit) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, { repoPath }) => [{ type: "HistoryCommits", id: repoPath }],
+    }),
+    resolveRefInfo: builder.query<RefResolution, RefInfoArgs>({
+      async queryFn({ repoPath, ref }) {
+        try {
+          return { data: await resolveRefInfo(repoPath, ref) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, { repoPath }) => [
+        { type: "Branches", id: repoPath },
+        { type: "HistoryCommits", id: repoPath },
+      ],
+    }),
     getBranches: builder.query<string[], string>({
       async queryFn(repoPath) {
         try {
@@ -142,6 +318,16 @@ export const gitApi = createApi({
       },
       providesTags: (_result, _error, repoPath) => [{ type: "Branches", id: repoPath }],
     }),
+    getDefaultBranch: builder.query<string | null, string>({
+      async queryFn(repoPath) {
+        try {
+          return { data: await getDefaultBranch(repoPath) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, repoPath) => [{ type: "Branches", id: repoPath }],
+    }),
     getBranchFiles: builder.query<FileItem[], BranchFilesArgs>({
       async queryFn({ repoPath, baseRef, headRef }) {
         try {
@@ -154,24 +340,30 @@ export const gitApi = createApi({
       },
       providesTags: (_result, _error, { repoPath, baseRef, headRef }) => [
         { type: "BranchFiles", id: `${repoPath}:${baseRef}:${headRef}` },
+        // Working tree comparisons go stale with every edit, so follow the snapshot.
+        ...(isWorktreeRef(headRef) ? [{ type: "Snapshot" as const, id: repoPath }] : []),
       ],
     }),
     getCommitFiles: builder.query<FileItem[], CommitFilesArgs>({
-      async queryFn({ repoPath, commitId }) {
+      async queryFn({ repoPath, commitId, parentIndex, baseRef }) {
         try {
-          return { data: (await getCommitFiles(repoPath, commitId)).map(normalizeFileItem) };
+          const files = baseRef
+            ? await getBranchFiles(repoPath, baseRef, commitId)
+            : await getCommitFiles(repoPath, commitId, parentIndex);
+          return { data: files.map(normalizeFileItem) };
         } catch (error) {
           return { error: toErrorResult(error) };
         }
       },
-      providesTags: (_result, _error, { repoPath, commitId }) => [
-        { type: "HistoryFiles", id: `${repoPath}:${commitId}` },
+      keepUnusedDataFor: COMMIT_DIFF_CACHE_SECONDS,
+      providesTags: (_result, _error, { repoPath, commitId, parentIndex = 0, baseRef }) => [
+        { type: "HistoryFiles", id: `${repoPath}:${commitId}:${baseRef ?? parentIndex}` },
       ],
     }),
-    getRepoFile: builder.query<DiffFile | null, RepoFileArgs>({
-      async queryFn({ repoPath, relPath, revision }) {
+    getRepoFile: builder.query<RepoFile | null, RepoFileArgs>({
+      async queryFn({ repoPath, relPath, revision, maxBytes }) {
         try {
-          return { data: await getRepoFile(repoPath, relPath, revision) };
+          return { data: await getRepoFile(repoPath, relPath, revision, maxBytes) };
         } catch (error) {
           return { error: toErrorResult(error) };
         }
@@ -181,15 +373,23 @@ export const gitApi = createApi({
       ],
     }),
     getCommitFileVersions: builder.query<FileVersions, CommitFileVersionsArgs>({
-      async queryFn({ repoPath, commitId, relPath, previousPath }) {
+      async queryFn({ repoPath, commitId, relPath, previousPath, parentIndex, baseRef }) {
         try {
-          return { data: await getCommitFileVersions(repoPath, commitId, relPath, previousPath) };
+          return {
+            data: baseRef
+              ? await getBranchFileVersions(repoPath, baseRef, commitId, relPath, previousPath)
+              : await getCommitFileVersions(repoPath, commitId, relPath, previousPath, parentIndex),
+          };
         } catch (error) {
           return { error: toErrorResult(error) };
         }
       },
-      providesTags: (_result, _error, { repoPath, commitId, relPath }) => [
-        { type: "FileVersions", id: `history:${repoPath}:${commitId}:${relPath}` },
+      keepUnusedDataFor: COMMIT_DIFF_CACHE_SECONDS,
+      providesTags: (_result, _error, { repoPath, commitId, relPath, parentIndex = 0, baseRef }) => [
+        {
+          type: "FileVersions",
+          id: `history:${repoPath}:${commitId}:${baseRef ?? parentIndex}:${relPath}`,
+        },
       ],
     }),
     getFileVersions: builder.query<FileVersions, FileVersionsArgs>({
@@ -201,7 +401,7 @@ export const gitApi = createApi({
         }
       },
       providesTags: (_result, _error, { repoPath, relPath }) => [
-        { type: "FileVersions", id: `${repoPath}:${relPath}` },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
       ],
     }),
     getBranchFileVersions: builder.query<FileVersions, BranchFileVersionsArgs>({
@@ -229,7 +429,7 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath, relPath }) => [
         { type: "Snapshot", id: repoPath },
-        { type: "FileVersions", id: `${repoPath}:${relPath}` },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
       ],
     }),
     unstageFile: builder.mutation<void, UnstageFileArgs>({
@@ -243,7 +443,7 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath, relPath }) => [
         { type: "Snapshot", id: repoPath },
-        { type: "FileVersions", id: `${repoPath}:${relPath}` },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
       ],
     }),
     updateIndexFileContents: builder.mutation<void, UpdateIndexFileContentsArgs>({
@@ -257,7 +457,7 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath, relPath }) => [
         { type: "Snapshot", id: repoPath },
-        { type: "FileVersions", id: `${repoPath}:${relPath}` },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
       ],
     }),
     updateWorktreeFileContents: builder.mutation<void, UpdateWorktreeFileContentsArgs>({
@@ -271,7 +471,7 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath, relPath }) => [
         { type: "Snapshot", id: repoPath },
-        { type: "FileVersions", id: `${repoPath}:${relPath}` },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
       ],
     }),
     discardFile: builder.mutation<void, DiscardFileArgs>({
@@ -285,7 +485,21 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath, relPath }) => [
         { type: "Snapshot", id: repoPath },
-        { type: "FileVersions", id: `${repoPath}:${relPath}` },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
+      ],
+    }),
+    checkoutFileFromRef: builder.mutation<void, CheckoutFileFromRefArgs>({
+      async queryFn({ repoPath, relPath, ref, stage }) {
+        try {
+          await checkoutFileFromRef(repoPath, relPath, ref, stage);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath, relPath }) => [
+        { type: "Snapshot", id: repoPath },
+        { type: "ChangeFileVersions", id: `${repoPath}:${relPath}` },
       ],
     }),
     discardFiles: builder.mutation<void, DiscardFilesArgs>({
@@ -299,6 +513,91 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath }) => [{ type: "Snapshot", id: repoPath }],
     }),
+    discardUnstaged: builder.mutation<void, { repoPath: string }>({
+      async queryFn({ repoPath }) {
+        try {
+          await discardUnstaged(repoPath);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        "FileVersions",
+        "ChangeFileVersions",
+      ],
+    }),
+    applyStash: builder.mutation<void, { repoPath: string; stash: StashSelector }>({
+      async queryFn({ repoPath, stash }) {
+        try {
+          await applyStash(repoPath, stash);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        "FileVersions",
+        "ChangeFileVersions",
+      ],
+    }),
+    listStashes: builder.query<StashEntry[], string>({
+      async queryFn(repoPath) {
+        try {
+          return { data: await listStashes(repoPath) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      providesTags: (_result, _error, repoPath) => [{ type: "HistoryCommits", id: repoPath }],
+    }),
+    pushStash: builder.mutation<void, { repoPath: string; options?: PushStashOptions }>({
+      async queryFn({ repoPath, options }) {
+        try {
+          await pushStash(repoPath, options);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        { type: "HistoryCommits", id: repoPath },
+        "FileVersions",
+        "ChangeFileVersions",
+      ],
+    }),
+    popStash: builder.mutation<void, { repoPath: string; stash: StashSelector }>({
+      async queryFn({ repoPath, stash }) {
+        try {
+          await popStash(repoPath, stash);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        { type: "HistoryCommits", id: repoPath },
+        "FileVersions",
+        "ChangeFileVersions",
+      ],
+    }),
+    addGitignorePattern: builder.mutation<boolean, { repoPath: string; pattern: string }>({
+      async queryFn({ repoPath, pattern }) {
+        try {
+          return { data: await addGitignorePattern(repoPath, pattern) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        { type: "RepoFiles", id: repoPath },
+      ],
+    }),
     stageAll: builder.mutation<void, { repoPath: string }>({
       async queryFn({ repoPath }) {
         try {
@@ -321,6 +620,28 @@ export const gitApi = createApi({
       },
       invalidatesTags: (_result, _error, { repoPath }) => [{ type: "Snapshot", id: repoPath }],
     }),
+    stageDirectory: builder.mutation<void, DirectoryArgs>({
+      async queryFn({ repoPath, dirRelPath }) {
+        try {
+          await stageDirectory(repoPath, dirRelPath);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [{ type: "Snapshot", id: repoPath }],
+    }),
+    unstageDirectory: builder.mutation<void, DirectoryArgs>({
+      async queryFn({ repoPath, dirRelPath }) {
+        try {
+          await unstageDirectory(repoPath, dirRelPath);
+          return { data: undefined };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [{ type: "Snapshot", id: repoPath }],
+    }),
     commitStaged: builder.mutation<string, CommitStagedArgs>({
       async queryFn({ repoPath, message }) {
         try {
@@ -334,14 +655,52 @@ export const gitApi = createApi({
         { type: "HistoryCommits", id: repoPath },
       ],
     }),
+    rewordHead: builder.mutation<string, CommitStagedArgs>({
+      async queryFn({ repoPath, message }) {
+        try {
+          return { data: await rewordHead(repoPath, message) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        { type: "HistoryCommits", id: repoPath },
+        "HistoryFiles",
+      ],
+    }),
+    amendHead: builder.mutation<string, AmendHeadArgs>({
+      async queryFn({ repoPath, message }) {
+        try {
+          return { data: await amendHead(repoPath, message) };
+        } catch (error) {
+          return { error: toErrorResult(error) };
+        }
+      },
+      invalidatesTags: (_result, _error, { repoPath }) => [
+        { type: "Snapshot", id: repoPath },
+        { type: "HistoryCommits", id: repoPath },
+        "HistoryFiles",
+      ],
+    }),
   }),
 });
 
 export const {
   useGetGitSnapshotQuery,
+  useGetWorkingCopySummaryQuery,
+  useGetChangedFileHashesQuery,
   useGetRepoFilesQuery,
-  useGetCommitHistoryQuery,
+  useGetAllRepoFilesQuery,
+  useGetRecentlyModifiedFilesQuery,
+  useGetCommitHistoryInfiniteQuery,
+  useGetCommitStatsQuery,
+  useGetParentSummaryQuery,
+  useResolveRefInfoQuery,
+  useGetReflogQuery,
+  useListStashesQuery,
   useGetBranchesQuery,
+  useGetDefaultBranchQuery,
   useGetBranchFilesQuery,
   useGetCommitFilesQuery,
   useGetRepoFileQuery,
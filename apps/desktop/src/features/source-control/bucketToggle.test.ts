@@ -0,0 +1,59 @@
+import { describe, expect, it } from "vitest";
+
+import { toggledBucketFile } from "./bucketToggle";
+import type { FileItem, FileStatus } from "./types";
+
+function file(path: string, status: FileStatus = "modified"): FileItem {
+  return { path, previousPath: null, status };
+}
+
+const snapshot = {
+  staged: [file("both.ts"), file("staged-only.ts")],
+  unstaged: [file("conflict.ts", "unmerged"), file("both.ts"), file("unstaged-only.ts")],
+  untracked: [file("new.ts", "untracked")],
+};
+
+describe("toggledBucketFile", () => {
+  it("keeps the path when the file has changes on the other side", () => {
+    expect(toggledBucketFile(snapshot, "unstaged", "both.ts")).toEqual({
+      bucket: "staged",
+      path: "both.ts",
+    });
+    expect(toggledBucketFile(snapshot, "staged", "both.ts")).toEqual({
+      bucket: "unstaged",
+      path: "both.ts",
+    });
+  });
+
+  it("restarts at the first file of the other side otherwise", () => {
+    expect(toggledBucketFile(snapshot, "unstaged", "unstaged-only.ts")).toEqual({
+      bucket: "staged",
+      path: "both.ts",
+    });
+    expect(toggledBucketFile(snapshot, "untracked", "new.ts")).toEqual({
+      bucket: "staged",
+      path: "both.ts",
+    });
+    expect(toggledBucketFile(snapshot, "staged", "staged-only.ts")).toEqual({
+      bucket: "unstaged",
+      path: "both.ts",
+    });
+  });
+
+  it("reaches untracked files and skips conflicts", () => {
+    expect(
+      toggledBucketFile(
+        { staged: [file("a.ts")], unstaged: [file("c.ts", "unmerged")], untracked: [file("n.ts")] },
+        "staged",
+        "a.ts",
+      ),
+    ).toEqual({ bucket: "untracked", path: "n.ts" });
+  });
+
+  it("returns null when the other side is empty", () => {
+    const unstagedOnly = { staged: [], unstaged: [file("a.ts")], untracked: [] };
+
+    expect(toggledBucketFile(unstagedOnly, "unstaged", "a.ts")).toBeNull();
+    expect(toggledBucketFile(undefined, "unstaged", "a.ts")).toBeNull();
+  });
+});
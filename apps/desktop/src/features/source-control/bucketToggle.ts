@@ -0,0 +1,33 @@
+import type { Bucket, GitSnapshot, SelectedFile } from "./types";
+
+type SnapshotBuckets = Pick<GitSnapshot, "staged" | "unstaged" | "untracked">;
+
+// Mirrors the changes tree sections: conflicts live in their own section, so they are never a
+// toggle target.
+function sectionFiles(snapshot: SnapshotBuckets, staged: boolean): SelectedFile[] {
+  if (staged) {
+    return snapshot.staged
+      .filter((file) => file.status !== "unmerged")
+      .map((file) => ({ bucket: "staged" as const, path: file.path }));
+  }
+
+  return [
+    ...snapshot.unstaged
+      .filter((file) => file.status !== "unmerged")
+      .map((file) => ({ bucket: "unstaged" as const, path: file.path })),
+    ...snapshot.untracked.map((file) => ({ bucket: "untracked" as const, path: file.path })),
+  ];
+}
+
+// Flips between the staged and unstaged side. A file with changes on both sides keeps its path;
+// otherwise selection restarts at the first file of the other side.
+export function toggledBucketFile(
+  snapshot: SnapshotBuckets | null | undefined,
+  activeBucket: Bucket,
+  activePath: string,
+): SelectedFile | null {
+  if (!snapshot) return null;
+
+  const targets = sectionFiles(snapshot, activeBucket !== "staged");
+  return targets.find((file) => file.path === activePath) ?? targets[0] ?? null;
+}
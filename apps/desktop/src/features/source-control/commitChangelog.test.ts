@@ -0,0 +1,22 @@
+import { describe, expect, it } from "vitest";
+
+import { commitChangelogLine, formatCommitChangelog } from "./commitChangelog";
+
+describe("commit changelog", () => {
+  it("formats a commit as its short id and subject", () => {
+    expect(commitChangelogLine({ shortId: "abc1234", summary: "feat: add thing" })).toBe(
+      "abc1234 feat: add thing",
+    );
+    expect(commitChangelogLine({ shortId: "abc1234", summary: "  " })).toBe("abc1234");
+  });
+
+  it("lists every commit on its own line", () => {
+    expect(
+      formatCommitChangelog([
+        { shortId: "abc1234", summary: "feat: add thing" },
+        { shortId: "def5678", summary: "fix: handle empty input" },
+      ]),
+    ).toBe("abc1234 feat: add thing\ndef5678 fix: handle empty input\n");
+    expect(formatCommitChangelog([])).toBe("");
+  });
+});
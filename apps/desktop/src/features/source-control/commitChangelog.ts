@@ -0,0 +1,14 @@
+import type { HistoryCommit } from "./types";
+
+type ChangelogCommit = Pick<HistoryCommit, "shortId" | "summary">;
+
+export function commitChangelogLine(commit: ChangelogCommit): string {
+  const subject = commit.summary.trim();
+  return subject ? `${commit.shortId} ${subject}` : commit.shortId;
+}
+
+// One line per commit in log order, newest first, with a trailing newline so it pastes as a block.
+export function formatCommitChangelog(commits: readonly ChangelogCommit[]): string {
+  if (commits.length === 0) return "";
+  return `${commits.map(commitChangelogLine).join("\n")}\n`;
+}
@@ -0,0 +1,63 @@
+import { describe, expect, it } from "vitest";
+
+import { assignLanes } from "./commitGraph";
+import type { HistoryCommit } from "./types";
+
+function commit(commitId: string, parentIds: string[]): HistoryCommit {
+  return {
+    commitId,
+    shortId: commitId,
+    summary: commitId,
+    author: "Ada",
+    relativeTime: "now",
+    parentIds,
+  };
+}
+
+describe("assignLanes", () => {
+  it("keeps a linear history in a single lane", () => {
+    const lanes = assignLanes([commit("c", ["b"]), commit("b", ["a"]), commit("a", [])]);
+
+    expect(lanes.map((lane) => lane.column)).toEqual([0, 0, 0]);
+    expect(lanes.map((lane) => lane.laneCount)).toEqual([1, 1, 1]);
+    expect(lanes[0]?.parents).toEqual([{ parentId: "b", column: 0 }]);
+    expect(lanes[2]?.parents).toEqual([]);
+  });
+
+  it("opens a second lane for a merge and joins it at the branch point", () => {
+    const lanes = assignLanes([
+      commit("merge", ["main", "feature"]),
+      commit("main", ["base"]),
+      commit("feature", ["base"]),
+      commit("base", []),
+    ]);
+
+    expect(lanes).toEqual([
+      {
+        commitId: "merge",
+        column: 0,
+        joiningColumns: [],
+        parents: [
+          { parentId: "main", column: 0 },
+          { parentId: "feature", column: 1 },
+        ],
+        laneCount: 2,
+      },
+      {
+        commitId: "main",
+        column: 0,
+        joiningColumns: [],
+        parents: [{ parentId: "base", column: 0 }],
+        laneCount: 2,
+      },
+      {
+        commitId: "feature",
+        column: 1,
+        joiningColumns: [],
+        parents: [{ parentId: "base", column: 1 }],
+        laneCount: 2,
+      },
+      { commitId: "base", column: 0, joiningColumns: [1], parents: [], laneCount: 1 },
+    ]);
+  });
+});
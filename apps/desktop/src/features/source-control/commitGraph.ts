@@ -0,0 +1,68 @@
+import type { HistoryCommit } from "@/features/source-control/types";
+
+export type CommitLaneEdge = {
+  parentId: string;
+  column: number;
+};
+
+export type CommitLane = {
+  commitId: string;
+  column: number;
+  // Lanes that end here because several children already pointed at this commit.
+  joiningColumns: number[];
+  parents: CommitLaneEdge[];
+  laneCount: number;
+};
+
+function claimFreeColumn(lanes: (string | null)[], reserved: number) {
+  const free = lanes.findIndex((lane, index) => lane === null && index !== reserved);
+  return free === -1 ? lanes.length : free;
+}
+
+// Commits are expected newest first, as returned by getCommitHistory.
+export function assignLanes(commits: readonly HistoryCommit[]): CommitLane[] {
+  const lanes: (string | null)[] = [];
+  const rows: CommitLane[] = [];
+
+  for (const commit of commits) {
+    let column = lanes.indexOf(commit.commitId);
+    if (column === -1) {
+      column = claimFreeColumn(lanes, -1);
+    }
+
+    const joiningColumns: number[] = [];
+    lanes.forEach((lane, index) => {
+      if (lane === commit.commitId && index !== column) {
+        joiningColumns.push(index);
+        lanes[index] = null;
+      }
+    });
+
+    const [firstParentId, ...mergedParentIds] = commit.parentIds ?? [];
+    lanes[column] = firstParentId ?? null;
+    const parents: CommitLaneEdge[] = firstParentId ? [{ parentId: firstParentId, column }] : [];
+
+    for (const parentId of mergedParentIds) {
+      let parentColumn = lanes.indexOf(parentId);
+      if (parentColumn === -1) {
+        parentColumn = claimFreeColumn(lanes, column);
+        lanes[parentColumn] = parentId;
+      }
+      parents.push({ parentId, column: parentColumn });
+    }
+
+    while (lanes.length > 0 && lanes[lanes.length - 1] === null) {
+      lanes.pop();
+    }
+
+    rows.push({
+      commitId: commit.commitId,
+      column,
+      joiningColumns,
+      parents,
+      laneCount: Math.max(lanes.length, column + 1),
+    });
+  }
+
+  return rows;
+}
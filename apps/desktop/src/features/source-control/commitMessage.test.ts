@@ -0,0 +1,38 @@
+import { describe, expect, it } from "vitest";
+
+import { analyzeCommitMessage } from "./commitMessage";
+
+describe("analyzeCommitMessage", () => {
+  it("flags subjects past the 50 and 72 character guides", () => {
+    expect(analyzeCommitMessage("Fix typo").subjectStatus).toBe("ok");
+    expect(analyzeCommitMessage("x".repeat(50)).subjectStatus).toBe("ok");
+    expect(analyzeCommitMessage("x".repeat(51)).subjectStatus).toBe("long");
+    expect(analyzeCommitMessage("x".repeat(73)).subjectStatus).toBe("too-long");
+  });
+
+  it("splits the body after the blank separator line", () => {
+    expect(analyzeCommitMessage("Add reflog view\n\nLists HEAD moves.\nSecond line.\n")).toEqual({
+      subject: "Add reflog view",
+      subjectLength: 15,
+      subjectStatus: "ok",
+      body: "Lists HEAD moves.\nSecond line.",
+      hasBody: true,
+      missingBlankLine: false,
+    });
+  });
+
+  it("reports a body that is missing its blank separator line", () => {
+    const analysis = analyzeCommitMessage("Add reflog view\nLists HEAD moves.");
+
+    expect(analysis.hasBody).toBe(true);
+    expect(analysis.missingBlankLine).toBe(true);
+    expect(analysis.body).toBe("Lists HEAD moves.");
+  });
+
+  it("has no body for a subject-only message", () => {
+    expect(analyzeCommitMessage("Add reflog view\n\n")).toMatchObject({
+      hasBody: false,
+      missingBlankLine: false,
+    });
+  });
+});
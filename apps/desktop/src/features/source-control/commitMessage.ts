@@ -0,0 +1,40 @@
+export const COMMIT_SUBJECT_SOFT_LIMIT = 50;
+export const COMMIT_SUBJECT_HARD_LIMIT = 72;
+
+export type CommitSubjectLengthStatus = "ok" | "long" | "too-long";
+
+export type CommitMessageAnalysis = {
+  subject: string;
+  subjectLength: number;
+  subjectStatus: CommitSubjectLengthStatus;
+  body: string;
+  hasBody: boolean;
+  // Git treats everything up to the first blank line as the subject.
+  missingBlankLine: boolean;
+};
+
+function subjectStatusForLength(length: number): CommitSubjectLengthStatus {
+  if (length > COMMIT_SUBJECT_HARD_LIMIT) return "too-long";
+  if (length > COMMIT_SUBJECT_SOFT_LIMIT) return "long";
+  return "ok";
+}
+
+export function analyzeCommitMessage(message: string): CommitMessageAnalysis {
+  const lines = message.replace(/\r\n/g, "\n").split("\n");
+  const subject = (lines[0] ?? "").trimEnd();
+  const secondLine = lines[1] ?? "";
+  const missingBlankLine = lines.length > 1 && secondLine.trim() !== "";
+  const body = lines
+    .slice(missingBlankLine ? 1 : 2)
+    .join("\n")
+    .trimEnd();
+
+  return {
+    subject,
+    subjectLength: [...subject].length,
+    subjectStatus: subjectStatusForLength([...subject].length),
+    body,
+    hasBody: body.trim() !== "",
+    missingBlankLine,
+  };
+}
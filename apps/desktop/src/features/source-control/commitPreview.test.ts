@@ -0,0 +1,29 @@
+import { describe, expect, it } from "vitest";
+
+import { buildCommitPreview } from "./commitPreview";
+import type { FileItem } from "./types";
+
+describe("buildCommitPreview", () => {
+  it("shows the staged files under the draft message", () => {
+    const staged: FileItem[] = [
+      { path: "src/main.ts", previousPath: null, status: "modified" },
+      { path: "docs/notes.md", previousPath: null, status: "added" },
+    ];
+    const parent = { commitId: "a".repeat(40), shortId: "aaaaaaa", summary: "init", author: "Ada" };
+
+    const preview = buildCommitPreview({
+      message: "Add notes\n\nExplains the release.\n",
+      staged,
+      branch: "main",
+      parent,
+    });
+
+    expect(preview).toEqual({
+      summary: "Add notes",
+      body: "Explains the release.",
+      branch: "main",
+      parent,
+      files: staged,
+    });
+  });
+});
@@ -0,0 +1,27 @@
+import { analyzeCommitMessage } from "./commitMessage";
+import type { FileItem, RefResolution } from "./types";
+
+// The commit that committing now would create, as far as it can be known beforehand: the
+// draft message, the staged files, and the commit it would sit on top of.
+export type CommitPreview = {
+  summary: string;
+  body: string;
+  branch: string;
+  parent: RefResolution | null;
+  files: FileItem[];
+};
+
+export function buildCommitPreview({
+  message,
+  staged,
+  branch,
+  parent,
+}: {
+  message: string;
+  staged: FileItem[];
+  branch: string;
+  parent: RefResolution | null;
+}): CommitPreview {
+  const { subject, body } = analyzeCommitMessage(message);
+  return { summary: subject.trim(), body, branch, parent, files: staged };
+}
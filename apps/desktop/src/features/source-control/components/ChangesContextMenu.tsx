@@ -6,10 +6,23 @@ import {
   DropdownMenuShortcut,
   DropdownMenuTrigger,
 } from "@/components/ui/dropdown-menu";
-import { getUnifiedChangeDirectoryContext } from "@/features/source-control/components/changesUnifiedPierreTree";
+import {
+  getUnifiedChangeDirectoryContext,
+  type UnifiedChangeSectionKey,
+} from "@/features/source-control/components/changesUnifiedPierreTree";
 import type { Bucket, BucketedFile } from "@/features/source-control/types";
 import type { ContextMenuItem, ContextMenuOpenContext } from "@pierre/trees";
-import { Minus, Plus, Trash2 } from "lucide-react";
+import {
+  EyeOff,
+  FolderOpen,
+  Lock,
+  Minus,
+  Pin,
+  PinOff,
+  Plus,
+  RotateCcw,
+  Trash2,
+} from "lucide-react";
 import type { ComponentProps, CSSProperties } from "react";
 
 function getFloatingContextMenuTriggerStyle(
@@ -67,11 +80,14 @@ type ChangesFileContextMenuProps = {
   item: ContextMenuItem;
   context: ContextMenuOpenContext;
   file: BucketedFile;
-  sectionKey: "staged" | "unstaged" | "conflicts";
+  sectionKey: UnifiedChangeSectionKey;
   hasRunningAction: boolean;
+  isProtected: boolean;
   onStageFile: (path: string) => void;
   onUnstageFile: (path: string) => void;
   onDiscardFile: (bucket: Bucket, path: string) => void;
+  onAddToGitignore: (path: string) => void;
+  onRevealInFileManager: (path: string) => void;
 };
 
 export function ChangesFileContextMenu({
@@ -79,9 +95,12 @@ export function ChangesFileContextMenu({
   file,
   sectionKey,
   hasRunningAction,
+  isProtected,
   onStageFile,
   onUnstageFile,
   onDiscardFile,
+  onAddToGitignore,
+  onRevealInFileManager,
 }: ChangesFileContextMenuProps) {
   return (
     <DropdownMenu
@@ -111,7 +130,30 @@ export function ChangesFileContextMenu({
           context.restoreFocus();
         }}
       >
-        {sectionKey === "conflicts" ? (
+        {isProtected ? (
+          <>
+            {sectionKey === "staged" ? (
+              <>
+                <ChangesMenuItem
+                  disabled={hasRunningAction}
+                  onSelect={() => {
+                    context.close({ restoreFocus: false });
+                    onUnstageFile(file.path);
+                  }}
+                >
+                  <Minus className="size-3.5" />
+                  Unstage
+                  <ChangesMenuShortcut>⌘↵</ChangesMenuShortcut>
+                </ChangesMenuItem>
+                <ChangesMenuSeparator />
+              </>
+            ) : null}
+            <ChangesMenuItem disabled>
+              <Lock className="size-3.5" />
+              Protected path
+            </ChangesMenuItem>
+          </>
+        ) : sectionKey === "conflicts" ? (
           <ChangesMenuItem
             variant="destructive"
             disabled={hasRunningAction}
@@ -164,6 +206,18 @@ export function ChangesFileContextMenu({
               Stage
               <ChangesMenuShortcut>⌘↵</ChangesMenuShortcut>
             </ChangesMenuItem>
+            {file.bucket === "untracked" ? (
+              <ChangesMenuItem
+                disabled={hasRunningAction}
+                onSelect={() => {
+                  context.close({ restoreFocus: false });
+                  onAddToGitignore(file.path);
+                }}
+              >
+                <EyeOff className="size-3.5" />
+                Add to .gitignore
+              </ChangesMenuItem>
+            ) : null}
             <ChangesMenuSeparator />
             <ChangesMenuItem
               variant="destructive"
@@ -179,6 +233,88 @@ export function ChangesFileContextMenu({
             </ChangesMenuItem>
           </>
         )}
+        <ChangesMenuSeparator />
+        <ChangesMenuItem
+          onSelect={() => {
+            context.close({ restoreFocus: false });
+            onRevealInFileManager(file.path);
+          }}
+        >
+          <FolderOpen className="size-3.5" />
+          Reveal in file manager
+        </ChangesMenuItem>
+      </ChangesMenuContent>
+    </DropdownMenu>
+  );
+}
+
+type ReviewFileContextMenuProps = {
+  context: ContextMenuOpenContext;
+  path: string;
+  restoreRef: string;
+  hasRunningAction: boolean;
+  isPinned: boolean;
+  onRestoreFile: (path: string, ref: string) => void;
+  onTogglePin: (path: string) => void;
+};
+
+export function ReviewFileContextMenu({
+  context,
+  path,
+  restoreRef,
+  hasRunningAction,
+  isPinned,
+  onRestoreFile,
+  onTogglePin,
+}: ReviewFileContextMenuProps) {
+  return (
+    <DropdownMenu
+      open
+      onOpenChange={(nextOpen) => {
+        if (!nextOpen) {
+          context.close();
+        }
+      }}
+      modal={false}
+    >
+      <DropdownMenuTrigger asChild>
+        <button
+          type="button"
+          aria-hidden="true"
+          tabIndex={-1}
+          style={getFloatingContextMenuTriggerStyle(context.anchorRect)}
+        />
+      </DropdownMenuTrigger>
+      <ChangesMenuContent
+        data-file-tree-context-menu-root="true"
+        align="start"
+        side="bottom"
+        sideOffset={getContextMenuSideOffset(context.anchorRect)}
+        onCloseAutoFocus={(event) => {
+          event.preventDefault();
+          context.restoreFocus();
+        }}
+      >
+        <ChangesMenuItem
+          onSelect={() => {
+            context.close({ restoreFocus: false });
+            onTogglePin(path);
+          }}
+        >
+          {isPinned ? <PinOff className="size-3.5" /> : <Pin className="size-3.5" />}
+          {isPinned ? "Unpin" : "Pin to top"}
+        </ChangesMenuItem>
+        <ChangesMenuItem
+          variant="destructive"
+          disabled={hasRunningAction || !restoreRef}
+          onSelect={() => {
+            context.close({ restoreFocus: false });
+            onRestoreFile(path, restoreRef);
+          }}
+        >
+          <RotateCcw className="size-3.5" />
+          Restore from {restoreRef}
+        </ChangesMenuItem>
       </ChangesMenuContent>
     </DropdownMenu>
   );
@@ -194,7 +330,8 @@ type ChangesSectionContextMenuProps = {
   onStageAll: () => void;
   onUnstageAll: () => void;
   onStageFiles: (files: BucketedFile[]) => void;
-  onUnstageFiles: (files: BucketedFile[]) => void;
+  onStageDirectory: (dirPath: string) => void;
+  onUnstageDirectory: (dirPath: string) => void;
   onDiscardChangesGroup: (files: BucketedFile[]) => void;
 };
 
@@ -208,7 +345,8 @@ export function ChangesSectionContextMenu({
   onStageAll,
   onUnstageAll,
   onStageFiles,
-  onUnstageFiles,
+  onStageDirectory,
+  onUnstageDirectory,
   onDiscardChangesGroup,
 }: ChangesSectionContextMenuProps) {
   const directoryContext = getUnifiedChangeDirectoryContext(
@@ -221,7 +359,7 @@ export function ChangesSectionContextMenu({
     return null;
   }
 
-  const { isRoot, rows, sectionKey } = directoryContext;
+  const { isRoot, coversAllChanges, directoryPath, rows, sectionKey } = directoryContext;
   const isStagedSection = sectionKey === "staged";
   const isConflictSection = sectionKey === "conflicts";
 
@@ -271,7 +409,7 @@ export function ChangesSectionContextMenu({
               if (isRoot) {
                 onUnstageAll();
               } else {
-                onUnstageFiles(rows);
+                onUnstageDirectory(directoryPath);
               }
             }}
           >
@@ -284,10 +422,12 @@ export function ChangesSectionContextMenu({
               disabled={hasRunningAction || rows.length === 0}
               onSelect={() => {
                 context.close({ restoreFocus: false });
-                if (isRoot) {
+                if (coversAllChanges) {
                   onStageAll();
-                } else {
+                } else if (isRoot) {
                   onStageFiles(rows);
+                } else {
+                  onStageDirectory(directoryPath);
                 }
               }}
             >
@@ -1,5 +1,5 @@
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
-import { useGetGitSnapshotQuery } from "@/features/source-control/api";
+import { useGetWorkingCopySummaryQuery } from "@/features/source-control/api";
 import { refreshActiveRepo } from "@/features/source-control/actions";
 import CurrentRepositoryHeader from "@/features/source-control/components/CurrentRepoHeader";
 import { ChangesTab } from "@/features/source-control/components/ChangesTab";
@@ -8,11 +8,9 @@ export function ChangesSidebar() {
   const dispatch = useAppDispatch();
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const runningAction = useAppSelector((state) => state.sourceControl.runningAction);
-  const { activeBranch } = useGetGitSnapshotQuery(activeRepo, {
+  const { data: summary } = useGetWorkingCopySummaryQuery(activeRepo, {
     skip: !activeRepo,
-    selectFromResult: ({ data }) => ({
-      activeBranch: data?.branch ?? "",
-    }),
+    refetchOnFocus: true,
   });
 
   return (
@@ -20,7 +18,8 @@ export function ChangesSidebar() {
       <div className="flex min-w-0 flex-1 flex-col overflow-hidden">
         <CurrentRepositoryHeader
           activeRepo={activeRepo}
-          activeBranch={activeBranch}
+          activeBranch={summary?.branch ?? ""}
+          summary={summary}
           runningAction={runningAction}
           onRefresh={() => {
             void dispatch(refreshActiveRepo());
@@ -6,11 +6,12 @@ import {
   discardChangesGroupAction,
   discardFileAction,
   stageAllAction,
+  stageDirectoryAction,
   stageFileAction,
   stageFilesAction,
   unstageAllAction,
+  unstageDirectoryAction,
   unstageFileAction,
-  unstageFilesAction,
 } from "@/features/source-control/actions";
 import type { Bucket, BucketedFile, FileItem } from "@/features/source-control/types";
 import { CommitBox } from "./CommitBox";
@@ -74,6 +75,9 @@ function ChangesFileList() {
   const onUnstageAll = () => {
     void dispatch(unstageAllAction());
   };
+  const onStageFiles = (files: BucketedFile[]) => {
+    void dispatch(stageFilesAction(files));
+  };
 
   const onDiscardChangesGroup = async (files: BucketedFile[]) => {
     if (files.length === 0) return;
@@ -89,12 +93,12 @@ function ChangesFileList() {
     void dispatch(unstageFileAction(path));
   };
 
-  const onStageFiles = (files: BucketedFile[]) => {
-    void dispatch(stageFilesAction(files));
+  const onStageDirectory = (dirPath: string) => {
+    void dispatch(stageDirectoryAction(dirPath));
   };
 
-  const onUnstageFiles = (files: BucketedFile[]) => {
-    void dispatch(unstageFilesAction(files));
+  const onUnstageDirectory = (dirPath: string) => {
+    void dispatch(unstageDirectoryAction(dirPath));
   };
 
   const onDiscardFile = async (bucket: Bucket, path: string) => {
@@ -117,10 +121,11 @@ function ChangesFileList() {
             activeRepo={activeRepo}
             onStageAll={onStageAll}
             onUnstageAll={onUnstageAll}
+            onStageFiles={onStageFiles}
             onStageFile={onStageFile}
             onUnstageFile={onUnstageFile}
-            onStageFiles={onStageFiles}
-            onUnstageFiles={onUnstageFiles}
+            onStageDirectory={onStageDirectory}
+            onUnstageDirectory={onUnstageDirectory}
             onDiscardFile={onDiscardFile}
             onDiscardChangesGroup={onDiscardChangesGroup}
           />
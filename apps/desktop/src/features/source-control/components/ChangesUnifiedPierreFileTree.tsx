@@ -2,12 +2,17 @@ import type { FileTreeRowDecoration } from "@pierre/trees";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
 import { countCommentsForPathInRepoContext } from "@/features/comments/selectors";
+import { useGetGitSnapshotQuery } from "@/features/source-control/api";
 import {
+  addToGitignoreAction,
   rangeSelectFile,
+  revealInFileManagerAction,
   selectFile,
   toggleFileSelection,
 } from "@/features/source-control/actions";
+import { foldSubmodulePaths } from "@/features/source-control/fileTree";
 import { getPierreFileTreeVisibleSelectedFiles } from "@/features/source-control/pierreFileTreeNavigation";
+import { isProtectedPath } from "@/platform/desktop/protectedPaths";
 import type { Bucket, BucketedFile, FileBrowserMode } from "@/features/source-control/types";
 import {
   buildUnifiedChangeTreeFiles,
@@ -18,6 +23,8 @@ import {
   CONFLICTS_ROOT_PATH,
   getUnifiedChangeTreeHeight,
   STAGED_ROOT_PATH,
+  UNSTAGED_ROOT_PATH,
+  UNTRACKED_ROOT_PATH,
 } from "./changesUnifiedPierreTree";
 import { buildPierreGitStatusEntries } from "./pierreFileTree";
 import { PierreFileTreeBrowser } from "./PierreFileTreeBrowser";
@@ -27,6 +34,7 @@ import {
 } from "@/features/source-control/components/ChangesContextMenu";
 
 const SELECTION_KEY_SEPARATOR = "\u0000";
+const NO_SUBMODULES: string[] = [];
 
 type ChangesUnifiedPierreFileTreeProps = {
   mode: FileBrowserMode;
@@ -36,10 +44,11 @@ type ChangesUnifiedPierreFileTreeProps = {
   activeRepo: string;
   onStageAll: () => void;
   onUnstageAll: () => void;
+  onStageFiles: (files: BucketedFile[]) => void;
   onStageFile: (path: string) => void;
   onUnstageFile: (path: string) => void;
-  onStageFiles: (files: BucketedFile[]) => void;
-  onUnstageFiles: (files: BucketedFile[]) => void;
+  onStageDirectory: (dirPath: string) => void;
+  onUnstageDirectory: (dirPath: string) => void;
   onDiscardFile: (bucket: Bucket, path: string) => void;
   onDiscardChangesGroup: (files: BucketedFile[]) => void;
 };
@@ -52,10 +61,11 @@ export function ChangesUnifiedPierreFileTree({
   activeRepo,
   onStageAll,
   onUnstageAll,
+  onStageFiles,
   onStageFile,
   onUnstageFile,
-  onStageFiles,
-  onUnstageFiles,
+  onStageDirectory,
+  onUnstageDirectory,
   onDiscardFile,
   onDiscardChangesGroup,
 }: ChangesUnifiedPierreFileTreeProps) {
@@ -65,7 +75,21 @@ export function ChangesUnifiedPierreFileTree({
   const selectedFiles = useAppSelector((state) => state.sourceControl.selectedFiles);
   const comments = useAppSelector((state) => state.comments);
   const runningAction = useAppSelector((state) => state.sourceControl.runningAction);
-  const files = buildUnifiedChangeTreeFiles(stagedRows, changedRows, conflictRows, mode);
+  const newSinceLastView = useAppSelector((state) => state.sourceControl.newSinceLastView);
+  const protectedPaths = useAppSelector(
+    (state) => state.settings.appSettings.sourceControl.protectedPaths,
+  );
+  const { submodulePaths } = useGetGitSnapshotQuery(activeRepo, {
+    skip: !activeRepo,
+    selectFromResult: ({ data }) => ({ submodulePaths: data?.submodulePaths ?? NO_SUBMODULES }),
+  });
+  const submoduleRoots = new Set(submodulePaths);
+  const files = buildUnifiedChangeTreeFiles(
+    foldSubmodulePaths(stagedRows, submoduleRoots),
+    foldSubmodulePaths(changedRows, submoduleRoots),
+    conflictRows,
+    mode,
+  );
   const filesByTreePath = new Map(files.map((file) => [file.path, file]));
   const treePathBySelectionKey = new Map(files.map((file) => [selectionKey(file), file.path]));
   const selectedPath = treePathBySelectionKey.get(toBucketPathKey(activeBucket, activePath)) ?? "";
@@ -114,7 +138,7 @@ export function ChangesUnifiedPierreFileTree({
       style={{ height: `${treeHeight}px` }}
       disableInternalScroll
       flattenEmptyDirectories={false}
-      sort={mode === "list" ? compareUnifiedChangeListEntries : compareUnifiedChangeTreeEntries}
+      sort={mode === "tree" ? compareUnifiedChangeTreeEntries : compareUnifiedChangeListEntries}
       compareTreeDirectories={compareUnifiedChangeTreeDirectories}
       onActivatePath={activatePath}
       onTogglePathSelection={togglePathSelection}
@@ -131,6 +155,11 @@ export function ChangesUnifiedPierreFileTree({
               title: `${changedRows.length} changed files`,
             };
           }
+          if (item.path === UNSTAGED_ROOT_PATH || item.path === UNTRACKED_ROOT_PATH) {
+            const bucket = item.path === UNSTAGED_ROOT_PATH ? "unstaged" : "untracked";
+            const count = changedRows.filter((file) => file.bucket === bucket).length;
+            return { text: String(count), title: `${count} ${bucket} files` };
+          }
           if (item.path === CONFLICTS_ROOT_PATH) {
             return {
               text: String(conflictRows.length),
@@ -142,6 +171,9 @@ export function ChangesUnifiedPierreFileTree({
 
         const file = filesByTreePath.get(item.path);
         if (!file) return null;
+        if (submoduleRoots.has(file.realPath)) {
+          return { text: "submodule", title: "Submodule: changes are commit pointer updates" };
+        }
 
         const commentCount = countCommentsForPathInRepoContext(
           comments,
@@ -149,12 +181,18 @@ export function ChangesUnifiedPierreFileTree({
           file.realPath,
           { kind: "changes" },
         );
-        return commentCount > 0
-          ? {
-              text: String(commentCount),
-              title: `${commentCount} comment${commentCount === 1 ? "" : "s"}`,
-            }
-          : null;
+        const isNewSinceLastView = newSinceLastView.includes(file.realPath);
+        const commentTitle = `${commentCount} comment${commentCount === 1 ? "" : "s"}`;
+        if (isNewSinceLastView) {
+          return {
+            text: commentCount > 0 ? `new · ${commentCount}` : "new",
+            title:
+              commentCount > 0
+                ? `Changed since you last looked, ${commentTitle}`
+                : "Changed since you last looked",
+          };
+        }
+        return commentCount > 0 ? { text: String(commentCount), title: commentTitle } : null;
       }}
       renderContextMenu={(item, context) => {
         if (item.kind === "directory") {
@@ -169,7 +207,8 @@ export function ChangesUnifiedPierreFileTree({
               onStageAll={onStageAll}
               onUnstageAll={onUnstageAll}
               onStageFiles={onStageFiles}
-              onUnstageFiles={onUnstageFiles}
+              onStageDirectory={onStageDirectory}
+              onUnstageDirectory={onUnstageDirectory}
               onDiscardChangesGroup={onDiscardChangesGroup}
             />
           );
@@ -185,9 +224,12 @@ export function ChangesUnifiedPierreFileTree({
             file={{ ...file, path: file.realPath }}
             sectionKey={file.sectionKey}
             hasRunningAction={hasRunningAction}
+            isProtected={isProtectedPath(file.realPath, protectedPaths)}
             onStageFile={onStageFile}
             onUnstageFile={onUnstageFile}
             onDiscardFile={onDiscardFile}
+            onAddToGitignore={(path) => void dispatch(addToGitignoreAction(path))}
+            onRevealInFileManager={(path) => void dispatch(revealInFileManagerAction(path))}
           />
         );
       }}
@@ -1,9 +1,22 @@
-import { GitCommitHorizontal } from "lucide-react";
+import { useState } from "react";
+import { skipToken } from "@reduxjs/toolkit/query";
+import { Eye, GitCommitHorizontal } from "lucide-react";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
-import { Input } from "@/components/ui/input";
-import { useGetGitSnapshotQuery } from "@/features/source-control/api";
-import { commitAction, setCommitMessageValue } from "@/features/source-control/actions";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Textarea } from "@/components/ui/textarea";
+import { useGetGitSnapshotQuery, useResolveRefInfoQuery } from "@/features/source-control/api";
+import {
+  amendLastCommitAction,
+  commitAction,
+  setCommitMessageValue,
+} from "@/features/source-control/actions";
+import {
+  analyzeCommitMessage,
+  COMMIT_SUBJECT_HARD_LIMIT,
+  COMMIT_SUBJECT_SOFT_LIMIT,
+} from "@/features/source-control/commitMessage";
+import { CommitPreviewDialog } from "./CommitPreviewDialog";
 
 export function CommitBox() {
   const dispatch = useAppDispatch();
@@ -15,33 +28,110 @@ export function CommitBox() {
   });
   const snapshot = activeRepo ? snapshotData : undefined;
   const stagedCount = snapshot?.staged?.length ?? 0;
-  const canCommit = !!commitMessage.trim() && stagedCount > 0 && !runningAction;
+  // Null until HEAD resolves, and on a branch with no commits yet, where there is nothing to amend.
+  const { headSummary } = useResolveRefInfoQuery(
+    activeRepo ? { repoPath: activeRepo, ref: "HEAD" } : skipToken,
+    { selectFromResult: ({ currentData }) => ({ headSummary: currentData?.summary ?? null }) },
+  );
+  const [amend, setAmend] = useState(false);
+  const isAmending = amend && headSummary !== null;
+  const canCommit = isAmending
+    ? !runningAction
+    : !!commitMessage.trim() && stagedCount > 0 && !runningAction;
+  const [previewOpen, setPreviewOpen] = useState(false);
+  const analysis = analyzeCommitMessage(commitMessage);
+  const subjectLimit =
+    analysis.subjectStatus === "ok" ? COMMIT_SUBJECT_SOFT_LIMIT : COMMIT_SUBJECT_HARD_LIMIT;
+
+  const onCommit = () => {
+    if (!isAmending) {
+      void dispatch(commitAction());
+      return;
+    }
+    void dispatch(amendLastCommitAction()).then((amended) => {
+      if (amended) setAmend(false);
+    });
+  };
 
   return (
     <div className="border-border border-b px-2 py-4">
-      <Input
+      <Textarea
         value={commitMessage}
         onChange={(e) => dispatch(setCommitMessageValue(e.target.value))}
-        placeholder="Message (Cmd+Enter to commit)"
-        className="border-input bg-input h-7 text-xs"
+        placeholder={
+          isAmending
+            ? "New message, or leave empty to keep the last one"
+            : "Message (Cmd+Enter to commit)"
+        }
+        rows={1}
+        className="border-input bg-input max-h-40 min-h-7 resize-none rounded-none px-2 py-1.5 text-xs break-words whitespace-pre-wrap md:text-xs"
         onKeyDown={(e) => {
           if ((e.metaKey || e.ctrlKey) && e.key === "Enter") {
             e.preventDefault();
-            void dispatch(commitAction());
+            onCommit();
           }
         }}
       />
-      <button
-        type="button"
-        className="bg-destructive text-destructive-foreground hover:bg-destructive/90 mt-1.5 flex w-full items-center justify-center gap-1.5 px-2 py-1.5 text-xs font-semibold disabled:cursor-not-allowed disabled:opacity-60"
-        onClick={() => {
-          void dispatch(commitAction());
-        }}
-        disabled={!canCommit}
-      >
-        <GitCommitHorizontal className="h-3.5 w-3.5" />
-        {runningAction === "commit" ? "Committing..." : "Commit"}
-      </button>
+      {commitMessage ? (
+        <div className="text-muted-foreground mt-1 flex items-center gap-2 text-[11px]">
+          <span
+            className={
+              analysis.subjectStatus === "too-long"
+                ? "text-destructive"
+                : analysis.subjectStatus === "long"
+                  ? "text-amber-600 dark:text-amber-400"
+                  : undefined
+            }
+            title={`Keep the subject under ${COMMIT_SUBJECT_SOFT_LIMIT} characters, ${COMMIT_SUBJECT_HARD_LIMIT} at most`}
+          >
+            Subject {analysis.subjectLength}/{subjectLimit}
+          </span>
+          {analysis.missingBlankLine ? (
+            <span className="text-amber-600 dark:text-amber-400">
+              Add a blank line before the body
+            </span>
+          ) : analysis.hasBody ? (
+            <span className="border-border flex-1 border-t" aria-hidden="true" />
+          ) : null}
+          {analysis.hasBody && !analysis.missingBlankLine ? <span>Body</span> : null}
+        </div>
+      ) : null}
+      <label className="text-muted-foreground mt-1.5 flex items-center gap-1.5 text-[11px]">
+        <Checkbox
+          checked={isAmending}
+          disabled={headSummary === null}
+          onCheckedChange={(checked) => setAmend(checked === true)}
+        />
+        Amend last commit
+      </label>
+      <div className="mt-1.5 flex gap-1.5">
+        <button
+          type="button"
+          className="bg-destructive text-destructive-foreground hover:bg-destructive/90 flex flex-1 items-center justify-center gap-1.5 px-2 py-1.5 text-xs font-semibold disabled:cursor-not-allowed disabled:opacity-60"
+          onClick={onCommit}
+          disabled={!canCommit}
+        >
+          <GitCommitHorizontal className="h-3.5 w-3.5" />
+          {isAmending
+            ? runningAction === "amend"
+              ? "Amending..."
+              : "Amend"
+            : runningAction === "commit"
+              ? "Committing..."
+              : "Commit"}
+        </button>
+        <button
+          type="button"
+          title="Review staged changes"
+          aria-label="Review staged changes"
+          className="border-border text-muted-foreground hover:bg-surface-1 hover:text-foreground flex items-center justify-center border px-2 py-1.5 disabled:cursor-not-allowed disabled:opacity-60"
+          onClick={() => setPreviewOpen(true)}
+          disabled={stagedCount === 0}
+        >
+          <Eye className="h-3.5 w-3.5" />
+        </button>
+      </div>
+      <CommitPreviewDialog open={previewOpen} onOpenChange={setPreviewOpen} />
     </div>
   );
 }
@@ -0,0 +1,187 @@
+import { useState } from "react";
+import { skipToken } from "@reduxjs/toolkit/query";
+import { GitCommitHorizontal } from "lucide-react";
+
+import { useAppDispatch, useAppSelector } from "@/app/hooks";
+import { Button } from "@/components/ui/button";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { DiffWorkspace } from "@/features/diff-view/DiffWorkspace";
+import type { DiffLineSource } from "@/features/diff-view/services/lineReference";
+import { commitAction } from "@/features/source-control/actions";
+import {
+  useGetFileVersionsQuery,
+  useGetGitSnapshotQuery,
+  useResolveRefInfoQuery,
+} from "@/features/source-control/api";
+import { buildCommitPreview, type CommitPreview } from "@/features/source-control/commitPreview";
+import { errorMessageFrom } from "@/features/source-control/shared-utils/errorMessage";
+import type { FileItem } from "@/features/source-control/types";
+import { cn } from "@/lib/utils";
+
+type CommitPreviewDialogProps = {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+};
+
+export function CommitPreviewDialog({ open, onOpenChange }: CommitPreviewDialogProps) {
+  const dispatch = useAppDispatch();
+  const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
+  const commitMessage = useAppSelector((state) => state.sourceControl.commitMessage);
+  const runningAction = useAppSelector((state) => state.sourceControl.runningAction);
+  const { stagedFiles, branch } = useGetGitSnapshotQuery(activeRepo, {
+    skip: !activeRepo || !open,
+    selectFromResult: ({ data }) => ({
+      stagedFiles: data?.staged ?? EMPTY_FILES,
+      branch: data?.branch ?? "",
+    }),
+  });
+  // An unborn branch has no HEAD to resolve, which leaves the preview without a parent.
+  const { currentData: headInfo, isError: isUnbornHead } = useResolveRefInfoQuery(
+    activeRepo && open ? { repoPath: activeRepo, ref: "HEAD" } : skipToken,
+  );
+  const preview = buildCommitPreview({
+    message: commitMessage,
+    staged: stagedFiles,
+    branch,
+    parent: headInfo ?? null,
+  });
+  const [previewPath, setPreviewPath] = useState("");
+  const selectedPath = preview.files.some((file) => file.path === previewPath)
+    ? previewPath
+    : (preview.files[0]?.path ?? "");
+  const canCommit = !!commitMessage.trim() && stagedFiles.length > 0 && !runningAction;
+
+  const onCommit = async () => {
+    await dispatch(commitAction());
+    onOpenChange(false);
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="flex h-[80vh] flex-col gap-3 p-4 sm:max-w-6xl">
+        <DialogHeader>
+          <DialogTitle>Review staged changes</DialogTitle>
+          <DialogDescription>
+            {stagedFiles.length} staged file{stagedFiles.length === 1 ? "" : "s"} will be committed.
+          </DialogDescription>
+        </DialogHeader>
+        <CommitPreviewHeader preview={preview} isRootCommit={isUnbornHead} />
+        <div className="border-border flex min-h-0 flex-1 overflow-hidden border">
+          <div className="border-border w-56 shrink-0 overflow-y-auto border-r py-1">
+            {preview.files.map((file) => (
+              <button
+                key={file.path}
+                type="button"
+                title={file.path}
+                className={cn(
+                  "block w-full truncate px-2 py-1 text-left text-xs",
+                  file.path === selectedPath
+                    ? "bg-accent text-accent-foreground"
+                    : "text-muted-foreground hover:bg-surface-1 hover:text-foreground",
+                )}
+                onClick={() => setPreviewPath(file.path)}
+              >
+                {file.path}
+              </button>
+            ))}
+          </div>
+          <StagedDiffPreview repoPath={activeRepo} relPath={selectedPath} />
+        </div>
+        <DialogFooter>
+          <Button type="button" disabled={!canCommit} onClick={() => void onCommit()}>
+            <GitCommitHorizontal className="h-3.5 w-3.5" />
+            {runningAction === "commit" ? "Committing..." : "Commit"}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
+
+const STAGED_LINE_SOURCE: DiffLineSource = { kind: "index" };
+const EMPTY_FILES: FileItem[] = [];
+
+// Laid out like a commit in history, so the staged changes read as the commit they will become.
+function CommitPreviewHeader({
+  preview,
+  isRootCommit,
+}: {
+  preview: CommitPreview;
+  isRootCommit: boolean;
+}) {
+  const { summary, body, branch, parent } = preview;
+
+  return (
+    <div className="border-border bg-surface-1 space-y-1 border px-3 py-2">
+      <div className="flex items-baseline gap-2">
+        <span className="text-muted-foreground shrink-0 font-mono text-xs">(uncommitted)</span>
+        <span
+          className={cn(
+            "min-w-0 truncate text-sm font-medium",
+            !summary && "text-muted-foreground italic",
+          )}
+        >
+          {summary || "Enter a commit message to commit."}
+        </span>
+      </div>
+      {body ? (
+        <pre className="text-muted-foreground max-h-24 overflow-y-auto font-sans text-xs whitespace-pre-wrap">
+          {body}
+        </pre>
+      ) : null}
+      <div className="text-muted-foreground truncate text-[11px]">
+        {branch === "HEAD" ? "Detached HEAD" : `On ${branch}`}
+        {parent ? (
+          <>
+            {" · parent "}
+            <span className="font-mono">{parent.shortId}</span> {parent.summary}
+          </>
+        ) : isRootCommit ? (
+          " · root commit"
+        ) : null}
+      </div>
+    </div>
+  );
+}
+
+function StagedDiffPreview({ repoPath, relPath }: { repoPath: string; relPath: string }) {
+  const stagedFileVersions = useGetFileVersionsQuery(
+    repoPath && relPath ? { repoPath, bucket: "staged", relPath } : skipToken,
+  );
+  const fileVersions = stagedFileVersions.currentData ?? stagedFileVersions.data;
+  const oldFile = fileVersions?.oldFile ?? null;
+  const newFile = fileVersions?.newFile ?? null;
+  const errorMessage = fileVersions ? "" : errorMessageFrom(stagedFileVersions.error, "");
+
+  return (
+    <div className="flex min-h-0 min-w-0 flex-1 flex-col">
+      {errorMessage ? (
+        <div className="text-destructive p-3 text-sm">{errorMessage}</div>
+      ) : !relPath ? (
+        <div className="text-muted-foreground p-3 text-sm">No staged files.</div>
+      ) : !fileVersions && stagedFileVersions.isFetching ? (
+        <div className="text-muted-foreground p-3 text-sm">Loading diff...</div>
+      ) : !oldFile && !newFile ? (
+        <div className="text-muted-foreground p-3 text-sm">No diff content.</div>
+      ) : (
+        <DiffWorkspace
+          oldFile={oldFile}
+          newFile={newFile}
+          activePath={relPath}
+          commentContext={{ kind: "changes" }}
+          canComment={false}
+          hideHeaderMetadataControls
+          readOnly
+          lineReferenceSource={STAGED_LINE_SOURCE}
+        />
+      )}
+    </div>
+  );
+}
@@ -1,10 +1,14 @@
 import { OpenInExternalEditor } from "@/features/source-control/components/OpenInExternalEditor";
+import type { WorkingCopySummary } from "@/features/source-control/types";
 import { repoLabel } from "@/features/source-control/utils";
-import { GitBranch, RefreshCw } from "lucide-react";
+import { desktop } from "@/platform/desktop";
+import { FolderOpen, GitBranch, RefreshCw } from "lucide-react";
+import { toast } from "sonner";
 
 type CurrentRepositoryHeaderProps = {
   activeRepo: string;
   activeBranch?: string;
+  summary?: WorkingCopySummary;
   runningAction: string;
   onRefresh: () => void;
 };
@@ -12,6 +16,7 @@ type CurrentRepositoryHeaderProps = {
 function CurrentRepositoryHeader({
   activeRepo,
   activeBranch,
+  summary,
   runningAction,
   onRefresh,
 }: CurrentRepositoryHeaderProps) {
@@ -30,6 +35,18 @@ function CurrentRepositoryHeader({
             compact
             disabled={!!runningAction}
           />
+          <button
+            type="button"
+            className="text-muted-foreground hover:text-foreground inline-flex h-6 w-6 items-center justify-center"
+            title="Open repository folder"
+            aria-label="Open repository folder"
+            disabled={!activeRepo}
+            onClick={() => {
+              void revealRepoFolder(activeRepo);
+            }}
+          >
+            <FolderOpen className="h-3.5 w-3.5" />
+          </button>
           <button
             type="button"
             className="text-muted-foreground hover:text-foreground inline-flex h-6 w-6 items-center justify-center"
@@ -47,6 +64,7 @@ function CurrentRepositoryHeader({
           <>
             <GitBranch className="h-3 w-3 shrink-0" />
             <span className="truncate">{branchLabel}</span>
+            {summary ? <WorkingCopyCounts summary={summary} /> : null}
           </>
         ) : null}
       </div>
@@ -54,4 +72,28 @@ function CurrentRepositoryHeader({
   );
 }
 
+async function revealRepoFolder(repoPath: string) {
+  try {
+    await desktop.revealInFileManager(repoPath);
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    toast.error(`Failed to open repository folder: ${message}`);
+  }
+}
+
+function WorkingCopyCounts({ summary }: { summary: WorkingCopySummary }) {
+  const hasSyncCounts = !!summary.upstream && (summary.ahead > 0 || summary.behind > 0);
+
+  return (
+    <span className="ml-auto flex shrink-0 items-center gap-1.5 tabular-nums">
+      {hasSyncCounts ? (
+        <span title={`${summary.ahead} ahead, ${summary.behind} behind ${summary.upstream}`}>
+          ↑{summary.ahead} ↓{summary.behind}
+        </span>
+      ) : null}
+      {summary.dirtyCount > 0 ? <span>{summary.dirtyCount} changed</span> : null}
+    </span>
+  );
+}
+
 export default CurrentRepositoryHeader;
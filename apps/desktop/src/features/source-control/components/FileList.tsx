@@ -1,8 +1,14 @@
+import type { ContextMenuOpenContext } from "@pierre/trees";
 import type { ReactNode } from "react";
 
 import type { FileBrowserMode, FileStatus } from "@/features/source-control/types";
+import { orderPinnedFirst } from "@/features/source-control/pinnedFiles";
 import { buildDisplayFiles, compareFlatPierreEntries } from "./flatPierreTree";
-import { buildCommentCountDecoration, buildGitStatusForDisplayFiles } from "./pierreFileTree";
+import {
+  buildCommentCountDecoration,
+  buildGitStatusForDisplayFiles,
+  buildIgnoredDecoration,
+} from "./pierreFileTree";
 import { PierreFileTreeBrowser } from "./PierreFileTreeBrowser";
 
 export type FileListContextMenuItem = {
@@ -11,9 +17,7 @@ export type FileListContextMenuItem = {
   path: string;
 };
 
-export type FileListContextMenuOpenContext = {
-  close: (options?: { restoreFocus?: boolean }) => void;
-};
+export type FileListContextMenuOpenContext = ContextMenuOpenContext;
 
 type FileListProps<TFile extends { path: string }> = {
   files: ReadonlyArray<TFile>;
@@ -21,9 +25,12 @@ type FileListProps<TFile extends { path: string }> = {
   selectedPath?: string;
   navRegion: string;
   className?: string;
+  // Pinned files lead the list; tree mode keeps directory order.
+  pinnedPaths?: ReadonlyArray<string>;
   onActivatePath: (path: string, file: TFile) => void;
   getCommentCount?: (file: TFile) => number;
   getFileStatus?: (file: TFile) => FileStatus | undefined;
+  isFileIgnored?: (file: TFile) => boolean;
   renderContextMenu?: (
     file: TFile,
     item: FileListContextMenuItem,
@@ -39,18 +46,27 @@ export function FileList<TFile extends { path: string }>({
   selectedPath = "",
   navRegion,
   className,
+  pinnedPaths = [],
   onActivatePath,
   getCommentCount,
   getFileStatus,
+  isFileIgnored,
   renderContextMenu,
 }: FileListProps<TFile>) {
-  const isList = mode === "list";
+  // Status mode sections files by bucket, which only the changes tree has; here it is a list.
+  const isList = mode !== "tree";
 
-  const displayFiles = buildDisplayFiles(mode, files, {
-    sort: isList
-      ? (left, right) => left.path.localeCompare(right.path, undefined, SORT_LOCALE_OPTIONS)
-      : undefined,
-  });
+  const displayFiles = buildDisplayFiles(
+    mode,
+    isList
+      ? orderPinnedFirst(
+          files.toSorted((left, right) =>
+            left.path.localeCompare(right.path, undefined, SORT_LOCALE_OPTIONS),
+          ),
+          pinnedPaths,
+        )
+      : files,
+  );
 
   const sourceByDisplayPath = new Map(displayFiles.map((file) => [file.path, file.source]));
 
@@ -62,6 +78,14 @@ export function FileList<TFile extends { path: string }>({
     ? buildGitStatusForDisplayFiles(displayFiles, getFileStatus)
     : undefined;
 
+  const getFileByPath = (path: string) => sourceByDisplayPath.get(path);
+  const commentDecoration = getCommentCount
+    ? buildCommentCountDecoration(getFileByPath, getCommentCount)
+    : undefined;
+  const ignoredDecoration = isFileIgnored
+    ? buildIgnoredDecoration(getFileByPath, isFileIgnored)
+    : undefined;
+
   return (
     <PierreFileTreeBrowser
       files={displayFiles}
@@ -78,8 +102,8 @@ export function FileList<TFile extends { path: string }>({
       }}
       gitStatus={gitStatus}
       renderRowDecoration={
-        getCommentCount
-          ? buildCommentCountDecoration((path) => sourceByDisplayPath.get(path), getCommentCount)
+        commentDecoration || ignoredDecoration
+          ? (args) => commentDecoration?.(args) ?? ignoredDecoration?.(args) ?? null
           : undefined
       }
       renderContextMenu={
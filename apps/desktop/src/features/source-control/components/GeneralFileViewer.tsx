@@ -9,7 +9,7 @@ import { useAppDispatch, useAppSelector } from "@/app/hooks";
 import { Button } from "@/components/ui/button";
 import { DIFF_LINE_FOCUS_CSS, useDiffLineFocus } from "@/features/source-control/diffLineFocus";
 import { getDiffTheme, getDiffThemeType } from "@/features/diff-view/diffRenderConfig";
-import { useGetRepoFileQuery } from "@/features/source-control/api";
+import { useGetRepoFileQuery, useGetRepoFilesQuery } from "@/features/source-control/api";
 import { useCurrentLspDocument } from "@/features/lsp/hooks/useCurrentLspDocument";
 import { LspSymbolPeekContainer } from "@/features/lsp/components/LspSymbolPeek";
 import { useLspTokenNavigation } from "@/features/lsp/useLspTokenNavigation";
@@ -21,6 +21,9 @@ type GeneralFileViewerProps = {
   target?: FileViewerTarget | null;
 };
 
+// Larger files open with only their first few megabytes, so one huge file can't stall the viewer.
+const FILE_VIEWER_MAX_BYTES = 4 * 1024 * 1024;
+
 const FILE_VIEWER_CSS = `
     :host {
       min-width: 0;
@@ -91,6 +94,7 @@ export function GeneralFileViewer(props: GeneralFileViewerProps) {
           repoPath: target.repoPath,
           relPath: target.relPath,
           revision: target.revision,
+          maxBytes: FILE_VIEWER_MAX_BYTES,
         }
       : skipToken,
     {
@@ -99,11 +103,24 @@ export function GeneralFileViewer(props: GeneralFileViewerProps) {
     },
   );
 
+  const { isOutsideSparseCone } = useGetRepoFilesQuery(
+    target && !target.revision ? target.repoPath : skipToken,
+    {
+      selectFromResult: ({ data }) => ({
+        isOutsideSparseCone:
+          data?.some(
+            (repoFile) => repoFile.path === target?.relPath && repoFile.outsideSparseCone,
+          ) ?? false,
+      }),
+    },
+  );
+
   const file = repoFileQuery.currentData ?? repoFileQuery.data;
   const errorMessage = file ? "" : errorMessageFrom(repoFileQuery.error, "");
   const selectedLine = target?.line && target.line > 0 ? target.line : null;
   const focusKey = target?.focusKey ?? null;
-  const lspText = file?.contents ?? null;
+  // A truncated prefix would give the language server a broken document.
+  const lspText = file && !file.truncated ? file.contents : null;
   const lineCount = file ? countFileLines(file.contents) : null;
   const { onTokenClick } = useLspTokenNavigation(
     target ? { repoPath: target.repoPath, relPath: target.relPath } : undefined,
@@ -129,6 +146,14 @@ export function GeneralFileViewer(props: GeneralFileViewerProps) {
     return <div className="text-muted-foreground p-4 text-sm">Loading file...</div>;
   }
 
+  if (!file && isOutsideSparseCone) {
+    return (
+      <div className="text-muted-foreground p-4 text-sm">
+        This file is outside the sparse-checkout cone and has no working tree copy.
+      </div>
+    );
+  }
+
   if (!file) {
     return <div className="text-muted-foreground p-4 text-sm">Select a file to view it.</div>;
   }
@@ -155,6 +180,11 @@ export function GeneralFileViewer(props: GeneralFileViewerProps) {
           </div>
         </div>
       ) : null}
+      {file.truncated ? (
+        <div className="border-border/70 bg-surface-alt text-muted-foreground border-b px-4 py-2 text-xs">
+          {`Showing the first ${FILE_VIEWER_MAX_BYTES / (1024 * 1024)} MB of this file.`}
+        </div>
+      ) : null}
       <div
         key={file.name}
         ref={viewerRef}
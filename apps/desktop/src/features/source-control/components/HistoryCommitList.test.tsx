@@ -0,0 +1,93 @@
+import { configureStore } from "@reduxjs/toolkit";
+import { fireEvent, render, screen } from "@testing-library/react";
+import { Provider } from "react-redux";
+import { MemoryRouter } from "react-router";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+
+import { hydrateAppSettings, settingsReducer } from "@/features/settings/settingsSlice";
+import { gitApi } from "@/features/source-control/api";
+import { getCommitHistory } from "@/features/source-control/services/git";
+import { setActiveRepo, sourceControlReducer } from "@/features/source-control/sourceControlSlice";
+import type { HistoryCommit } from "@/features/source-control/types";
+import { createAppSettings } from "@/platform/desktop/appSettings";
+import { HistoryCommitList } from "./HistoryCommitList";
+
+const HISTORY_PAGE_SIZE = 25;
+
+vi.mock("@/features/source-control/services/git", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("@/features/source-control/services/git")>()),
+  getCommitHistory: vi.fn(),
+}));
+
+function makeCommits(start: number, count: number): HistoryCommit[] {
+  return Array.from({ length: count }, (_, index) => {
+    const commitId = (start + index).toString(16).padStart(40, "0");
+    return {
+      commitId,
+      shortId: commitId.slice(-7),
+      summary: `commit ${start + index}`,
+      author: "Dev",
+      relativeTime: "1 day ago",
+      parentIds: [],
+    };
+  });
+}
+
+function renderList() {
+  const store = configureStore({
+    reducer: {
+      settings: settingsReducer,
+      sourceControl: sourceControlReducer,
+      [gitApi.reducerPath]: gitApi.reducer,
+    },
+    middleware: (getDefaultMiddleware) => getDefaultMiddleware().concat(gitApi.middleware),
+  });
+  store.dispatch(
+    hydrateAppSettings(
+      createAppSettings({ sourceControl: { historyPageSize: HISTORY_PAGE_SIZE } }),
+    ),
+  );
+  store.dispatch(setActiveRepo("/repo"));
+
+  render(
+    <Provider store={store}>
+      <MemoryRouter>
+        <HistoryCommitList />
+      </MemoryRouter>
+    </Provider>,
+  );
+}
+
+describe("HistoryCommitList", () => {
+  beforeEach(() => {
+    for (const name of ["ResizeObserver", "IntersectionObserver"]) {
+      Object.defineProperty(window, name, {
+        configurable: true,
+        writable: true,
+        value: class {
+          observe() {}
+          unobserve() {}
+          disconnect() {}
+        },
+      });
+    }
+
+    vi.mocked(getCommitHistory).mockReset();
+    vi.mocked(getCommitHistory)
+      .mockResolvedValueOnce(makeCommits(0, HISTORY_PAGE_SIZE))
+      .mockResolvedValueOnce(makeCommits(HISTORY_PAGE_SIZE, 3));
+  });
+
+  it("loads the next configured page after the last loaded commit", async () => {
+    renderList();
+
+    fireEvent.click(await screen.findByRole("button", { name: "Load more" }));
+
+    expect(await screen.findByText(`commit ${HISTORY_PAGE_SIZE + 2}`)).toBeInTheDocument();
+    expect(vi.mocked(getCommitHistory).mock.calls).toEqual([
+      ["/repo", HISTORY_PAGE_SIZE, 0],
+      ["/repo", HISTORY_PAGE_SIZE, HISTORY_PAGE_SIZE],
+    ]);
+    expect(screen.queryByRole("button", { name: "Load more" })).not.toBeInTheDocument();
+  });
+});
@@ -1,36 +1,71 @@
+import { useEffect, useRef, useState, type RefObject } from "react";
 import { skipToken } from "@reduxjs/toolkit/query";
+import { useNavigate } from "react-router";
+
+import { Copy, FileDiff, FileText } from "lucide-react";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
 
+import {
+  ContextMenu,
+  ContextMenuContent,
+  ContextMenuItem,
+  ContextMenuTrigger,
+} from "@/components/ui/context-menu";
 import { Kbd } from "@/components/ui/kbd";
 import { Input } from "@/components/ui/input";
 import { ScrollArea } from "@/components/ui/scroll-area";
-import { useGetCommitHistoryQuery } from "@/features/source-control/api";
-import { selectHistoryCommit } from "@/features/source-control/actions";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  loadedHistoryCommits,
+  useGetCommitHistoryInfiniteQuery,
+  useGetCommitStatsQuery,
+} from "@/features/source-control/api";
+import {
+  compareCommitWithWorktreeAction,
+  copyCommitChangelogAction,
+  copyCommitPatchAction,
+  selectHistoryCommit,
+} from "@/features/source-control/actions";
+import { useCommitHistoryArgs } from "@/features/source-control/hooks/useCommitHistoryArgs";
 import { HISTORY_FILTER_INPUT_ID } from "@/features/source-control/constants";
-import { setHistoryFilter } from "@/features/source-control/sourceControlSlice";
+import { filterHistoryCommits, historyAuthors } from "@/features/source-control/historyFilter";
+import {
+  setHistoryAuthorFilter,
+  setHistoryFilter,
+} from "@/features/source-control/sourceControlSlice";
 import type { HistoryCommit } from "@/features/source-control/types";
+import { formatTimestamp } from "@/platform/desktop/dateFormat";
+
+// Radix Select reserves the empty string, so "every author" needs its own value.
+const ALL_AUTHORS_VALUE = "__all_authors__";
 
 export function HistoryCommitList() {
   const dispatch = useAppDispatch();
-  const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const historyFilter = useAppSelector((state) => state.sourceControl.historyFilter);
-  const { data: historyCommits = [], isFetching: loadingHistoryCommits } = useGetCommitHistoryQuery(
-    activeRepo ? { repoPath: activeRepo } : skipToken,
-  );
+  const historyAuthorFilter = useAppSelector((state) => state.sourceControl.historyAuthorFilter);
+  const {
+    data: historyPages,
+    isFetching,
+    isFetchingNextPage,
+    hasNextPage,
+    fetchNextPage,
+  } = useGetCommitHistoryInfiniteQuery(useCommitHistoryArgs());
+  const loadingHistoryCommits = isFetching && !isFetchingNextPage;
 
-  const allHistoryCommits = historyCommits as HistoryCommit[];
-  const query = historyFilter.trim().toLowerCase();
-  const filteredHistoryCommits = query
-    ? allHistoryCommits.filter((commit) => {
-        return (
-          commit.summary.toLowerCase().includes(query) ||
-          commit.shortId.toLowerCase().includes(query) ||
-          commit.commitId.toLowerCase().includes(query) ||
-          commit.author.toLowerCase().includes(query)
-        );
-      })
-    : allHistoryCommits;
+  const historyCommits = loadedHistoryCommits(historyPages);
+  const authors = historyAuthors(historyCommits);
+  const filteredHistoryCommits = filterHistoryCommits(
+    historyCommits,
+    historyFilter,
+    historyAuthorFilter,
+  );
 
   return (
     <ScrollArea data-nav-region="history-commits" className="min-h-0 flex-1 overflow-hidden">
@@ -44,11 +79,46 @@ export function HistoryCommitList() {
             className="border-input bg-input h-8 px-2 text-xs"
           />
 
+          {authors.length > 1 || historyAuthorFilter ? (
+            <Select
+              value={historyAuthorFilter || ALL_AUTHORS_VALUE}
+              onValueChange={(value) => {
+                dispatch(setHistoryAuthorFilter(value === ALL_AUTHORS_VALUE ? "" : value));
+              }}
+            >
+              <SelectTrigger className="mt-1.5 h-7 w-full text-xs" aria-label="Filter by author">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={ALL_AUTHORS_VALUE}>All authors</SelectItem>
+                {authors.map((author) => (
+                  <SelectItem key={author} value={author}>
+                    {author}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          ) : null}
+
           <div className="mt-1.5 flex items-center justify-between gap-2">
             <div className="text-muted-foreground text-[11px]">
               {filteredHistoryCommits.length} / {historyCommits.length} commits
             </div>
-            <Kbd className="h-4 px-1 text-[10px]">/</Kbd>
+            <div className="flex items-center gap-1.5">
+              <button
+                type="button"
+                className="text-muted-foreground hover:text-foreground inline-flex items-center gap-1 text-[11px] disabled:opacity-60"
+                disabled={historyCommits.length === 0}
+                title="Copy every loaded commit as `<short id> <subject>` lines"
+                onClick={() => {
+                  void dispatch(copyCommitChangelogAction(historyCommits));
+                }}
+              >
+                <Copy className="size-3" />
+                Changelog
+              </button>
+              <Kbd className="h-4 px-1 text-[10px]">/</Kbd>
+            </div>
           </div>
         </div>
 
@@ -74,6 +144,19 @@ export function HistoryCommitList() {
             ))}
           </div>
         )}
+
+        {hasNextPage && !loadingHistoryCommits ? (
+          <button
+            type="button"
+            className="border-input bg-surface text-muted-foreground hover:bg-accent/45 hover:text-foreground rounded-md border px-2 py-2 text-[11px] disabled:opacity-60"
+            disabled={isFetchingNextPage}
+            onClick={() => {
+              void fetchNextPage();
+            }}
+          >
+            {isFetchingNextPage ? "Loading more..." : "Load more"}
+          </button>
+        ) : null}
       </div>
     </ScrollArea>
   );
@@ -86,33 +169,120 @@ type HistoryCommitRowProps = {
 };
 
 function HistoryCommitRow({ commit, navIndex, onSelect }: HistoryCommitRowProps) {
+  const dispatch = useAppDispatch();
+  const navigate = useNavigate();
+  const rowRef = useRef<HTMLButtonElement | null>(null);
+  const isVisible = useIsVisible(rowRef);
   const isActive = useAppSelector(
     (state) => state.sourceControl.historyCommitId === commit.commitId,
   );
+  const historyDateFormat = useAppSelector(
+    (state) => state.settings.appSettings.sourceControl.historyDateFormat,
+  );
+  const commitDate =
+    historyDateFormat && commit.timestamp
+      ? formatTimestamp(commit.timestamp, historyDateFormat)
+      : commit.relativeTime;
   const stateClass = isActive
     ? "border-ring/30 bg-surface-active shadow-[inset_0_0_0_1px_rgba(120,132,160,0.3)]"
     : "border-input bg-surface hover:bg-accent/45";
 
   return (
-    <button
-      type="button"
-      data-nav-index={navIndex}
-      className={`block w-full min-w-0 overflow-hidden rounded-md border px-2.5 py-2 text-left ${stateClass}`}
-      onClick={() => onSelect(commit.commitId)}
-      title={commit.summary || commit.commitId}
+    <ContextMenu>
+      <ContextMenuTrigger asChild>
+        <button
+          ref={rowRef}
+          type="button"
+          data-nav-index={navIndex}
+          className={`block w-full min-w-0 overflow-hidden rounded-md border px-2.5 py-2 text-left ${stateClass}`}
+          onClick={() => onSelect(commit.commitId)}
+          title={commit.summary || commit.commitId}
+        >
+          <div className="flex min-w-0 items-center gap-1.5">
+            <span className="text-foreground w-0 flex-1 truncate text-[13px] leading-5 font-semibold">
+              {commit.summary || "(no commit message)"}
+            </span>
+          </div>
+          <div className="text-muted-foreground mt-1.5 flex min-w-0 items-center gap-1.5 overflow-hidden text-[11px]">
+            <span className="border-input bg-surface-alt text-foreground/90 max-w-[32%] shrink-0 truncate rounded-sm border px-1.5 py-0.5 font-semibold">
+              {commit.shortId}
+            </span>
+            <span className="min-w-0 flex-1 truncate">{commit.author || "Unknown"}</span>
+            <span className="shrink-0 truncate">{commitDate}</span>
+          </div>
+          <CommitChurnBar commitId={commit.commitId} enabled={isVisible} />
+        </button>
+      </ContextMenuTrigger>
+      <ContextMenuContent className="text-xs">
+        <ContextMenuItem onSelect={() => void dispatch(copyCommitChangelogAction([commit]))}>
+          <Copy className="size-3.5" />
+          Copy Short SHA + Subject
+        </ContextMenuItem>
+        <ContextMenuItem onSelect={() => void dispatch(copyCommitPatchAction(commit.commitId))}>
+          <FileText className="size-3.5" />
+          Copy as Patch
+        </ContextMenuItem>
+        <ContextMenuItem
+          onSelect={() => {
+            navigate("/review");
+            dispatch(compareCommitWithWorktreeAction(commit.commitId));
+          }}
+        >
+          <FileDiff className="size-3.5" />
+          Compare with Working Tree
+        </ContextMenuItem>
+      </ContextMenuContent>
+    </ContextMenu>
+  );
+}
+
+// Log scale so a 10k-line commit fills the bar without flattening typical small commits.
+const CHURN_BAR_FULL_SCALE = Math.log10(10_000);
+
+function CommitChurnBar({ commitId, enabled }: { commitId: string; enabled: boolean }) {
+  const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
+  const { data: stats } = useGetCommitStatsQuery(
+    enabled && activeRepo ? { repoPath: activeRepo, commitId } : skipToken,
+  );
+
+  if (!stats) {
+    return <div className="mt-1.5 h-1" />;
+  }
+
+  const churn = stats.insertions + stats.deletions;
+  const widthPercent = Math.min(100, (Math.log10(churn + 1) / CHURN_BAR_FULL_SCALE) * 100);
+  const insertionShare = churn > 0 ? (stats.insertions / churn) * 100 : 0;
+  const fileLabel = `${stats.files} file${stats.files === 1 ? "" : "s"}`;
+
+  return (
+    <div
+      className="mt-1.5 flex h-1 overflow-hidden rounded-full"
+      style={{ width: `${widthPercent}%` }}
+      title={`+${stats.insertions} −${stats.deletions} in ${fileLabel}`}
     >
-      <div className="flex min-w-0 items-center gap-1.5">
-        <span className="text-foreground w-0 flex-1 truncate text-[13px] leading-5 font-semibold">
-          {commit.summary || "(no commit message)"}
-        </span>
-      </div>
-      <div className="text-muted-foreground mt-1.5 flex min-w-0 items-center gap-1.5 overflow-hidden text-[11px]">
-        <span className="border-input bg-surface-alt text-foreground/90 max-w-[32%] shrink-0 truncate rounded-sm border px-1.5 py-0.5 font-semibold">
-          {commit.shortId}
-        </span>
-        <span className="min-w-0 flex-1 truncate">{commit.author || "Unknown"}</span>
-        <span className="shrink-0 truncate">{commit.relativeTime}</span>
-      </div>
-    </button>
+      <div className="h-full bg-emerald-500/80" style={{ width: `${insertionShare}%` }} />
+      <div className="h-full flex-1 bg-rose-500/80" />
+    </div>
   );
 }
+
+function useIsVisible(ref: RefObject<HTMLElement | null>) {
+  const [isVisible, setIsVisible] = useState(false);
+
+  useEffect(() => {
+    const element = ref.current;
+    if (!element || isVisible) return;
+
+    const observer = new IntersectionObserver((entries) => {
+      if (entries.some((entry) => entry.isIntersecting)) {
+        setIsVisible(true);
+        observer.disconnect();
+      }
+    });
+    observer.observe(element);
+
+    return () => observer.disconnect();
+  }, [isVisible, ref]);
+
+  return isVisible;
+}
@@ -1,7 +1,8 @@
 import { skipToken } from "@reduxjs/toolkit/query";
-import { FileText } from "lucide-react";
+import { FileText, Layers } from "lucide-react";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
+import { Button } from "@/components/ui/button";
 import {
   Empty,
   EmptyDescription,
@@ -9,12 +10,31 @@ import {
   EmptyMedia,
   EmptyTitle,
 } from "@/components/ui/empty";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
 import { countCommentsForPathInRepoContext } from "@/features/comments/selectors";
-import { useGetCommitFilesQuery, useGetCommitHistoryQuery } from "@/features/source-control/api";
+import {
+  loadedHistoryCommits,
+  useGetCommitFilesQuery,
+  useGetCommitHistoryInfiniteQuery,
+  useGetParentSummaryQuery,
+} from "@/features/source-control/api";
 import { selectHistoryFile } from "@/features/source-control/actions";
 import { FileList } from "@/features/source-control/components/FileList";
-import { setHistoryNavTarget } from "@/features/source-control/sourceControlSlice";
+import { useCommitHistoryArgs } from "@/features/source-control/hooks/useCommitHistoryArgs";
+import { useHistoryDiffBase } from "@/features/source-control/hooks/useHistoryDiffBase";
+import {
+  setHistoryNavTarget,
+  setHistoryParentIndex,
+  setHistoryRangeStartId,
+} from "@/features/source-control/sourceControlSlice";
 import type { FileItem } from "@/features/source-control/types";
+import { formatTimestamp } from "@/platform/desktop/dateFormat";
 
 export function HistoryFilesPane() {
   const dispatch = useAppDispatch();
@@ -22,17 +42,26 @@ export function HistoryFilesPane() {
   const activePath = useAppSelector((state) => state.sourceControl.activePath);
   const comments = useAppSelector((state) => state.comments);
   const historyCommitId = useAppSelector((state) => state.sourceControl.historyCommitId);
+  const historyParentIndex = useAppSelector((state) => state.sourceControl.historyParentIndex);
+  const historyRangeStartId = useAppSelector((state) => state.sourceControl.historyRangeStartId);
+  const historyDiffBase = useHistoryDiffBase();
   const fileBrowserMode = useAppSelector(
     (state) => state.settings.appSettings.sourceControl.fileTreeRenderMode,
   );
-  const { historyCommits } = useGetCommitHistoryQuery(
-    activeRepo ? { repoPath: activeRepo } : skipToken,
-    {
-      selectFromResult: ({ data }) => ({ historyCommits: data ?? [] }),
-    },
+  const historyDateFormat = useAppSelector(
+    (state) => state.settings.appSettings.sourceControl.historyDateFormat,
   );
+  const { data: historyPages } = useGetCommitHistoryInfiniteQuery(useCommitHistoryArgs());
+  const historyCommits = loadedHistoryCommits(historyPages);
   const { historyFiles, loadingHistoryFiles } = useGetCommitFilesQuery(
-    activeRepo && historyCommitId ? { repoPath: activeRepo, commitId: historyCommitId } : skipToken,
+    activeRepo && historyCommitId
+      ? {
+          repoPath: activeRepo,
+          commitId: historyCommitId,
+          parentIndex: historyParentIndex,
+          baseRef: historyDiffBase,
+        }
+      : skipToken,
     {
       selectFromResult: ({ data, isFetching }) => ({
         historyFiles: data ?? [],
@@ -41,8 +70,18 @@ export function HistoryFilesPane() {
     },
   );
 
+  const { data: parentSummary } = useGetParentSummaryQuery(
+    activeRepo && historyCommitId ? { repoPath: activeRepo, commitId: historyCommitId } : skipToken,
+  );
+
   const selectedCommit = historyCommits.find((commit) => commit?.commitId === historyCommitId);
   const files = historyFiles as FileItem[];
+  const parentIds = selectedCommit?.parentIds ?? [];
+  const commitDate =
+    historyDateFormat && selectedCommit?.timestamp
+      ? formatTimestamp(selectedCommit.timestamp, historyDateFormat)
+      : "";
+  const rangeStart = historyCommits.find((commit) => commit?.commitId === historyRangeStartId);
 
   return (
     <aside
@@ -57,9 +96,69 @@ export function HistoryFilesPane() {
         </div>
         <div className="text-muted-foreground mt-1 text-xs">
           {selectedCommit
-            ? `${selectedCommit.shortId} · ${historyFiles.length} file${historyFiles.length === 1 ? "" : "s"}`
+            ? [
+                selectedCommit.shortId,
+                commitDate,
+                `${historyFiles.length} file${historyFiles.length === 1 ? "" : "s"}`,
+              ]
+                .filter(Boolean)
+                .join(" · ")
             : "No commit selected"}
         </div>
+        {selectedCommit && parentSummary ? (
+          <div
+            className="text-muted-foreground/80 mt-0.5 truncate text-[11px]"
+            title={parentSummary}
+          >
+            {`on top of: ${parentSummary}`}
+          </div>
+        ) : null}
+        {historyDiffBase && rangeStart ? (
+          <div className="text-muted-foreground mt-2 flex items-center justify-between gap-2 text-xs">
+            <span className="truncate">{`Cumulative since ${rangeStart.shortId}`}</span>
+            <Button
+              variant="ghost"
+              size="xs"
+              onClick={() => {
+                dispatch(setHistoryRangeStartId(""));
+              }}
+            >
+              Single commit
+            </Button>
+          </div>
+        ) : selectedCommit && parentIds.length > 0 ? (
+          <Button
+            variant="ghost"
+            size="xs"
+            className="mt-2"
+            title="Diff newer commits against this commit's parent"
+            onClick={() => {
+              dispatch(setHistoryRangeStartId(selectedCommit.commitId));
+            }}
+          >
+            <Layers />
+            Start range here
+          </Button>
+        ) : null}
+        {parentIds.length > 1 && !historyDiffBase ? (
+          <Select
+            value={String(historyParentIndex)}
+            onValueChange={(value) => {
+              dispatch(setHistoryParentIndex(Number(value)));
+            }}
+          >
+            <SelectTrigger className="mt-2 h-7 w-full text-xs" aria-label="Compare against parent">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {parentIds.map((parentId, index) => (
+                <SelectItem key={parentId} value={String(index)}>
+                  {`Parent ${index + 1} · ${parentId.slice(0, 7)}`}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        ) : null}
       </div>
       {loadingHistoryFiles && files.length === 0 ? (
         <Empty className="h-auto border-0 p-4">
@@ -9,6 +9,7 @@ import { FileTree as PierreFileTree, useFileTree } from "@pierre/trees/react";
 import {
   useEffect,
   useRef,
+  useState,
   type CSSProperties,
   type KeyboardEvent,
   type MouseEvent,
@@ -29,6 +30,7 @@ import {
   collectCollapsedDirectoryPaths,
   collectVisibleFilePaths,
   collapseDirectoryPaths,
+  syncPierreGitStatus,
   type PierreFileTreeBrowserFile,
 } from "./pierreFileTree";
 
@@ -167,11 +169,14 @@ export function PierreFileTreeBrowser<TFile extends PierreFileTreeBrowserFile>({
   onRangeSelectPathRef.current = onRangeSelectPath;
   renderRowDecorationRef.current = renderRowDecoration;
 
-  const filePaths = files.map((file) => file.path);
-  const initialPreparedInput = prepareFileTreeInput(filePaths, {
-    flattenEmptyDirectories,
-    sort,
-  });
+  // The model only reads this on mount; later path changes go through resetPaths below.
+  const [initialPreparedInput] = useState(() =>
+    prepareFileTreeInput(
+      files.map((file) => file.path),
+      { flattenEmptyDirectories, sort },
+    ),
+  );
+  const appliedGitStatusRef = useRef(gitStatus);
 
   const { model } = useFileTree({
     density: 0.5,
@@ -262,7 +267,7 @@ export function PierreFileTreeBrowser<TFile extends PierreFileTreeBrowserFile>({
   }, [model, selectedPath]);
 
   useEffect(() => {
-    model.setGitStatus(gitStatus);
+    appliedGitStatusRef.current = syncPierreGitStatus(model, appliedGitStatusRef.current, gitStatus);
   }, [gitStatus, model]);
 
   useOnLayoutScrollToFocusedPath({
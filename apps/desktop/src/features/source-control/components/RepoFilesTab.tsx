@@ -1,6 +1,10 @@
+import { skipToken } from "@reduxjs/toolkit/query";
+import { useState } from "react";
+
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
+import { Button } from "@/components/ui/button";
 import { countCommentsForPathInRepoContext } from "@/features/comments/selectors";
-import { useGetRepoFilesQuery } from "@/features/source-control/api";
+import { useGetAllRepoFilesQuery } from "@/features/source-control/api";
 import {
   openFileViewer,
   setRepoTreeActivePath,
@@ -15,15 +19,18 @@ export function RepoFilesTab() {
   const fileBrowserMode = useAppSelector(
     (state) => state.settings.appSettings.sourceControl.fileTreeRenderMode,
   );
-  const { repoFiles, isLoadingRepoFiles } = useGetRepoFilesQuery(activeRepo, {
-    skip: !activeRepo,
-    refetchOnFocus: true,
-    refetchOnReconnect: true,
-    selectFromResult: ({ data, isLoading }) => ({
-      repoFiles: data ?? [],
-      isLoadingRepoFiles: isLoading,
-    }),
-  });
+  const [showIgnored, setShowIgnored] = useState(false);
+  const { repoFiles, isLoadingRepoFiles } = useGetAllRepoFilesQuery(
+    activeRepo ? { repoPath: activeRepo, includeIgnored: showIgnored } : skipToken,
+    {
+      refetchOnFocus: true,
+      refetchOnReconnect: true,
+      selectFromResult: ({ data, isLoading }) => ({
+        repoFiles: data ?? [],
+        isLoadingRepoFiles: isLoading,
+      }),
+    },
+  );
   const openRepoFilePath = (path: string) => {
     if (!activeRepo) {
       dispatch(setRepoTreeActivePath(path));
@@ -40,6 +47,20 @@ export function RepoFilesTab() {
 
   return (
     <aside className="bg-surface-toolbar border-border/70 flex h-full min-h-0 flex-col overflow-hidden border-r">
+      <div className="border-border/70 flex items-center border-b px-2 py-1">
+        <Button
+          type="button"
+          size="xs"
+          variant={showIgnored ? "secondary" : "ghost"}
+          className="h-6 rounded-sm px-2 text-[11px]"
+          aria-pressed={showIgnored}
+          disabled={!activeRepo}
+          title="Include gitignored files"
+          onClick={() => setShowIgnored((value) => !value)}
+        >
+          Ignored
+        </Button>
+      </div>
       {isLoadingRepoFiles && repoFiles.length === 0 ? (
         <div className="text-muted-foreground px-2 py-2 text-xs">Loading files...</div>
       ) : repoFiles.length === 0 ? (
@@ -56,6 +77,7 @@ export function RepoFilesTab() {
           getCommentCount={(file) =>
             countCommentsForPathInRepoContext(comments, activeRepo, file.path)
           }
+          isFileIgnored={(file) => file.ignored === true}
         />
       )}
     </aside>
@@ -1,11 +1,17 @@
 import { useEffect, type ReactNode } from "react";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
+import { confirmDiscard } from "@/features/comments/actions";
 import { countCommentsForPathInRepoContext } from "@/features/comments/selectors";
 import { countPullRequestThreadsForFile } from "@/features/pull-requests/utils/reviewThreadAnnotations";
+import { restoreFileFromRefAction } from "@/features/source-control/actions";
+import { ReviewFileContextMenu } from "@/features/source-control/components/ChangesContextMenu";
 import { FileList } from "@/features/source-control/components/FileList";
 import { useReviewKeyboardNav } from "@/features/source-control/hooks/useReviewKeyboardNav";
-import { setReviewActivePath } from "@/features/source-control/sourceControlSlice";
+import {
+  setReviewActivePath,
+  togglePinnedPath,
+} from "@/features/source-control/sourceControlSlice";
 import type { FileItem } from "@/features/source-control/types";
 import type { PullRequestReviewThread } from "@/platform/desktop";
 
@@ -51,6 +57,8 @@ export function ReviewSelectionSync({
   return null;
 }
 
+const NO_PINNED_PATHS: string[] = [];
+
 type ReviewFileListProps = {
   title: string;
   subtitle?: ReactNode;
@@ -85,10 +93,22 @@ export function ReviewFileList({
     (state) => state.settings.appSettings.sourceControl.fileTreeRenderMode,
   );
   const reviewActivePath = useAppSelector((state) => state.sourceControl.reviewActivePath);
+  const pinnedPaths = useAppSelector(
+    (state) => state.sourceControl.pinnedPaths[activeRepo] ?? NO_PINNED_PATHS,
+  );
   const comments = useAppSelector((state) => state.comments);
+  const hasRunningAction = useAppSelector((state) => state.sourceControl.runningAction !== "");
 
   useReviewKeyboardNav(navRegion);
 
+  const onRestoreFile = async (path: string, ref: string) => {
+    const confirmed = await confirmDiscard(
+      `Restore ${path} from ${ref}? Local changes to this file will be lost.`,
+    );
+    if (!confirmed) return;
+    void dispatch(restoreFileFromRefAction(path, ref));
+  };
+
   const getCommentCount = (file: FileItem) =>
     countPullRequestThreadsForFile({
       path: file.path,
@@ -123,11 +143,23 @@ export function ReviewFileList({
           mode={fileBrowserMode}
           selectedPath={reviewActivePath}
           navRegion={navRegion}
+          pinnedPaths={pinnedPaths}
           onActivatePath={(path) => {
             dispatch(setReviewActivePath(path));
           }}
           getCommentCount={getCommentCount}
           getFileStatus={(file) => file.status}
+          renderContextMenu={(file, _item, context) => (
+            <ReviewFileContextMenu
+              context={context}
+              path={file.path}
+              restoreRef={reviewBaseRef}
+              hasRunningAction={hasRunningAction}
+              isPinned={pinnedPaths.includes(file.path)}
+              onRestoreFile={(path, ref) => void onRestoreFile(path, ref)}
+              onTogglePin={(path) => dispatch(togglePinnedPath({ repoPath: activeRepo, path }))}
+            />
+          )}
         />
       )}
     </aside>
@@ -1,5 +1,5 @@
 import type { ReactNode } from "react";
-import { FolderTree, List } from "lucide-react";
+import { FolderTree, List, ListTree } from "lucide-react";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
 import { Button } from "@/components/ui/button";
@@ -35,6 +35,14 @@ export function SourceControlFileViewToggle({ className }: SourceControlFileView
         >
           <List className="h-3.5 w-3.5" />
         </ViewModeButton>
+        <ViewModeButton
+          active={fileBrowserMode === "status"}
+          label="Status"
+          mode="status"
+          onClick={(mode) => void dispatch(updateFileTreeRenderMode(mode))}
+        >
+          <ListTree className="h-3.5 w-3.5" />
+        </ViewModeButton>
       </div>
     </div>
   );
@@ -0,0 +1,56 @@
+import { describe, expect, it } from "vitest";
+
+import type { BucketedFile } from "@/features/source-control/types";
+import {
+  buildUnifiedChangeTreeFiles,
+  getUnifiedChangeDirectoryContext,
+} from "./changesUnifiedPierreTree";
+
+const staged: BucketedFile[] = [
+  { path: "src/app.ts", previousPath: null, status: "modified", bucket: "staged" },
+];
+const changed: BucketedFile[] = [
+  { path: "src/b.ts", previousPath: null, status: "modified", bucket: "unstaged" },
+  { path: "notes.md", previousPath: null, status: "untracked", bucket: "untracked" },
+  { path: "src/a.ts", previousPath: null, status: "deleted", bucket: "unstaged" },
+];
+
+describe("unified change tree sections", () => {
+  it("sections status mode by bucket", () => {
+    const files = buildUnifiedChangeTreeFiles(staged, changed, [], "status");
+
+    expect(files.map((file) => [file.path.split("/")[0], file.realPath])).toEqual([
+      ["Staged Changes", "src/app.ts"],
+      ["Unstaged", "src/a.ts"],
+      ["Unstaged", "src/b.ts"],
+      ["Untracked", "notes.md"],
+    ]);
+    expect(files.map((file) => file.sectionKey)).toEqual([
+      "staged",
+      "unstaged",
+      "unstaged",
+      "untracked",
+    ]);
+  });
+
+  it("keeps unstaged and untracked files together outside status mode", () => {
+    const files = buildUnifiedChangeTreeFiles(staged, changed, [], "tree");
+
+    expect(files.map((file) => file.path)).toEqual([
+      "Staged Changes/src/app.ts",
+      "Changes/src/b.ts",
+      "Changes/notes.md",
+      "Changes/src/a.ts",
+    ]);
+  });
+
+  it("scopes a bucket section to its own files", () => {
+    const untracked = getUnifiedChangeDirectoryContext("Untracked/", staged, changed, []);
+    const changes = getUnifiedChangeDirectoryContext("Changes/", staged, changed, []);
+
+    expect(untracked).toMatchObject({ isRoot: true, coversAllChanges: false });
+    expect(untracked?.rows.map((file) => file.path)).toEqual(["notes.md"]);
+    expect(changes).toMatchObject({ isRoot: true, coversAllChanges: true });
+    expect(changes?.rows).toHaveLength(3);
+  });
+});
@@ -5,6 +5,7 @@ import {
   collectDirectoryPaths,
   type SourceControlTreeDirectoryNode,
 } from "@/features/source-control/fileTree";
+import { groupFilesByBucket } from "@/features/source-control/statusGroups";
 import type { BucketedFile, FileBrowserMode } from "@/features/source-control/types";
 import { getFlatPierrePathIndex, toDisplayPath } from "./flatPierreTree";
 import { PIERRE_FILE_TREE_ITEM_HEIGHT } from "./PierreFileTreeBrowser";
@@ -18,17 +19,35 @@ export const CHANGES_ROOT_PATH = `${CHANGES_ROOT}/`;
 const CONFLICTS_ROOT = "Merge Conflicts";
 export const CONFLICTS_ROOT_PATH = `${CONFLICTS_ROOT}/`;
 
+// Status mode splits Changes into its two buckets.
+const UNSTAGED_ROOT = "Unstaged";
+export const UNSTAGED_ROOT_PATH = `${UNSTAGED_ROOT}/`;
+
+const UNTRACKED_ROOT = "Untracked";
+export const UNTRACKED_ROOT_PATH = `${UNTRACKED_ROOT}/`;
+
 const SECTION_SORT_ORDER = new Map([
   [CONFLICTS_ROOT, 0],
   [STAGED_ROOT, 1],
   [CHANGES_ROOT, 2],
+  [UNSTAGED_ROOT, 3],
+  [UNTRACKED_ROOT, 4],
 ]);
+
+const BUCKET_SECTION_ROOTS = {
+  staged: STAGED_ROOT,
+  unstaged: UNSTAGED_ROOT,
+  untracked: UNTRACKED_ROOT,
+} as const;
+
 const SORT_LOCALE_OPTIONS: Intl.CollatorOptions = { numeric: true, sensitivity: "base" };
 
+export type UnifiedChangeSectionKey = "staged" | "unstaged" | "untracked" | "conflicts";
+
 export type UnifiedChangeTreeFile = BucketedFile & {
   path: string;
   realPath: string;
-  sectionKey: "staged" | "unstaged" | "conflicts";
+  sectionKey: UnifiedChangeSectionKey;
 };
 
 export function buildUnifiedChangeTreeFiles(
@@ -37,25 +56,36 @@ export function buildUnifiedChangeTreeFiles(
   conflictRows: ReadonlyArray<BucketedFile>,
   mode: FileBrowserMode,
 ): UnifiedChangeTreeFile[] {
+  const conflictFiles = conflictRows.map((file, index) =>
+    toUnifiedFile(file, CONFLICTS_ROOT, "conflicts", mode, index),
+  );
+  if (mode === "status") {
+    return [
+      ...conflictFiles,
+      ...groupFilesByBucket([...stagedRows, ...changedRows]).flatMap(({ bucket, files }) =>
+        files.map((file, index) =>
+          toUnifiedFile(file, BUCKET_SECTION_ROOTS[bucket], bucket, mode, index),
+        ),
+      ),
+    ];
+  }
+
   return [
-    ...conflictRows.map((file, index) => toUnifiedFile(file, "conflicts", mode, index)),
-    ...stagedRows.map((file, index) => toUnifiedFile(file, "staged", mode, index)),
-    ...changedRows.map((file, index) => toUnifiedFile(file, "unstaged", mode, index)),
+    ...conflictFiles,
+    ...stagedRows.map((file, index) => toUnifiedFile(file, STAGED_ROOT, "staged", mode, index)),
+    ...changedRows.map((file, index) =>
+      toUnifiedFile(file, CHANGES_ROOT, "unstaged", mode, index),
+    ),
   ];
 }
 
 function toUnifiedFile(
   file: BucketedFile,
-  sectionKey: "staged" | "unstaged" | "conflicts",
+  root: string,
+  sectionKey: UnifiedChangeSectionKey,
   mode: FileBrowserMode,
   index: number,
 ): UnifiedChangeTreeFile {
-  const root =
-    sectionKey === "staged"
-      ? STAGED_ROOT
-      : sectionKey === "conflicts"
-        ? CONFLICTS_ROOT
-        : CHANGES_ROOT;
   return {
     ...file,
     path: `${root}/${toDisplayPath(mode, file.path, index)}`,
@@ -150,20 +180,29 @@ export function getUnifiedChangeDirectoryContext(
   conflictRows: ReadonlyArray<BucketedFile>,
 ) {
   const normalizedSectionPath = normalizeTreePath(sectionPath);
-  const stagedRoot = normalizeTreePath(STAGED_ROOT_PATH);
-  const changesRoot = normalizeTreePath(CHANGES_ROOT_PATH);
-  const conflictsRoot = normalizeTreePath(CONFLICTS_ROOT_PATH);
-
-  const sectionKey = getSectionKey(normalizedSectionPath, stagedRoot, changesRoot, conflictsRoot);
-  if (!sectionKey) {
+  const section = [
+    { root: STAGED_ROOT, sectionKey: "staged" as const, sectionRows: stagedRows },
+    { root: CHANGES_ROOT, sectionKey: "unstaged" as const, sectionRows: changedRows },
+    {
+      root: UNSTAGED_ROOT,
+      sectionKey: "unstaged" as const,
+      sectionRows: changedRows.filter((file) => file.bucket === "unstaged"),
+    },
+    {
+      root: UNTRACKED_ROOT,
+      sectionKey: "untracked" as const,
+      sectionRows: changedRows.filter((file) => file.bucket === "untracked"),
+    },
+    { root: CONFLICTS_ROOT, sectionKey: "conflicts" as const, sectionRows: conflictRows },
+  ].find(
+    ({ root }) => normalizedSectionPath === root || normalizedSectionPath.startsWith(`${root}/`),
+  );
+  if (!section) {
     return null;
   }
 
-  const rootPath =
-    sectionKey === "staged" ? stagedRoot : sectionKey === "conflicts" ? conflictsRoot : changesRoot;
-  const sectionRows =
-    sectionKey === "staged" ? stagedRows : sectionKey === "conflicts" ? conflictRows : changedRows;
-  const directoryPath = normalizedSectionPath.slice(rootPath.length).replace(/^\/+/, "");
+  const { root, sectionKey, sectionRows } = section;
+  const directoryPath = normalizedSectionPath.slice(root.length).replace(/^\/+/, "");
   const rows =
     directoryPath.length === 0
       ? [...sectionRows]
@@ -171,32 +210,14 @@ export function getUnifiedChangeDirectoryContext(
 
   return {
     isRoot: directoryPath.length === 0,
+    // Stage All and Unstage All cover these roots exactly; a bucket section is only part of one.
+    coversAllChanges: directoryPath.length === 0 && (root === STAGED_ROOT || root === CHANGES_ROOT),
+    directoryPath,
     rows,
     sectionKey,
   };
 }
 
-function getSectionKey(
-  sectionPath: string,
-  stagedRoot: string,
-  changesRoot: string,
-  conflictsRoot: string,
-) {
-  if (sectionPath === stagedRoot || sectionPath.startsWith(`${stagedRoot}/`)) {
-    return "staged";
-  }
-
-  if (sectionPath === changesRoot || sectionPath.startsWith(`${changesRoot}/`)) {
-    return "unstaged";
-  }
-
-  if (sectionPath === conflictsRoot || sectionPath.startsWith(`${conflictsRoot}/`)) {
-    return "conflicts";
-  }
-
-  return null;
-}
-
 function normalizeTreePath(path: string) {
   return path.replaceAll("\\", "/").replace(/^\/+|\/+$/g, "");
 }
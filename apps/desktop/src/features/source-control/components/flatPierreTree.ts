@@ -33,7 +33,7 @@ export const compareFlatPierreEntries: FileTreeSortComparator = (left, right) =>
 };
 
 export function toDisplayPath(mode: FileBrowserMode, realPath: string, index: number): string {
-  return mode === "list" ? toFlatPierreLeafPath(realPath, index) : realPath;
+  return mode === "tree" ? realPath : toFlatPierreLeafPath(realPath, index);
 }
 
 export function buildDisplayFiles<TSource extends { path: string }>(
@@ -43,7 +43,7 @@ export function buildDisplayFiles<TSource extends { path: string }>(
     sort?: (left: TSource, right: TSource) => number;
   },
 ): Array<DisplayFile<TSource>> {
-  const isList = mode === "list";
+  const isList = mode !== "tree";
   const sorted = isList && options?.sort ? files.toSorted(options.sort) : [...files];
 
   return sorted.map((file, index) => ({
@@ -0,0 +1,39 @@
+import { describe, expect, it, vi } from "vitest";
+
+import { buildIgnoredDecoration, syncPierreGitStatus } from "./pierreFileTree";
+
+describe("syncPierreGitStatus", () => {
+  it("only pushes status into the tree when the entries change", () => {
+    const model = { setGitStatus: vi.fn() };
+    const modified = [{ path: "src/app.ts", status: "modified" as const }];
+
+    let applied = syncPierreGitStatus(model, undefined, modified);
+    applied = syncPierreGitStatus(model, applied, [{ path: "src/app.ts", status: "modified" }]);
+
+    expect(model.setGitStatus).toHaveBeenCalledTimes(1);
+
+    syncPierreGitStatus(model, applied, [{ path: "src/app.ts", status: "added" }]);
+
+    expect(model.setGitStatus).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe("buildIgnoredDecoration", () => {
+  it("marks ignored files and leaves tracked files and directories alone", () => {
+    const files = new Map([
+      ["dist/app.js", { path: "dist/app.js", ignored: true }],
+      ["src/app.ts", { path: "src/app.ts" }],
+    ]);
+    const decorate = buildIgnoredDecoration(
+      (path) => files.get(path),
+      (file) => file.ignored === true,
+    );
+
+    expect(decorate({ item: { kind: "file", path: "dist/app.js" } })).toEqual({
+      text: "ignored",
+      title: "Ignored by git",
+    });
+    expect(decorate({ item: { kind: "file", path: "src/app.ts" } })).toBeNull();
+    expect(decorate({ item: { kind: "directory", path: "dist" } })).toBeNull();
+  });
+});
@@ -212,6 +212,32 @@ export function buildPierreGitStatusEntries<TFile>(
   return entries;
 }
 
+function areGitStatusEntriesEqual(
+  left: readonly GitStatusEntry[] | undefined,
+  right: readonly GitStatusEntry[] | undefined,
+) {
+  if (left === right) return true;
+  if (!left || !right || left.length !== right.length) return false;
+
+  return left.every(
+    (entry, index) => entry.path === right[index]?.path && entry.status === right[index]?.status,
+  );
+}
+
+// Callers rebuild status entries every render; only push them into the tree when they differ.
+export function syncPierreGitStatus(
+  model: Pick<PierreFileTreeModel, "setGitStatus">,
+  applied: readonly GitStatusEntry[] | undefined,
+  next: readonly GitStatusEntry[] | undefined,
+): readonly GitStatusEntry[] | undefined {
+  if (areGitStatusEntriesEqual(applied, next)) {
+    return applied;
+  }
+
+  model.setGitStatus(next);
+  return next;
+}
+
 export function buildGitStatusForDisplayFiles<TDisplay extends DisplayFile>(
   displayFiles: ReadonlyArray<TDisplay>,
   getFileStatus: (source: TDisplay["source"]) => FileStatus | undefined,
@@ -246,3 +272,18 @@ export function buildCommentCountDecoration<TFile>(
       : null;
   };
 }
+
+// Ignored files are only listed on request; the marker sets them apart from tracked ones.
+export function buildIgnoredDecoration<TFile>(
+  getFileByPath: (path: string) => TFile | undefined,
+  isIgnored: (file: TFile) => boolean,
+): (args: { item: { kind: string; path: string } }) => FileTreeRowDecoration | null {
+  return ({ item }): FileTreeRowDecoration | null => {
+    if (item.kind === "directory") {
+      return null;
+    }
+
+    const file = getFileByPath(item.path);
+    return file && isIgnored(file) ? { text: "ignored", title: "Ignored by git" } : null;
+  };
+}
@@ -1,6 +1,11 @@
 import { describe, expect, it } from "vitest";
 
-import { buildSourceControlFileTree, collectDirectoryPaths } from "./fileTree";
+import {
+  buildSourceControlFileTree,
+  collectDirectoryPaths,
+  foldSubmodulePaths,
+  submoduleRootFor,
+} from "./fileTree";
 
 describe("buildSourceControlFileTree", () => {
   it("groups files into sorted directories and files", () => {
@@ -65,6 +70,23 @@ describe("buildSourceControlFileTree", () => {
     ]);
   });
 
+  it("collapses a very deep single-child chain into one node", () => {
+    const segments = Array.from({ length: 2000 }, (_, index) => `level${index}`);
+    const directoryPath = segments.join("/");
+    const tree = buildSourceControlFileTree([{ path: `${directoryPath}/leaf.ts` }]);
+
+    expect(tree).toMatchObject([
+      {
+        kind: "directory",
+        name: "level0/…/level1999",
+        path: directoryPath,
+        fileCount: 1,
+        children: [{ kind: "file", name: "leaf.ts", path: `${directoryPath}/leaf.ts` }],
+      },
+    ]);
+    expect(collectDirectoryPaths(tree)).toEqual([directoryPath]);
+  });
+
   it("can keep single-child directory chains unflattened", () => {
     const tree = buildSourceControlFileTree(
       [
@@ -116,3 +138,36 @@ describe("collectDirectoryPaths", () => {
     expect(collectDirectoryPaths(tree)).toEqual(["src", "src/features", "src/lib"]);
   });
 });
+
+describe("foldSubmodulePaths", () => {
+  it("renders a submodule root instead of expanding its internals", () => {
+    const submodulePaths = new Set(["libs/vendor"]);
+    const tree = buildSourceControlFileTree(
+      foldSubmodulePaths(
+        [
+          { path: "libs/vendor/src/index.ts" },
+          { path: "libs/vendor/README.md" },
+          { path: "libs/vendored.ts" },
+          { path: "src/app.ts" },
+        ],
+        submodulePaths,
+      ),
+    );
+
+    expect(tree).toMatchObject([
+      {
+        kind: "directory",
+        path: "libs",
+        fileCount: 2,
+        children: [
+          { kind: "file", name: "vendor", path: "libs/vendor" },
+          { kind: "file", name: "vendored.ts", path: "libs/vendored.ts" },
+        ],
+      },
+      { kind: "directory", path: "src" },
+    ]);
+    expect(collectDirectoryPaths(tree)).not.toContain("libs/vendor");
+    expect(submoduleRootFor("libs/vendor", submodulePaths)).toBe("libs/vendor");
+    expect(submoduleRootFor("libs/vendored.ts", submodulePaths)).toBeNull();
+  });
+});
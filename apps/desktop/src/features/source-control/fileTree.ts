@@ -52,23 +52,28 @@ function compareByName(a: { name: string }, b: { name: string }): number {
   return a.name.localeCompare(b.name, undefined, SORT_LOCALE_OPTIONS);
 }
 
+// Compacted chains longer than this keep only their first and last folders in the name, so
+// pathological nesting stays readable; siblings still sort by the untouched first segment.
+const MAX_COMPACTED_SEGMENTS = 6;
+
+function compactedDirectoryName(relativePath: string): string {
+  const segments = relativePath.split("/");
+  if (segments.length <= MAX_COMPACTED_SEGMENTS) return relativePath;
+  return `${segments[0]}/…/${segments.at(-1)}`;
+}
+
+// Children are compacted before their parent, so only the parent's own chain is merged here.
 function compactDirectoryNode<TFile>(
   node: SourceControlTreeDirectoryNode<TFile>,
 ): SourceControlTreeDirectoryNode<TFile> {
-  const compactedChildren = node.children.map((child) =>
-    child.kind === "directory" ? compactDirectoryNode(child) : child,
-  );
-
-  let compactedNode: SourceControlTreeDirectoryNode<TFile> = {
-    ...node,
-    children: compactedChildren,
-  };
+  const parentPrefixLength = node.path.length - node.name.length;
+  let compactedNode = node;
 
   while (compactedNode.children.length === 1 && compactedNode.children[0]?.kind === "directory") {
     const onlyChild = compactedNode.children[0];
     compactedNode = {
       kind: "directory",
-      name: `${compactedNode.name}/${onlyChild.name}`,
+      name: compactedDirectoryName(onlyChild.path.slice(parentPrefixLength)),
       path: onlyChild.path,
       fileCount: onlyChild.fileCount,
       children: onlyChild.children,
@@ -78,31 +83,60 @@ function compactDirectoryNode<TFile>(
   return compactedNode;
 }
 
+type PendingDirectory<TFile> = {
+  directory: MutableDirectoryNode<TFile>;
+  depth: number;
+  childrenQueued: boolean;
+};
+
+// Built bottom-up with an explicit stack so deeply nested paths cannot exhaust the call stack.
 function toTreeNodes<TFile>(
-  directory: MutableDirectoryNode<TFile>,
+  root: MutableDirectoryNode<TFile>,
   options: BuildSourceControlFileTreeOptions<TFile>,
-  depth: number,
 ): SourceControlTreeNode<TFile>[] {
-  const subdirectories: SourceControlTreeDirectoryNode<TFile>[] = [
-    ...directory.directories.values(),
-  ]
-    .toSorted(compareByName)
-    .map<SourceControlTreeDirectoryNode<TFile>>((subdirectory) => ({
-      kind: "directory",
-      name: subdirectory.name,
-      path: subdirectory.path,
-      fileCount: subdirectory.fileCount,
-      children: toTreeNodes(subdirectory, options, depth + 1),
-    }))
-    .map((subdirectory) =>
-      options.flattenEmptyDirectories === false ? subdirectory : compactDirectoryNode(subdirectory),
-    )
-    .toSorted((left, right) => options.compareDirectories?.(left, right, depth) ?? 0);
-
-  const files = [...directory.files].toSorted(
-    (left, right) => options.compareFiles?.(left, right, depth) ?? compareByName(left, right),
-  );
-  return [...subdirectories, ...files];
+  const builtChildren = new Map<MutableDirectoryNode<TFile>, SourceControlTreeNode<TFile>[]>();
+  const pending: PendingDirectory<TFile>[] = [{ directory: root, depth: 0, childrenQueued: false }];
+
+  for (let entry = pending.pop(); entry; entry = pending.pop()) {
+    const { directory, depth } = entry;
+    if (!entry.childrenQueued) {
+      entry.childrenQueued = true;
+      pending.push(entry);
+      for (const subdirectory of directory.directories.values()) {
+        pending.push({ directory: subdirectory, depth: depth + 1, childrenQueued: false });
+      }
+      continue;
+    }
+
+    const subdirectories: SourceControlTreeDirectoryNode<TFile>[] = [
+      ...directory.directories.values(),
+    ]
+      .toSorted(compareByName)
+      .map<SourceControlTreeDirectoryNode<TFile>>((subdirectory) => {
+        const children = builtChildren.get(subdirectory) ?? [];
+        builtChildren.delete(subdirectory);
+        return {
+          kind: "directory",
+          name: subdirectory.name,
+          path: subdirectory.path,
+          fileCount: subdirectory.fileCount,
+          children,
+        };
+      })
+      .map((subdirectory) =>
+        options.flattenEmptyDirectories === false
+          ? subdirectory
+          : compactDirectoryNode(subdirectory),
+      )
+      .toSorted((left, right) => options.compareDirectories?.(left, right, depth) ?? 0);
+
+    const files = [...directory.files].toSorted(
+      (left, right) => options.compareFiles?.(left, right, depth) ?? compareByName(left, right),
+    );
+    builtChildren.set(directory, [...subdirectories, ...files]);
+  }
+
+  return builtChildren.get(root) ?? [];
 }
 
 export function buildSourceControlFileTree<TFile extends { path: string }>(
@@ -159,17 +193,56 @@ export function buildSourceControlFileTree<TFile extends { path: string }>(
     }
   }
 
-  return toTreeNodes(root, options, 0);
+  return toTreeNodes(root, options);
+}
+
+export function submoduleRootFor(
+  pathValue: string,
+  submodulePaths: ReadonlySet<string>,
+): string | null {
+  if (submodulePaths.size === 0) return null;
+
+  const segments = normalizePathSegments(pathValue);
+  for (let length = 1; length <= segments.length; length += 1) {
+    const prefix = segments.slice(0, length).join("/");
+    if (submodulePaths.has(prefix)) return prefix;
+  }
+  return null;
+}
+
+// Git reports a submodule as one gitlink path, whose diff is only a commit pointer change. Any
+// paths beneath a submodule root collapse onto that root so the tree never descends into it.
+export function foldSubmodulePaths<TFile extends { path: string }>(
+  files: ReadonlyArray<TFile>,
+  submodulePaths: ReadonlySet<string>,
+): TFile[] {
+  if (submodulePaths.size === 0) return [...files];
+
+  const foldedRoots = new Set<string>();
+  const folded: TFile[] = [];
+  for (const file of files) {
+    const root = submoduleRootFor(file.path, submodulePaths);
+    if (!root) {
+      folded.push(file);
+      continue;
+    }
+    if (foldedRoots.has(root)) continue;
+
+    foldedRoots.add(root);
+    folded.push(root === file.path ? file : { ...file, path: root });
+  }
+  return folded;
 }
 
 export function collectDirectoryPaths<TFile>(
   nodes: ReadonlyArray<SourceControlTreeNode<TFile>>,
 ): string[] {
   const paths: string[] = [];
-  for (const node of nodes) {
+  const pendingNodes = nodes.toReversed();
+  for (let node = pendingNodes.pop(); node; node = pendingNodes.pop()) {
     if (node.kind !== "directory") continue;
     paths.push(node.path);
-    paths.push(...collectDirectoryPaths(node.children));
+    pendingNodes.push(...node.children.toReversed());
   }
   return paths;
 }
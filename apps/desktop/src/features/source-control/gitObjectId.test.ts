@@ -0,0 +1,11 @@
+import { describe, expect, it } from "vitest";
+
+import { gitBlobId } from "./gitObjectId";
+
+describe("gitBlobId", () => {
+  it("matches git hash-object", () => {
+    expect(gitBlobId("")).toBe("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391");
+    expect(gitBlobId("héllo ✓\n")).toBe("922546d2b370fcdb20dec9db061f2960c3a118cd");
+    expect(gitBlobId("x".repeat(1000))).toBe("14c7dfdd4258dec5c0e9d2e919bd249bd674be1f");
+  });
+});
@@ -0,0 +1,65 @@
+function rotateLeft(value: number, bits: number) {
+  return (value << bits) | (value >>> (32 - bits));
+}
+
+function sha1Hex(bytes: Uint8Array): string {
+  const bitLength = bytes.length * 8;
+  const paddedLength = Math.ceil((bytes.length + 9) / 64) * 64;
+  const padded = new Uint8Array(paddedLength);
+  padded.set(bytes);
+  padded[bytes.length] = 0x80;
+  const view = new DataView(padded.buffer);
+  view.setUint32(paddedLength - 8, Math.floor(bitLength / 2 ** 32));
+  view.setUint32(paddedLength - 4, bitLength >>> 0);
+
+  const state = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+  const words = new Uint32Array(80);
+
+  for (let offset = 0; offset < paddedLength; offset += 64) {
+    for (let index = 0; index < 16; index += 1) {
+      words[index] = view.getUint32(offset + index * 4);
+    }
+    for (let index = 16; index < 80; index += 1) {
+      words[index] = rotateLeft(
+        words[index - 3]! ^ words[index - 8]! ^ words[index - 14]! ^ words[index - 16]!,
+        1,
+      );
+    }
+
+    let [a, b, c, d, e] = state as [number, number, number, number, number];
+    for (let index = 0; index < 80; index += 1) {
+      const [f, k] =
+        index < 20
+          ? [(b & c) | (~b & d), 0x5a827999]
+          : index < 40
+            ? [b ^ c ^ d, 0x6ed9eba1]
+            : index < 60
+              ? [(b & c) | (b & d) | (c & d), 0x8f1bbcdc]
+              : [b ^ c ^ d, 0xca62c1d6];
+      const next = (rotateLeft(a, 5) + f + e + k + words[index]!) >>> 0;
+      e = d;
+      d = c;
+      c = rotateLeft(b, 30) >>> 0;
+      b = a;
+      a = next;
+    }
+
+    state[0] = (state[0]! + a) >>> 0;
+    state[1] = (state[1]! + b) >>> 0;
+    state[2] = (state[2]! + c) >>> 0;
+    state[3] = (state[3]! + d) >>> 0;
+    state[4] = (state[4]! + e) >>> 0;
+  }
+
+  return state.map((word) => word.toString(16).padStart(8, "0")).join("");
+}
+
+// The id `git hash-object` gives the contents, so patches can carry a real `index` line.
+export function gitBlobId(contents: string): string {
+  const body = new TextEncoder().encode(contents);
+  const header = new TextEncoder().encode(`blob ${body.length}\0`);
+  const bytes = new Uint8Array(header.length + body.length);
+  bytes.set(header);
+  bytes.set(body, header.length);
+  return sha1Hex(bytes);
+}
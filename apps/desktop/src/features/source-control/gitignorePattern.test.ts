@@ -0,0 +1,19 @@
+import { describe, expect, it } from "vitest";
+
+import { gitignorePatternForPath } from "./gitignorePattern";
+
+describe("gitignorePatternForPath", () => {
+  it("anchors plain paths to the repo root", () => {
+    expect(gitignorePatternForPath("logs/scratch.log")).toBe("/logs/scratch.log");
+  });
+
+  it("escapes glob, negation, and comment characters", () => {
+    expect(gitignorePatternForPath("a[1]*?.txt")).toBe("/a\\[1\\]\\*\\?.txt");
+    expect(gitignorePatternForPath("!important/#notes")).toBe("/\\!important/\\#notes");
+    expect(gitignorePatternForPath("dir\\file")).toBe("/dir\\\\file");
+  });
+
+  it("escapes trailing spaces only", () => {
+    expect(gitignorePatternForPath("my file  ")).toBe("/my file\\ \\ ");
+  });
+});
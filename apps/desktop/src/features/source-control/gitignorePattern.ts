@@ -0,0 +1,6 @@
+// A root-anchored pattern that matches exactly this path: glob and comment characters are
+// backslash-escaped, and trailing spaces are kept instead of being stripped by git.
+export function gitignorePatternForPath(filePath: string): string {
+  const escaped = filePath.replace(/[\\[\]*?!#]/g, "\\$&");
+  return `/${escaped.replace(/ +$/, (spaces) => "\\ ".repeat(spaces.length))}`;
+}
@@ -0,0 +1,47 @@
+import { describe, expect, it } from "vitest";
+
+import { filterCommitsByAuthor, filterHistoryCommits, historyAuthors } from "./historyFilter";
+import type { HistoryCommit } from "./types";
+
+function commit(commitId: string, author: string, summary = commitId): HistoryCommit {
+  return {
+    commitId,
+    shortId: commitId.slice(0, 7),
+    summary,
+    author,
+    relativeTime: "now",
+  };
+}
+
+const commits = [
+  commit("c4", "Grace", "fix parser"),
+  commit("c3", "Ada", "add parser"),
+  commit("c2", "Grace", "docs"),
+  commit("c1", "Linus", "initial"),
+];
+
+describe("historyAuthors", () => {
+  it("lists each loaded author once in sorted order", () => {
+    expect(historyAuthors(commits)).toEqual(["Ada", "Grace", "Linus"]);
+  });
+});
+
+describe("filterCommitsByAuthor", () => {
+  it("keeps only the selected author's commits", () => {
+    const matches = filterCommitsByAuthor(commits, "Grace");
+
+    expect(matches.map((entry) => entry.commitId)).toEqual(["c4", "c2"]);
+  });
+
+  it("keeps every commit when no author is selected", () => {
+    expect(filterCommitsByAuthor(commits, "")).toEqual(commits);
+  });
+});
+
+describe("filterHistoryCommits", () => {
+  it("combines the author filter with the text query", () => {
+    const matches = filterHistoryCommits(commits, "parser", "Grace");
+
+    expect(matches.map((entry) => entry.commitId)).toEqual(["c4"]);
+  });
+});
@@ -0,0 +1,33 @@
+import type { HistoryCommit } from "./types";
+
+export function historyAuthors(commits: readonly HistoryCommit[]): string[] {
+  const authors = new Set(commits.map((commit) => commit.author).filter(Boolean));
+  return [...authors].sort((left, right) => left.localeCompare(right));
+}
+
+export function filterCommitsByAuthor(
+  commits: readonly HistoryCommit[],
+  author: string,
+): HistoryCommit[] {
+  if (!author) return [...commits];
+  return commits.filter((commit) => commit.author === author);
+}
+
+export function filterHistoryCommits(
+  commits: readonly HistoryCommit[],
+  filter: string,
+  author: string,
+): HistoryCommit[] {
+  const query = filter.trim().toLowerCase();
+  const authorCommits = filterCommitsByAuthor(commits, author);
+  if (!query) return authorCommits;
+
+  return authorCommits.filter((commit) => {
+    return (
+      commit.summary.toLowerCase().includes(query) ||
+      commit.shortId.toLowerCase().includes(query) ||
+      commit.commitId.toLowerCase().includes(query) ||
+      commit.author.toLowerCase().includes(query)
+    );
+  });
+}
@@ -0,0 +1,34 @@
+import { describe, expect, it } from "vitest";
+
+import { cumulativeBaseFor } from "./historyRange";
+import type { HistoryCommit } from "./types";
+
+function commit(commitId: string, parentIds: string[]): HistoryCommit {
+  return {
+    commitId,
+    shortId: commitId.slice(0, 7),
+    summary: commitId,
+    author: "Dev",
+    relativeTime: "now",
+    parentIds,
+  };
+}
+
+const commits = [commit("c3", ["c2"]), commit("c2", ["c1"]), commit("c1", ["c0"])];
+
+describe("cumulativeBaseFor", () => {
+  it("diffs commits at or above the range start against the start's parent", () => {
+    expect(cumulativeBaseFor(commits, "c1", "c3")).toBe("c0");
+    expect(cumulativeBaseFor(commits, "c1", "c1")).toBe("c0");
+  });
+
+  it("falls back to the parent diff outside the range", () => {
+    expect(cumulativeBaseFor(commits, "c2", "c1")).toBeNull();
+    expect(cumulativeBaseFor(commits, "", "c3")).toBeNull();
+    expect(cumulativeBaseFor(commits, "missing", "c3")).toBeNull();
+  });
+
+  it("has no base when the range starts at a root commit", () => {
+    expect(cumulativeBaseFor([commit("root", [])], "root", "root")).toBeNull();
+  });
+});
@@ -0,0 +1,17 @@
+import type { HistoryCommit } from "./types";
+
+// History is listed newest first, so commits at or above the range start build on it and
+// can be diffed against the start's first parent to show everything the range has changed.
+export function cumulativeBaseFor(
+  commits: readonly HistoryCommit[],
+  rangeStartId: string,
+  commitId: string,
+): string | null {
+  if (!rangeStartId || !commitId) return null;
+
+  const startIndex = commits.findIndex((commit) => commit.commitId === rangeStartId);
+  const commitIndex = commits.findIndex((commit) => commit.commitId === commitId);
+  if (startIndex === -1 || commitIndex === -1 || commitIndex > startIndex) return null;
+
+  return commits[startIndex]?.parentIds?.[0] ?? null;
+}
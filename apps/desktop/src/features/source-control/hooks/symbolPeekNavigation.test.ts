@@ -9,8 +9,12 @@ function createState(overrides: Partial<RootState["sourceControl"]>): RootState
       repos: [],
       activeRepo: "/repo",
       recentRepos: [],
+      unavailableRepos: [],
       historyFilter: "",
+      historyAuthorFilter: "",
       historyCommitId: "",
+      historyParentIndex: 0,
+      historyRangeStartId: "",
       historyNavTarget: "commits",
       collapseStaged: false,
       collapseUnstaged: false,
@@ -19,6 +23,9 @@ function createState(overrides: Partial<RootState["sourceControl"]>): RootState
       activePath: "",
       repoTreeActivePath: "",
       diffStyle: "split",
+      diffWrap: false,
+      diffChangesOnly: false,
+      diffClusterPreview: false,
       commitMessage: "",
       lastCommitId: "",
       runningAction: "",
@@ -30,9 +37,13 @@ function createState(overrides: Partial<RootState["sourceControl"]>): RootState
       diffFocusTarget: null,
       fileViewerTarget: null,
       symbolPeek: null,
+      lastViewedFileHashes: null,
+      newSinceLastView: [],
+      pinnedPaths: {},
       ...overrides,
     },
     comments: [],
+    reviewedFiles: {},
     hostedReposApi: {} as RootState["hostedReposApi"],
     hostedRepos: {
       selectedProviderId: null,
@@ -9,8 +9,10 @@ import {
   discardChangesGroupAction,
   rangeSelectFile,
   selectFile,
+  stageDirectoryAction,
   stageFilesAction,
   stageOrUnstageSelectionAction,
+  unstageDirectoryAction,
   unstageFilesAction,
 } from "@/features/source-control/actions";
 import {
@@ -22,6 +24,7 @@ import {
   scrollPierreFileTreeBucketedFileIntoView,
 } from "@/features/source-control/pierreFileTreeNavigation";
 import { getUnifiedChangeDirectoryContext } from "@/features/source-control/components/changesUnifiedPierreTree";
+import { toggledBucketFile } from "@/features/source-control/bucketToggle";
 import type { Bucket, BucketedFile, FileItem } from "@/features/source-control/types";
 import { isTypingTarget } from "@/features/source-control/utils";
 import {
@@ -33,6 +36,9 @@ import { getWrappedNavigationIndex } from "@/lib/keyboard-navigation";
 import { SOURCE_CONTROL_HOTKEY_OPTIONS, useVerticalNavigationHotkeys } from "./keyboardNavigation";
 import { getNextSymbolPeekIndex } from "./symbolPeekNavigation";
 
+const STAGED_VIEW_TOGGLE_REGIONS =
+  '[data-nav-region="changes-files"], [data-nav-region="changes-diff"]';
+
 function toBucketedFile(file: FileItem, bucket: Bucket) {
   return {
     path: file.path,
@@ -204,11 +210,18 @@ export function useChangesKeyboardNav(mode: "changes" | "files") {
       );
       if (directoryContext && directoryContext.rows.length > 0) {
         event.preventDefault();
-        void dispatch(
-          directoryContext.sectionKey === "staged"
-            ? unstageFilesAction(directoryContext.rows)
-            : stageFilesAction(directoryContext.rows),
-        );
+        const { directoryPath, isRoot, rows, sectionKey } = directoryContext;
+        if (isRoot || sectionKey === "conflicts") {
+          void dispatch(
+            sectionKey === "staged" ? unstageFilesAction(rows) : stageFilesAction(rows),
+          );
+        } else {
+          void dispatch(
+            sectionKey === "staged"
+              ? unstageDirectoryAction(directoryPath)
+              : stageDirectoryAction(directoryPath),
+          );
+        }
         return;
       }
     }
@@ -261,6 +274,28 @@ export function useChangesKeyboardNav(mode: "changes" | "files") {
     void dispatch(discardChangesGroupAction(discardTargets));
   };
 
+  const toggleStagedView = (event: KeyboardEvent) => {
+    if (isTypingTarget(event.target)) return;
+    // Tab keeps moving focus everywhere but the file tree and the diff, and in dialogs and menus
+    // opened over them.
+    if (
+      !(event.target instanceof Element) ||
+      !event.target.closest(STAGED_VIEW_TOGGLE_REGIONS) ||
+      event.target.closest('[role="dialog"], [role="menu"]')
+    ) {
+      return;
+    }
+    if (mode !== "changes") return;
+
+    const { activeBucket, activePath, snapshot } = getNavigationData();
+    const target = toggledBucketFile(snapshot, activeBucket, activePath);
+    if (!target) return;
+
+    event.preventDefault();
+    scrollPierreFileTreeBucketedFileIntoView("changes-files", target.bucket, target.path);
+    void dispatch(selectFile(target.bucket, target.path));
+  };
+
   useVerticalNavigationHotkeys({
     onNext: (event) => navigateChanges(event, true, false),
     onPrevious: (event) => navigateChanges(event, false, false),
@@ -270,4 +305,5 @@ export function useChangesKeyboardNav(mode: "changes" | "files") {
 
   useHotkey("Mod+Enter", stageOrUnstageSelection, SOURCE_CONTROL_HOTKEY_OPTIONS);
   useHotkey("Mod+Escape", discardSelection, SOURCE_CONTROL_HOTKEY_OPTIONS);
+  useHotkey("Tab", toggleStagedView, SOURCE_CONTROL_HOTKEY_OPTIONS);
 }
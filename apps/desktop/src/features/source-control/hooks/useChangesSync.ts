@@ -1,9 +1,13 @@
 import { useEffect } from "react";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
-import { useGetGitSnapshotQuery } from "@/features/source-control/api";
+import {
+  useGetChangedFileHashesQuery,
+  useGetGitSnapshotQuery,
+} from "@/features/source-control/api";
 import {
   clearDiffSelection,
+  recordChangedFileHashes,
   setActiveBucket,
   setSelectedFiles,
   setSelectionAnchor,
@@ -19,6 +23,16 @@ export function useChangesSync() {
   const selectionAnchor = useAppSelector((state) => state.sourceControl.selectionAnchor);
   const { data: snapshotData } = useGetGitSnapshotQuery(activeRepo, { skip: !activeRepo });
   const snapshot = activeRepo ? snapshotData : undefined;
+  const { data: changedFileHashes } = useGetChangedFileHashesQuery(activeRepo, {
+    skip: !activeRepo,
+    refetchOnFocus: true,
+    refetchOnReconnect: true,
+  });
+
+  useEffect(() => {
+    if (!activeRepo || !changedFileHashes) return;
+    dispatch(recordChangedFileHashes(changedFileHashes));
+  }, [activeRepo, changedFileHashes, dispatch]);
 
   useEffect(() => {
     if (!activeRepo) {
@@ -0,0 +1,12 @@
+import { skipToken } from "@reduxjs/toolkit/query";
+
+import { useAppSelector } from "@/app/hooks";
+
+// History pages are cached per page size, so every reader has to ask with the same args.
+export function useCommitHistoryArgs() {
+  const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
+  const pageSize = useAppSelector(
+    (state) => state.settings.appSettings.sourceControl.historyPageSize,
+  );
+  return activeRepo ? { repoPath: activeRepo, pageSize } : skipToken;
+}
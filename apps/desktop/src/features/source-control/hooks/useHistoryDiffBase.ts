@@ -0,0 +1,23 @@
+import { skipToken } from "@reduxjs/toolkit/query";
+
+import { useAppSelector } from "@/app/hooks";
+import {
+  loadedHistoryCommits,
+  useGetCommitHistoryInfiniteQuery,
+} from "@/features/source-control/api";
+import { useCommitHistoryArgs } from "@/features/source-control/hooks/useCommitHistoryArgs";
+import { cumulativeBaseFor } from "@/features/source-control/historyRange";
+
+// The base the selected history commit is diffed against when a cumulative range is active.
+export function useHistoryDiffBase(): string | undefined {
+  const historyCommitId = useAppSelector((state) => state.sourceControl.historyCommitId);
+  const historyRangeStartId = useAppSelector((state) => state.sourceControl.historyRangeStartId);
+  const historyArgs = useCommitHistoryArgs();
+  const { data: historyPages } = useGetCommitHistoryInfiniteQuery(
+    historyRangeStartId ? historyArgs : skipToken,
+  );
+
+  if (!historyRangeStartId) return undefined;
+  const commits = loadedHistoryCommits(historyPages);
+  return cumulativeBaseFor(commits, historyRangeStartId, historyCommitId) ?? undefined;
+}
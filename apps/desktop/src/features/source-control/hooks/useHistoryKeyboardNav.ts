@@ -3,9 +3,10 @@ import { useStore } from "react-redux";
 
 import { useAppDispatch } from "@/app/hooks";
 import type { RootState } from "@/app/store";
-import { gitApi } from "@/features/source-control/api";
+import { gitApi, loadedHistoryCommits } from "@/features/source-control/api";
 import { selectHistoryCommit, selectHistoryFile } from "@/features/source-control/actions";
 import { HISTORY_FILTER_INPUT_ID } from "@/features/source-control/constants";
+import { filterHistoryCommits } from "@/features/source-control/historyFilter";
 import {
   movePierreFileTreeFocus,
   movePierreFileTreeFocusFile,
@@ -16,7 +17,6 @@ import {
   setHistoryNavTarget,
   setSymbolPeekActiveIndex,
 } from "@/features/source-control/sourceControlSlice";
-import type { HistoryCommit } from "@/features/source-control/types";
 import { isTypingTarget } from "@/features/source-control/utils";
 import {
   focusKeyboardNavItem,
@@ -36,11 +36,20 @@ export function useHistoryKeyboardNav() {
 
   const getNavigationData = () => {
     const state = store.getState();
-    const { historyCommitId, historyNavTarget, historyFilter, activePath, activeRepo } =
-      state.sourceControl;
-    const fileBrowserMode = state.settings.appSettings.sourceControl.fileTreeRenderMode;
-    const historyCommitsArgs = activeRepo ? { repoPath: activeRepo } : null;
-    const historyCommits = historyCommitsArgs
+    const {
+      historyCommitId,
+      historyNavTarget,
+      historyFilter,
+      historyAuthorFilter,
+      activePath,
+      activeRepo,
+    } = state.sourceControl;
+    const { fileTreeRenderMode: fileBrowserMode, historyPageSize } =
+      state.settings.appSettings.sourceControl;
+    const historyCommitsArgs = activeRepo
+      ? { repoPath: activeRepo, pageSize: historyPageSize }
+      : null;
+    const historyPages = historyCommitsArgs
       ? gitApi.endpoints.getCommitHistory.select(historyCommitsArgs)(state).data
       : undefined;
 
@@ -48,9 +57,10 @@ export function useHistoryKeyboardNav() {
       historyCommitId,
       historyNavTarget,
       historyFilter,
+      historyAuthorFilter,
       fileBrowserMode,
       activePath,
-      allHistoryCommits: (historyCommits ?? []) as HistoryCommit[],
+      allHistoryCommits: loadedHistoryCommits(historyPages),
     };
   };
 
@@ -66,8 +76,14 @@ export function useHistoryKeyboardNav() {
 
     event.preventDefault();
 
-    const { historyCommitId, historyNavTarget, historyFilter, fileBrowserMode, allHistoryCommits } =
-      getNavigationData();
+    const {
+      historyCommitId,
+      historyNavTarget,
+      historyFilter,
+      historyAuthorFilter,
+      fileBrowserMode,
+      allHistoryCommits,
+    } = getNavigationData();
 
     if (historyNavTarget === "files") {
       if (fileBrowserMode === "tree") {
@@ -81,17 +97,11 @@ export function useHistoryKeyboardNav() {
       return;
     }
 
-    const query = historyFilter.trim().toLowerCase();
-    const filteredHistoryCommits = !query
-      ? allHistoryCommits
-      : allHistoryCommits.filter((commit) => {
-          return (
-            commit.summary.toLowerCase().includes(query) ||
-            commit.shortId.toLowerCase().includes(query) ||
-            commit.commitId.toLowerCase().includes(query) ||
-            commit.author.toLowerCase().includes(query)
-          );
-        });
+    const filteredHistoryCommits = filterHistoryCommits(
+      allHistoryCommits,
+      historyFilter,
+      historyAuthorFilter,
+    );
 
     if (filteredHistoryCommits.length === 0) return;
 
@@ -112,18 +122,8 @@ export function useHistoryKeyboardNav() {
   };
 
   const getFilteredHistoryCommits = () => {
-    const { historyFilter, allHistoryCommits } = getNavigationData();
-    const query = historyFilter.trim().toLowerCase();
-    return !query
-      ? allHistoryCommits
-      : allHistoryCommits.filter((commit) => {
-          return (
-            commit.summary.toLowerCase().includes(query) ||
-            commit.shortId.toLowerCase().includes(query) ||
-            commit.commitId.toLowerCase().includes(query) ||
-            commit.author.toLowerCase().includes(query)
-          );
-        });
+    const { historyFilter, historyAuthorFilter, allHistoryCommits } = getNavigationData();
+    return filterHistoryCommits(allHistoryCommits, historyFilter, historyAuthorFilter);
   };
 
   const focusHistoryCommitList = () => {
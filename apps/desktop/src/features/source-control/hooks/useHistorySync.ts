@@ -2,7 +2,13 @@ import { useEffect } from "react";
 import { skipToken } from "@reduxjs/toolkit/query";
 
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
-import { useGetCommitFilesQuery, useGetCommitHistoryQuery } from "@/features/source-control/api";
+import {
+  loadedHistoryCommits,
+  useGetCommitFilesQuery,
+  useGetCommitHistoryInfiniteQuery,
+} from "@/features/source-control/api";
+import { useCommitHistoryArgs } from "@/features/source-control/hooks/useCommitHistoryArgs";
+import { useHistoryDiffBase } from "@/features/source-control/hooks/useHistoryDiffBase";
 import {
   clearHistorySelection,
   setActivePath,
@@ -13,14 +19,22 @@ export function useHistorySync() {
   const dispatch = useAppDispatch();
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const historyCommitId = useAppSelector((state) => state.sourceControl.historyCommitId);
+  const historyParentIndex = useAppSelector((state) => state.sourceControl.historyParentIndex);
+  const historyDiffBase = useHistoryDiffBase();
   const activePath = useAppSelector((state) => state.sourceControl.activePath);
 
-  const { data: historyCommits } = useGetCommitHistoryQuery(
-    activeRepo ? { repoPath: activeRepo } : skipToken,
-  );
+  const { data: historyPages } = useGetCommitHistoryInfiniteQuery(useCommitHistoryArgs());
+  const historyCommits = historyPages ? loadedHistoryCommits(historyPages) : undefined;
 
   const { data: historyFiles } = useGetCommitFilesQuery(
-    activeRepo && historyCommitId ? { repoPath: activeRepo, commitId: historyCommitId } : skipToken,
+    activeRepo && historyCommitId
+      ? {
+          repoPath: activeRepo,
+          commitId: historyCommitId,
+          parentIndex: historyParentIndex,
+          baseRef: historyDiffBase,
+        }
+      : skipToken,
   );
 
   useEffect(() => {
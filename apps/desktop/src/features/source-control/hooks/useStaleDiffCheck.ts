@@ -0,0 +1,37 @@
+import { useEffect, useState } from "react";
+
+import { useGetChangedFileHashesQuery } from "@/features/source-control/api";
+import { isFileStale } from "@/features/source-control/snapshotDelta";
+
+type DisplayedDiff = {
+  versions: unknown;
+  hash: string | undefined;
+};
+
+// Remembers the worktree hash of the file when its diff was loaded. When a later refresh reports a
+// different hash while the diff itself hasn't been refetched, the view is stale.
+export function useStaleDiffCheck(
+  repoPath: string,
+  relPath: string,
+  versions: unknown,
+  enabled: boolean,
+) {
+  const { data: changedFileHashes } = useGetChangedFileHashesQuery(repoPath, {
+    skip: !repoPath,
+  });
+  const currentHash = changedFileHashes?.[relPath];
+  const [displayed, setDisplayed] = useState<DisplayedDiff | null>(null);
+
+  useEffect(() => {
+    if (!enabled || !versions || !changedFileHashes) return;
+
+    setDisplayed((previous) =>
+      previous?.versions === versions && previous.hash !== undefined
+        ? previous
+        : { versions, hash: currentHash },
+    );
+  }, [changedFileHashes, currentHash, enabled, versions]);
+
+  if (!enabled || !versions || displayed?.versions !== versions) return false;
+  return isFileStale(displayed.hash, currentHash);
+}
@@ -0,0 +1,187 @@
+import { parseDiffFromFile } from "@pierre/diffs";
+import { describe, expect, it } from "vitest";
+
+import {
+  buildIndexContentsForHunkOperation,
+  buildIndexContentsForLineOperation,
+  formatFilePatch,
+  formatHunkPatch,
+  unstagesEntireFile,
+} from "./hunkOperations";
+
+const headLines = Array.from({ length: 12 }, (_, index) => `line ${index + 1}\n`);
+
+function stagedDiff(indexLines: string[]) {
+  return parseDiffFromFile(
+    { name: "notes.txt", contents: headLines.join("") },
+    { name: "notes.txt", contents: indexLines.join("") },
+  );
+}
+
+describe("buildIndexContentsForHunkOperation", () => {
+  it("unstages one hunk of a fully staged file and keeps the other", () => {
+    const indexLines = [...headLines];
+    indexLines[0] = "first change\n";
+    indexLines[11] = "last change\n";
+    const fileDiff = stagedDiff(indexLines);
+
+    expect(fileDiff.hunks).toHaveLength(2);
+
+    const expectedLines = [...headLines];
+    expectedLines[11] = "last change\n";
+    expect(
+      buildIndexContentsForHunkOperation({ fileDiff, hunkIndex: 0, operation: "unstage" }),
+    ).toBe(expectedLines.join(""));
+    expect(unstagesEntireFile(fileDiff, "unstage")).toBe(false);
+  });
+
+  it("treats unstaging the only staged hunk as a full unstage", () => {
+    const indexLines = [...headLines];
+    indexLines[5] = "only change\n";
+    const fileDiff = stagedDiff(indexLines);
+
+    expect(unstagesEntireFile(fileDiff, "unstage")).toBe(true);
+    expect(unstagesEntireFile(fileDiff, "stage")).toBe(false);
+  });
+});
+
+describe("buildIndexContentsForLineOperation", () => {
+  const worktreeLines = [...headLines];
+  worktreeLines[1] = "second change\n";
+  worktreeLines[10] = "eleventh change\n";
+  const fileDiff = stagedDiff(worktreeLines);
+
+  it("stages only a selected removed line of a modified file", () => {
+    const expectedLines = headLines.filter((_, index) => index !== 1);
+
+    expect(
+      buildIndexContentsForLineOperation({
+        fileDiff,
+        range: { start: 2, end: 2, side: "deletions" },
+        operation: "stage",
+      }),
+    ).toBe(expectedLines.join(""));
+  });
+
+  it("stages a selection that spans two hunks", () => {
+    expect(fileDiff.hunks).toHaveLength(2);
+
+    expect(
+      buildIndexContentsForLineOperation({
+        fileDiff,
+        range: { start: 2, end: 11, side: "deletions", endSide: "additions" },
+        operation: "stage",
+      }),
+    ).toBe(worktreeLines.join(""));
+  });
+
+  it("unstages only the selected added line", () => {
+    const expectedLines = [...worktreeLines];
+    expectedLines.splice(10, 1);
+
+    expect(
+      buildIndexContentsForLineOperation({
+        fileDiff,
+        range: { start: 11, end: 11, side: "additions" },
+        operation: "unstage",
+      }),
+    ).toBe(expectedLines.join(""));
+  });
+});
+
+describe("formatHunkPatch", () => {
+  it("writes one hunk of a modified file with its blob ids", () => {
+    const changed = [...headLines];
+    changed[0] = "first change\n";
+    changed[11] = "last change";
+    const fileDiff = stagedDiff(changed);
+
+    expect(fileDiff.hunks).toHaveLength(2);
+    expect(formatHunkPatch(fileDiff, 1)).toBe(
+      [
+        "diff --git a/notes.txt b/notes.txt",
+        "index 624b469..4b0ef73 100644",
+        "--- a/notes.txt",
+        "+++ b/notes.txt",
+        "@@ -9,4 +9,4 @@",
+        " line 9",
+        " line 10",
+        " line 11",
+        "-line 12",
+        "+last change",
+        "\\ No newline at end of file",
+        "",
+      ].join("\n"),
+    );
+    expect(formatHunkPatch(fileDiff, 2)).toBeNull();
+  });
+
+  it("writes a new file against /dev/null", () => {
+    const fileDiff = parseDiffFromFile(
+      { name: "new.txt", contents: "" },
+      { name: "new.txt", contents: "a\nb\n" },
+    );
+
+    expect(formatHunkPatch(fileDiff, 0, "added")).toBe(
+      [
+        "diff --git a/new.txt b/new.txt",
+        "new file mode 100644",
+        "index 0000000..422c2b7",
+        "--- /dev/null",
+        "+++ b/new.txt",
+        "@@ -0,0 +1,2 @@",
+        "+a",
+        "+b",
+        "",
+      ].join("\n"),
+    );
+  });
+});
+
+describe("formatFilePatch", () => {
+  function headerOf(patch: string) {
+    const lines = patch.split("\n");
+    return lines.slice(0, lines.indexOf("--- a/notes.txt"));
+  }
+
+  it("keeps the mode of an executable file in the index line", () => {
+    const changed = [...headLines];
+    changed[0] = "first change\n";
+    const patch = formatFilePatch(stagedDiff(changed), "modified", undefined, {
+      oldMode: "100755",
+      newMode: "100755",
+    });
+
+    expect(headerOf(patch)).toEqual([
+      "diff --git a/notes.txt b/notes.txt",
+      expect.stringMatching(/^index [0-9a-f]{7}\.\.[0-9a-f]{7} 100755$/),
+    ]);
+  });
+
+  it("writes a mode change as old and new mode lines", () => {
+    const changed = [...headLines];
+    changed[0] = "first change\n";
+    const patch = formatFilePatch(stagedDiff(changed), "modified", undefined, {
+      oldMode: "100644",
+      newMode: "100755",
+    });
+
+    expect(headerOf(patch)).toEqual([
+      "diff --git a/notes.txt b/notes.txt",
+      "old mode 100644",
+      "new mode 100755",
+      expect.stringMatching(/^index [0-9a-f]{7}\.\.[0-9a-f]{7}$/),
+    ]);
+  });
+
+  it("writes the mode of an added symlink", () => {
+    const fileDiff = parseDiffFromFile(
+      { name: "link", contents: "" },
+      { name: "link", contents: "target" },
+    );
+
+    expect(formatFilePatch(fileDiff, "added", undefined, { newMode: "120000" })).toContain(
+      "new file mode 120000\n",
+    );
+  });
+});
@@ -1,7 +1,19 @@
 import { diffAcceptRejectHunk, type FileDiffMetadata } from "@pierre/diffs";
 
+import {
+  formatUnifiedDiff,
+  type UnifiedDiffFile,
+  type UnifiedDiffHunk,
+  type UnifiedDiffLine,
+} from "@/features/diff-view/services/unifiedDiff";
+import { gitBlobId } from "@/features/source-control/gitObjectId";
+import type { SelectionRange } from "@/features/source-control/types";
+
 export type DiffHunkOperation = "stage" | "unstage" | "discard";
 
+// Copying never touches the index, so it is offered on every diff, read-only ones included.
+export type DiffHunkAction = DiffHunkOperation | "copy";
+
 export type DiffHunkActionPayload = {
   fileDiff: FileDiffMetadata;
   hunkIndex: number;
@@ -9,14 +21,31 @@ export type DiffHunkActionPayload = {
 
 export type DiffHunkActionAnnotation = DiffHunkActionPayload & {
   type: "hunk-action";
+  operations: DiffHunkAction[];
+  onAction: (operation: DiffHunkAction, payload: DiffHunkActionPayload) => void;
+};
+
+export type DiffLinesActionPayload = {
+  fileDiff: FileDiffMetadata;
+  range: SelectionRange;
+};
+
+export type DiffLinesActionAnnotation = DiffLinesActionPayload & {
+  type: "line-action";
   operations: DiffHunkOperation[];
-  onAction: (operation: DiffHunkOperation, payload: DiffHunkActionPayload) => void;
+  onAction: (operation: DiffHunkOperation, payload: DiffLinesActionPayload) => void;
 };
 
 function contentsFromAdditionLines(diff: FileDiffMetadata) {
   return diff.additionLines.join("");
 }
 
+// Unstaging the last staged hunk resets the index entry to HEAD instead of writing a blob, so
+// newly added files drop out of the index rather than lingering as empty files.
+export function unstagesEntireFile(fileDiff: FileDiffMetadata, operation: DiffHunkOperation) {
+  return operation === "unstage" && fileDiff.hunks.length <= 1;
+}
+
 export function buildIndexContentsForHunkOperation({
   fileDiff,
   hunkIndex,
@@ -37,3 +66,274 @@ export function buildIndexContentsForHunkOperation({
 
   return contentsFromAdditionLines(nextDiff);
 }
+
+type DiffRow = {
+  kind: "context" | "deletion" | "addition";
+  deletionLine: number | null;
+  additionLine: number | null;
+};
+
+// An empty side starts after the given line in git's convention, so its first index is `start`.
+function firstLineIndex(start: number, count: number) {
+  return count === 0 ? start : start - 1;
+}
+
+function diffRows(fileDiff: FileDiffMetadata): DiffRow[] {
+  const rows: DiffRow[] = [];
+
+  for (const hunk of fileDiff.hunks) {
+    let deletionLine = firstLineIndex(hunk.deletionStart, hunk.deletionCount) + 1;
+    let additionLine = firstLineIndex(hunk.additionStart, hunk.additionCount) + 1;
+
+    for (const content of hunk.hunkContent) {
+      if (content.type === "context") {
+        for (let index = 0; index < content.lines; index += 1) {
+          rows.push({ kind: "context", deletionLine, additionLine });
+          deletionLine += 1;
+          additionLine += 1;
+        }
+        continue;
+      }
+
+      for (let index = 0; index < content.deletions; index += 1) {
+        rows.push({ kind: "deletion", deletionLine, additionLine: null });
+        deletionLine += 1;
+      }
+      for (let index = 0; index < content.additions; index += 1) {
+        rows.push({ kind: "addition", deletionLine: null, additionLine });
+        additionLine += 1;
+      }
+    }
+  }
+
+  return rows;
+}
+
+function rowLine(row: DiffRow, side: "deletions" | "additions") {
+  return side === "deletions" ? row.deletionLine : row.additionLine;
+}
+
+// The selected rows in diff order, from the first row at or after the earlier endpoint to the
+// last row at or before the later one, so endpoints in unchanged gaps still bound the selection.
+function selectedRowRange(rows: DiffRow[], range: SelectionRange) {
+  const side = range.side ?? "additions";
+  const endSide = range.endSide ?? side;
+  const firstAtOrAfter = (lineNumber: number, lineSide: typeof side) => {
+    const index = rows.findIndex((row) => (rowLine(row, lineSide) ?? -1) >= lineNumber);
+    return index === -1 ? rows.length : index;
+  };
+  const lastAtOrBefore = (lineNumber: number, lineSide: typeof side) =>
+    rows.findLastIndex((row) => {
+      const line = rowLine(row, lineSide);
+      return line !== null && line <= lineNumber;
+    });
+
+  return {
+    from: Math.min(firstAtOrAfter(range.start, side), firstAtOrAfter(range.end, endSide)),
+    to: Math.max(lastAtOrBefore(range.start, side), lastAtOrBefore(range.end, endSide)),
+  };
+}
+
+// Changed line numbers on each side that fall inside a line selection. A selection that stays on
+// one side, like a drag down one column of the split view, only picks that side's changes.
+export function selectedChangeLines(fileDiff: FileDiffMetadata, range: SelectionRange) {
+  const rows = diffRows(fileDiff);
+  const side = range.side ?? "additions";
+  const singleSide = side === (range.endSide ?? side) ? side : null;
+  const { from, to } = selectedRowRange(rows, range);
+  const deletions = new Set<number>();
+  const additions = new Set<number>();
+
+  for (let index = Math.max(from, 0); index <= to && index < rows.length; index += 1) {
+    const row = rows[index]!;
+    if (row.kind === "deletion" && singleSide !== "additions") {
+      deletions.add(row.deletionLine!);
+    } else if (row.kind === "addition" && singleSide !== "deletions") {
+      additions.add(row.additionLine!);
+    }
+  }
+
+  return { deletions, additions };
+}
+
+// Staging starts from the old side (the index) and applies only the selected changes; unstaging
+// and discarding start from the new side and revert only the selected ones. Lines outside the
+// hunks are the same on both sides and are copied from the side being rebuilt.
+export function buildIndexContentsForLineOperation({
+  fileDiff,
+  range,
+  operation,
+}: {
+  fileDiff: FileDiffMetadata;
+  range: SelectionRange;
+  operation: DiffHunkOperation;
+}) {
+  const selected = selectedChangeLines(fileDiff, range);
+  const applying = operation === "stage";
+  const baseLines = applying ? fileDiff.deletionLines : fileDiff.additionLines;
+  const output: string[] = [];
+  let cursor = 0;
+
+  for (const hunk of fileDiff.hunks) {
+    let deletionIndex = firstLineIndex(hunk.deletionStart, hunk.deletionCount);
+    let additionIndex = firstLineIndex(hunk.additionStart, hunk.additionCount);
+    output.push(...baseLines.slice(cursor, applying ? deletionIndex : additionIndex));
+
+    for (const content of hunk.hunkContent) {
+      if (content.type === "context") {
+        output.push(...fileDiff.additionLines.slice(additionIndex, additionIndex + content.lines));
+        deletionIndex += content.lines;
+        additionIndex += content.lines;
+        continue;
+      }
+
+      for (let index = 0; index < content.deletions; index += 1) {
+        if (selected.deletions.has(deletionIndex + 1) !== applying) {
+          output.push(fileDiff.deletionLines[deletionIndex] ?? "");
+        }
+        deletionIndex += 1;
+      }
+      for (let index = 0; index < content.additions; index += 1) {
+        if (selected.additions.has(additionIndex + 1) === applying) {
+          output.push(fileDiff.additionLines[additionIndex] ?? "");
+        }
+        additionIndex += 1;
+      }
+    }
+
+    cursor = applying ? deletionIndex : additionIndex;
+  }
+
+  output.push(...baseLines.slice(cursor));
+  return output.join("");
+}
+
+export type PatchFileChange = "modified" | "added" | "deleted";
+
+// Git file modes of each side, as `getFileVersions` reports them. A missing mode is a regular
+// file, and a missing new mode is the old one unchanged.
+export type PatchFileModes = { oldMode?: string | null; newMode?: string | null };
+
+const REGULAR_FILE_MODE = "100644";
+
+function unifiedLine(
+  kind: UnifiedDiffLine["kind"],
+  text: string | undefined,
+  oldLineNumber: number | null,
+  newLineNumber: number | null,
+): UnifiedDiffLine {
+  const line = text ?? "";
+  const noNewlineAtEof = !line.endsWith("\n");
+  return {
+    kind,
+    content: noNewlineAtEof ? line : line.slice(0, -1),
+    oldLineNumber,
+    newLineNumber,
+    noNewlineAtEof,
+  };
+}
+
+function abbreviatedBlobId(lines: string[] | null) {
+  return lines ? gitBlobId(lines.join("")).slice(0, 7) : "0000000";
+}
+
+function unifiedHunk(
+  fileDiff: FileDiffMetadata,
+  hunkIndex: number,
+  change: PatchFileChange,
+): UnifiedDiffHunk {
+  const hunk = fileDiff.hunks[hunkIndex]!;
+  let deletionLine = firstLineIndex(hunk.deletionStart, hunk.deletionCount) + 1;
+  let additionLine = firstLineIndex(hunk.additionStart, hunk.additionCount) + 1;
+  const lines: UnifiedDiffLine[] = [];
+
+  for (const content of hunk.hunkContent) {
+    if (content.type === "context") {
+      for (let index = 0; index < content.lines; index += 1) {
+        const text = fileDiff.additionLines[additionLine - 1];
+        lines.push(unifiedLine("context", text, deletionLine, additionLine));
+        deletionLine += 1;
+        additionLine += 1;
+      }
+      continue;
+    }
+
+    for (let index = 0; index < content.deletions; index += 1) {
+      const text = fileDiff.deletionLines[deletionLine - 1];
+      lines.push(unifiedLine("remove", text, deletionLine, null));
+      deletionLine += 1;
+    }
+    for (let index = 0; index < content.additions; index += 1) {
+      const text = fileDiff.additionLines[additionLine - 1];
+      lines.push(unifiedLine("add", text, null, additionLine));
+      additionLine += 1;
+    }
+  }
+
+  return {
+    // A side that does not exist is `0,0`; an empty side of an existing file keeps its start,
+    // the line the other side's lines go after.
+    oldStart: change === "added" ? 0 : hunk.deletionStart,
+    oldLines: hunk.deletionCount,
+    newStart: change === "deleted" ? 0 : hunk.additionStart,
+    newLines: hunk.additionCount,
+    section: "",
+    lines,
+    noNewlineOld: lines.some((line) => line.kind !== "add" && line.noNewlineAtEof),
+    noNewlineNew: lines.some((line) => line.kind !== "remove" && line.noNewlineAtEof),
+  };
+}
+
+// A standalone patch for some of a file's hunks, with the headers `git apply` needs to place
+// them: `/dev/null` and a file mode for a file that is added or deleted, and the blob ids either
+// way. Without `hunkIndexes` every hunk is included.
+export function formatFilePatch(
+  fileDiff: FileDiffMetadata,
+  change: PatchFileChange = "modified",
+  hunkIndexes: readonly number[] = fileDiff.hunks.map((_, index) => index),
+  modes: PatchFileModes = {},
+): string {
+  const oldPath = fileDiff.prevName ?? fileDiff.name;
+  const newPath = fileDiff.name;
+  const oldMode = modes.oldMode ?? REGULAR_FILE_MODE;
+  const newMode = modes.newMode ?? (change === "added" ? REGULAR_FILE_MODE : oldMode);
+  const oldId = abbreviatedBlobId(change === "added" ? null : fileDiff.deletionLines);
+  const newId = abbreviatedBlobId(change === "deleted" ? null : fileDiff.additionLines);
+  const headerLines = [`diff --git a/${oldPath} b/${newPath}`];
+  if (change === "added") {
+    headerLines.push(`new file mode ${newMode}`, `index ${oldId}..${newId}`);
+  } else if (change === "deleted") {
+    headerLines.push(`deleted file mode ${oldMode}`, `index ${oldId}..${newId}`);
+  } else {
+    if (oldMode !== newMode) headerLines.push(`old mode ${oldMode}`, `new mode ${newMode}`);
+    if (oldPath !== newPath) headerLines.push(`rename from ${oldPath}`, `rename to ${newPath}`);
+    headerLines.push(
+      oldMode === newMode ? `index ${oldId}..${newId} ${oldMode}` : `index ${oldId}..${newId}`,
+    );
+  }
+  headerLines.push(
+    change === "added" ? "--- /dev/null" : `--- a/${oldPath}`,
+    change === "deleted" ? "+++ /dev/null" : `+++ b/${newPath}`,
+  );
+
+  const file: UnifiedDiffFile = {
+    oldPath: change === "added" ? null : oldPath,
+    newPath: change === "deleted" ? null : newPath,
+    headerLines,
+    hunks: hunkIndexes
+      .filter((index) => fileDiff.hunks[index] !== undefined)
+      .map((index) => unifiedHunk(fileDiff, index, change)),
+    isBinary: false,
+  };
+  return formatUnifiedDiff([file]);
+}
+
+export function formatHunkPatch(
+  fileDiff: FileDiffMetadata,
+  hunkIndex: number,
+  change: PatchFileChange = "modified",
+): string | null {
+  if (!fileDiff.hunks[hunkIndex]) return null;
+
+  return formatFilePatch(fileDiff, change, [hunkIndex]);
+}
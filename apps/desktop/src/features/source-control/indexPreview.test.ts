@@ -0,0 +1,54 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+
+const mocks = vi.hoisted(() => ({
+  confirm: vi.fn(),
+  previewStageAll: vi.fn(),
+  previewUnstageAll: vi.fn(),
+  toastError: vi.fn(),
+}));
+
+vi.mock("@/platform/desktop", () => ({ desktop: { confirm: mocks.confirm } }));
+vi.mock("./services/git", () => ({
+  previewStageAll: mocks.previewStageAll,
+  previewUnstageAll: mocks.previewUnstageAll,
+}));
+vi.mock("sonner", () => ({ toast: Object.assign(vi.fn(), { error: mocks.toastError }) }));
+
+import { confirmBulkIndexOperation, formatIndexPreviewMessage } from "./indexPreview";
+
+describe("index previews", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("asks for confirmation on every preview, however small", async () => {
+    mocks.previewStageAll.mockResolvedValue([{ path: "a.ts", action: "add" }]);
+    mocks.confirm.mockResolvedValue(true);
+
+    await expect(confirmBulkIndexOperation("/repo", "stage")).resolves.toBe(true);
+    expect(mocks.confirm).toHaveBeenCalledWith(
+      "Stage 1 path?\n\n+ a.ts",
+      expect.objectContaining({ title: "Stage All" }),
+    );
+  });
+
+  it("reports a failed preview instead of going ahead", async () => {
+    mocks.previewUnstageAll.mockRejectedValue(new Error("index.lock exists"));
+
+    await expect(confirmBulkIndexOperation("/repo", "unstage")).resolves.toBe(false);
+    expect(mocks.toastError).toHaveBeenCalledWith("Failed to preview unstaging: index.lock exists");
+    expect(mocks.confirm).not.toHaveBeenCalled();
+  });
+
+  it("lists affected paths and summarizes the overflow", () => {
+    const entries = Array.from({ length: 14 }, (_, index) => ({
+      path: `file-${index}.ts`,
+      action: index === 0 ? ("remove" as const) : ("add" as const),
+    }));
+
+    const message = formatIndexPreviewMessage("stage", entries);
+
+    expect(message.startsWith("Stage 14 paths?\n\n− file-0.ts\n+ file-1.ts")).toBe(true);
+    expect(message.endsWith("+ file-11.ts\n…and 2 more")).toBe(true);
+  });
+});
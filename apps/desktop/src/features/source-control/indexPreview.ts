@@ -0,0 +1,66 @@
+import { toast } from "sonner";
+
+import { desktop } from "@/platform/desktop";
+import { previewStageAll, previewUnstageAll } from "./services/git";
+import type { IndexPreviewEntry } from "./types";
+
+const INDEX_PREVIEW_LISTED_PATHS = 12;
+
+export type BulkIndexOperation = "stage" | "unstage";
+
+function previewLinePrefix(entry: IndexPreviewEntry) {
+  if (entry.action === "add") return "+";
+  if (entry.action === "remove") return "−";
+  return "↺";
+}
+
+export function formatIndexPreviewMessage(
+  operation: BulkIndexOperation,
+  entries: ReadonlyArray<IndexPreviewEntry>,
+) {
+  const verb = operation === "stage" ? "Stage" : "Unstage";
+  const lines = entries
+    .slice(0, INDEX_PREVIEW_LISTED_PATHS)
+    .map((entry) => `${previewLinePrefix(entry)} ${entry.path}`);
+  const remaining = entries.length - lines.length;
+  if (remaining > 0) {
+    lines.push(`…and ${remaining} more`);
+  }
+
+  const noun = entries.length === 1 ? "path" : "paths";
+  return `${verb} ${entries.length} ${noun}?\n\n${lines.join("\n")}`;
+}
+
+// Every bulk operation is confirmed against its preview. Without one there is nothing to confirm
+// against, so a failed preview stops the operation and reports why.
+export async function confirmBulkIndexOperation(
+  repoPath: string,
+  operation: BulkIndexOperation,
+): Promise<boolean> {
+  let entries: IndexPreviewEntry[];
+  try {
+    entries =
+      operation === "stage" ? await previewStageAll(repoPath) : await previewUnstageAll(repoPath);
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    toast.error(`Failed to preview ${operation === "stage" ? "staging" : "unstaging"}: ${message}`);
+    return false;
+  }
+
+  if (entries.length === 0) {
+    toast(operation === "stage" ? "Nothing to stage" : "Nothing to unstage");
+    return false;
+  }
+
+  const message = formatIndexPreviewMessage(operation, entries);
+  try {
+    return await desktop.confirm(message, {
+      title: operation === "stage" ? "Stage All" : "Unstage All",
+      kind: "info",
+      okLabel: operation === "stage" ? "Stage" : "Unstage",
+      cancelLabel: "Cancel",
+    });
+  } catch {
+    return window.confirm(message);
+  }
+}
@@ -0,0 +1,22 @@
+import { describe, expect, it } from "vitest";
+
+import { orderPinnedFirst } from "./pinnedFiles";
+
+const files = ["a.ts", "b.ts", "c.ts", "d.ts"].map((path) => ({ path }));
+
+describe("orderPinnedFirst", () => {
+  it("moves a pinned file to the front and keeps the rest in order", () => {
+    expect(orderPinnedFirst(files, ["c.ts"]).map((file) => file.path)).toEqual([
+      "c.ts",
+      "a.ts",
+      "b.ts",
+      "d.ts",
+    ]);
+  });
+
+  it("orders several pins by when they were pinned and ignores missing paths", () => {
+    expect(
+      orderPinnedFirst(files, ["d.ts", "gone.ts", "b.ts"]).map((file) => file.path),
+    ).toEqual(["d.ts", "b.ts", "a.ts", "c.ts"]);
+  });
+});
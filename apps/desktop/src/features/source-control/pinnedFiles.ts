@@ -0,0 +1,15 @@
+// Pinned files lead in pin order; everything else keeps its incoming order.
+export function orderPinnedFirst<TFile extends { path: string }>(
+  files: ReadonlyArray<TFile>,
+  pinnedPaths: ReadonlyArray<string>,
+): TFile[] {
+  if (pinnedPaths.length === 0) return [...files];
+
+  const pinRank = new Map(pinnedPaths.map((path, index) => [path, index]));
+  const pinned = files
+    .filter((file) => pinRank.has(file.path))
+    .toSorted((left, right) => pinRank.get(left.path)! - pinRank.get(right.path)!);
+  const rest = files.filter((file) => !pinRank.has(file.path));
+
+  return [...pinned, ...rest];
+}
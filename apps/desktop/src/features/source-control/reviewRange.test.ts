@@ -0,0 +1,68 @@
+import { describe, expect, it } from "vitest";
+
+import {
+  parseReviewRangeInput,
+  resolveDefaultReviewRange,
+  resolveReviewRangeInput,
+} from "./reviewRange";
+
+const BRANCHES = ["main", "origin/main", "feature"];
+
+describe("resolveDefaultReviewRange", () => {
+  it("seeds the review refs from a configured range", () => {
+    expect(resolveDefaultReviewRange("origin/main..HEAD", BRANCHES, "feature")).toEqual({
+      baseRef: "origin/main",
+      headRef: "feature",
+    });
+    expect(resolveDefaultReviewRange("main..", BRANCHES, "feature")).toEqual({
+      baseRef: "main",
+      headRef: "feature",
+    });
+  });
+
+  it("returns null so the caller falls back when the range does not resolve", () => {
+    expect(resolveDefaultReviewRange(undefined, BRANCHES, "feature")).toBeNull();
+    expect(resolveDefaultReviewRange("upstream/main..HEAD", BRANCHES, "feature")).toBeNull();
+    expect(resolveDefaultReviewRange("main...feature", BRANCHES, "feature")).toBeNull();
+    expect(resolveDefaultReviewRange("main", BRANCHES, "feature")).toBeNull();
+    expect(resolveDefaultReviewRange("main..HEAD", BRANCHES, "")).toBeNull();
+  });
+});
+
+describe("parseReviewRangeInput", () => {
+  it("rejects empty and malformed input with a hint", () => {
+    expect(parseReviewRangeInput("")).toMatchObject({ ok: false });
+    expect(parseReviewRangeInput("   ")).toMatchObject({ ok: false });
+    expect(parseReviewRangeInput("main")).toMatchObject({ ok: false });
+    expect(parseReviewRangeInput("main...feature")).toMatchObject({ ok: false });
+    expect(parseReviewRangeInput("..HEAD")).toMatchObject({ ok: false });
+  });
+
+  it("trims both sides and fills in a missing side", () => {
+    expect(parseReviewRangeInput("  main .. feature ")).toEqual({
+      ok: true,
+      baseRef: "main",
+      headRef: "feature",
+    });
+    expect(parseReviewRangeInput("main..")).toEqual({ ok: true, baseRef: "main", headRef: "HEAD" });
+    expect(parseReviewRangeInput("..HEAD", "origin/main")).toEqual({
+      ok: true,
+      baseRef: "origin/main",
+      headRef: "HEAD",
+    });
+  });
+});
+
+describe("resolveReviewRangeInput", () => {
+  it("maps HEAD to the active branch and names a ref that does not resolve", () => {
+    expect(resolveReviewRangeInput("main..HEAD", BRANCHES, "feature")).toEqual({
+      ok: true,
+      baseRef: "main",
+      headRef: "feature",
+    });
+    expect(resolveReviewRangeInput("upstream/main..", BRANCHES, "feature")).toEqual({
+      ok: false,
+      hint: "Unknown base ref upstream/main.",
+    });
+  });
+});
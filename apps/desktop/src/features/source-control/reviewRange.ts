@@ -0,0 +1,62 @@
+import { isCommitIdRef } from "@/platform/desktop/gitRefs";
+
+export type ReviewRange = {
+  baseRef: string;
+  headRef: string;
+};
+
+export type ReviewRangeInput = ({ ok: true } & ReviewRange) | { ok: false; hint: string };
+
+// Parses git's two-dot `base..head` form, trimming each side. A missing head means `HEAD`, as it
+// does for git; a missing base falls back to `defaultBase`, and is an error without one.
+export function parseReviewRangeInput(input: string, defaultBase = ""): ReviewRangeInput {
+  const trimmed = input.trim();
+  if (!trimmed) return { ok: false, hint: "Enter a range such as main..HEAD." };
+  if (trimmed.includes("...")) {
+    return { ok: false, hint: "Three-dot ranges are not supported. Use base..head." };
+  }
+
+  const separatorIndex = trimmed.indexOf("..");
+  if (separatorIndex < 0) {
+    return { ok: false, hint: "Separate the refs with .., as in main..HEAD." };
+  }
+
+  const baseRef = trimmed.slice(0, separatorIndex).trim() || defaultBase;
+  const headRef = trimmed.slice(separatorIndex + 2).trim() || "HEAD";
+  if (!baseRef) return { ok: false, hint: "Add a base ref before the .., as in main..HEAD." };
+  return { ok: true, baseRef, headRef };
+}
+
+// `HEAD` maps to the checked-out branch so the range tracks whatever branch is being reviewed.
+function resolveRangeRef(ref: string, branches: readonly string[], activeBranch: string) {
+  if (ref === "HEAD") return activeBranch && branches.includes(activeBranch) ? activeBranch : null;
+  if (isCommitIdRef(ref) || branches.includes(ref)) return ref;
+  return null;
+}
+
+// Resolves a range typed into the review sidebar against the known branches and commit ids.
+export function resolveReviewRangeInput(
+  input: string,
+  branches: readonly string[],
+  activeBranch: string,
+  defaultBase = "",
+): ReviewRangeInput {
+  const parsed = parseReviewRangeInput(input, defaultBase);
+  if (!parsed.ok) return parsed;
+
+  const baseRef = resolveRangeRef(parsed.baseRef, branches, activeBranch);
+  if (!baseRef) return { ok: false, hint: `Unknown base ref ${parsed.baseRef}.` };
+  const headRef = resolveRangeRef(parsed.headRef, branches, activeBranch);
+  if (!headRef) return { ok: false, hint: `Unknown compare ref ${parsed.headRef}.` };
+  return { ok: true, baseRef, headRef };
+}
+
+// A configured range needs both a base and a head that resolve; there is no base to fall back on.
+export function resolveDefaultReviewRange(
+  range: string | undefined,
+  branches: readonly string[],
+  activeBranch: string,
+): ReviewRange | null {
+  const resolved = resolveReviewRangeInput(range ?? "", branches, activeBranch);
+  return resolved.ok ? { baseRef: resolved.baseRef, headRef: resolved.headRef } : null;
+}
@@ -6,19 +6,24 @@ import { DiffWorkspace } from "@/features/diff-view/DiffWorkspace";
 import { LspStatusNotice } from "@/features/lsp/components/LspStatusNotice";
 import { useCurrentLspDocument } from "@/features/lsp/hooks/useCurrentLspDocument";
 import { useDiffDiagnostics } from "@/features/lsp/hooks/useDiffDiagnostics";
-import { applyHunkToIndexAction } from "@/features/source-control/actions";
+import { applyHunkToIndexAction, unstageFileAction } from "@/features/source-control/actions";
 import { useGetFileVersionsQuery, useGetGitSnapshotQuery } from "@/features/source-control/api";
 import { ChangesSidebar } from "@/features/source-control/components/ChangesSidebar";
 import { MergeConflictViewer } from "@/features/source-control/components/MergeConflictViewer";
 import { useChangesKeyboardNav } from "@/features/source-control/hooks/useChangesKeyboardNav";
 import { useChangesSync } from "@/features/source-control/hooks/useChangesSync";
+import { useStaleDiffCheck } from "@/features/source-control/hooks/useStaleDiffCheck";
 import { useThrottledDiffSelection } from "@/features/source-control/hooks/useThrottledDiffSelection";
 import {
   buildIndexContentsForHunkOperation,
+  buildIndexContentsForLineOperation,
+  unstagesEntireFile,
   type DiffHunkActionPayload,
   type DiffHunkOperation,
+  type DiffLinesActionPayload,
 } from "@/features/source-control/hunkOperations";
 import { errorMessageFrom } from "@/features/source-control/shared-utils/errorMessage";
+import { isProtectedPath } from "@/platform/desktop/protectedPaths";
 
 export function ChangesScreen() {
   useChangesKeyboardNav("changes");
@@ -42,6 +47,9 @@ function ChangesDiffPane() {
   const activeBucket = useAppSelector((state) => state.sourceControl.activeBucket);
   const activePath = useAppSelector((state) => state.sourceControl.activePath);
   const diffFocusTarget = useAppSelector((state) => state.sourceControl.diffFocusTarget);
+  const protectedPaths = useAppSelector(
+    (state) => state.settings.appSettings.sourceControl.protectedPaths,
+  );
 
   const { data: snapshot } = useGetGitSnapshotQuery(activeRepo ?? "", {
     skip: !activeRepo,
@@ -77,6 +85,13 @@ function ChangesDiffPane() {
   const newFile = fileVersions?.newFile ?? null;
   const errorMessage = fileVersions ? "" : errorMessageFrom(workingFileVersions.error, "");
   const previewPath = previewSelection?.path ?? activePath ?? "";
+  // Staged diffs compare HEAD with the index, so worktree edits can't make them stale.
+  const diffIsStale = useStaleDiffCheck(
+    activeRepo,
+    previewPath,
+    fileVersions,
+    !!previewSelection && previewSelection.bucket !== "staged" && !workingFileVersions.isFetching,
+  );
   const lspText = !loadingPatch && newFile ? newFile.contents : null;
   const lspHoverDocument =
     activeRepo && previewPath && lspText !== null && !isMergeConflict
@@ -98,9 +113,13 @@ function ChangesDiffPane() {
     diffFocusTarget?.kind === "changes" && diffFocusTarget.path === previewPath
       ? diffFocusTarget.focusKey
       : null;
+  const previewIsProtected =
+    !!previewSelection && isProtectedPath(previewSelection.path, protectedPaths);
   const hunkOperations: DiffHunkOperation[] =
     previewSelection?.bucket === "unstaged"
-      ? ["stage", "discard"]
+      ? previewIsProtected
+        ? []
+        : ["stage", "discard"]
       : previewSelection?.bucket === "staged"
         ? ["unstage"]
         : [];
@@ -110,6 +129,11 @@ function ChangesDiffPane() {
       return;
     }
 
+    if (unstagesEntireFile(payload.fileDiff, operation)) {
+      void dispatch(unstageFileAction(previewPath));
+      return;
+    }
+
     const contents = buildIndexContentsForHunkOperation({
       fileDiff: payload.fileDiff,
       hunkIndex: payload.hunkIndex,
@@ -125,9 +149,29 @@ function ChangesDiffPane() {
     );
   }
 
+  function handleLinesAction(operation: DiffHunkOperation, payload: DiffLinesActionPayload) {
+    if (!previewPath) {
+      return;
+    }
+
+    const contents = buildIndexContentsForLineOperation({
+      fileDiff: payload.fileDiff,
+      range: payload.range,
+      operation,
+    });
+
+    // Unstaging every staged line is a full unstage; see unstagesEntireFile.
+    if (operation === "unstage" && contents === payload.fileDiff.deletionLines.join("")) {
+      void dispatch(unstageFileAction(previewPath));
+      return;
+    }
+
+    void dispatch(applyHunkToIndexAction({ filePath: previewPath, contents, operation }));
+  }
+
   return (
     <div className="grid h-full min-h-0 min-w-0">
-      <section className="flex h-full min-h-0 min-w-0 flex-col">
+      <section data-nav-region="changes-diff" className="flex h-full min-h-0 min-w-0 flex-col">
         <div className="min-h-0 min-w-0 flex-1">
           {errorMessage ? (
             <div className="text-destructive p-3 text-sm">{errorMessage}</div>
@@ -139,11 +183,32 @@ function ChangesDiffPane() {
             <div className="flex h-full min-h-0 min-w-0 flex-col">
               <MergeConflictViewer repoPath={activeRepo} relPath={activePath} />
             </div>
+          ) : fileVersions?.outsideSparseCone ? (
+            <div className="text-muted-foreground p-3 text-sm">
+              This file is outside the sparse-checkout cone and has no working tree copy.
+            </div>
           ) : !oldFile && !newFile ? (
             <div className="text-muted-foreground p-3 text-sm">No diff content.</div>
           ) : (
             <div className="flex h-full min-h-0 min-w-0 flex-col">
               <LspStatusNotice repoPath={activeRepo} relPath={previewPath} active />
+              {diffIsStale ? (
+                <div
+                  role="status"
+                  className="border-border/70 flex items-center gap-2 border-b bg-amber-500/10 px-3 py-1.5 text-xs"
+                >
+                  <span className="text-amber-600 dark:text-amber-400">
+                    Changed on disk since this diff loaded.
+                  </span>
+                  <button
+                    type="button"
+                    className="text-foreground underline underline-offset-2"
+                    onClick={() => void workingFileVersions.refetch()}
+                  >
+                    Reload
+                  </button>
+                </div>
+              ) : null}
               <DiffWorkspace
                 oldFile={oldFile}
                 newFile={newFile}
@@ -159,6 +224,7 @@ function ChangesDiffPane() {
                 focusedLineKey={focusedLineKey}
                 hunkOperations={hunkOperations}
                 onHunkAction={handleHunkAction}
+                onLinesAction={handleLinesAction}
               />
             </div>
           )}
@@ -3,11 +3,13 @@ import { skipToken } from "@reduxjs/toolkit/query";
 import { useAppSelector } from "@/app/hooks";
 import { ResizableSidebarLayout } from "@/components/layout/ResizableSidebarLayout";
 import { DiffWorkspace } from "@/features/diff-view/DiffWorkspace";
+import type { DiffLineSource } from "@/features/diff-view/services/lineReference";
 import {
   useGetCommitFilesQuery,
   useGetCommitFileVersionsQuery,
 } from "@/features/source-control/api";
 import { HistoryFilesPane } from "@/features/source-control/components/HistoryFilesPane";
+import { useHistoryDiffBase } from "@/features/source-control/hooks/useHistoryDiffBase";
 import { useHistoryKeyboardNav } from "@/features/source-control/hooks/useHistoryKeyboardNav";
 import { useHistorySync } from "@/features/source-control/hooks/useHistorySync";
 import { useThrottledDiffSelection } from "@/features/source-control/hooks/useThrottledDiffSelection";
@@ -39,9 +41,19 @@ function HistorySelectionSync() {
 function HistoryDiffPane() {
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const historyCommitId = useAppSelector((state) => state.sourceControl.historyCommitId);
+  const lineReferenceSource: DiffLineSource = { kind: "commit", commitId: historyCommitId };
+  const historyParentIndex = useAppSelector((state) => state.sourceControl.historyParentIndex);
+  const historyDiffBase = useHistoryDiffBase();
   const activePath = useAppSelector((state) => state.sourceControl.activePath);
   const { data: historyFiles } = useGetCommitFilesQuery(
-    activeRepo && historyCommitId ? { repoPath: activeRepo, commitId: historyCommitId } : skipToken,
+    activeRepo && historyCommitId
+      ? {
+          repoPath: activeRepo,
+          commitId: historyCommitId,
+          parentIndex: historyParentIndex,
+          baseRef: historyDiffBase,
+        }
+      : skipToken,
   );
 
   const selectedHistoryFile = historyFiles?.find((file) => file.path === activePath);
@@ -61,6 +73,8 @@ function HistoryDiffPane() {
           commitId: previewSelection.commitId,
           relPath: previewSelection.path,
           previousPath: previewSelection.previousPath,
+          parentIndex: historyParentIndex,
+          baseRef: historyDiffBase,
         }
       : skipToken,
   );
@@ -93,6 +107,7 @@ function HistoryDiffPane() {
               commentContext={{ kind: "changes" }}
               canComment={false}
               fileViewerRevision={historyCommitId}
+              lineReferenceSource={lineReferenceSource}
             />
           </div>
         )}
@@ -1,9 +1,10 @@
 import { skipToken } from "@reduxjs/toolkit/query";
 import { ArrowRightLeft, GitCompare } from "lucide-react";
-import { useEffect } from "react";
+import { useEffect, useState } from "react";
 import { useAppDispatch, useAppSelector } from "@/app/hooks";
 import { ResizableSidebarLayout } from "@/components/layout/ResizableSidebarLayout";
 import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
 import {
   Select,
   SelectContent,
@@ -18,12 +19,16 @@ import {
   EmptyTitle,
   EmptyDescription,
 } from "@/components/ui/empty";
+import { setFileReviewedAction } from "@/features/comments/actions";
+import { isFileReviewed } from "@/features/comments/reviewedFilesSlice";
 import { DiffWorkspace } from "@/features/diff-view/DiffWorkspace";
 import {
   useGetBranchesQuery,
+  useGetDefaultBranchQuery,
   useGetBranchFilesQuery,
   useGetBranchFileVersionsQuery,
   useGetGitSnapshotQuery,
+  useResolveRefInfoQuery,
 } from "@/features/source-control/api";
 import {
   ReviewFileList,
@@ -36,14 +41,21 @@ import {
   setReviewBaseRef,
   setReviewHeadRef,
 } from "@/features/source-control/sourceControlSlice";
+import {
+  resolveDefaultReviewRange,
+  resolveReviewRangeInput,
+  type ReviewRange,
+} from "@/features/source-control/reviewRange";
 import { errorMessageFrom } from "@/features/source-control/shared-utils/errorMessage";
 import type { FileItem } from "@/features/source-control/types";
+import { isCommitIdRef, isWorktreeRef, WORKTREE_REF } from "@/platform/desktop/gitRefs";
 
 function firstAvailableBranch(branches: string[]): string {
   return branches[0] ?? "";
 }
 
-function preferredBaseBranch(branches: string[]): string {
+function preferredBaseBranch(branches: string[], defaultBranch: string | null): string {
+  if (defaultBranch && branches.includes(defaultBranch)) return defaultBranch;
   if (branches.includes("main")) return "main";
   if (branches.includes("master")) return "master";
   const remoteMain = branches.find((branch) => branch.endsWith("/main"));
@@ -66,6 +78,7 @@ type BranchSelectFieldProps = {
   value: string;
   placeholder: string;
   options: string[];
+  includeWorktree?: boolean;
   onChange: (value: string) => void;
 };
 
@@ -74,6 +87,7 @@ function BranchSelectField({
   value,
   placeholder,
   options,
+  includeWorktree = false,
   onChange,
 }: BranchSelectFieldProps) {
   return (
@@ -86,6 +100,16 @@ function BranchSelectField({
           <SelectValue placeholder={placeholder} />
         </SelectTrigger>
         <SelectContent>
+          {includeWorktree ? (
+            <SelectItem key={`${label}-${WORKTREE_REF}`} value={WORKTREE_REF}>
+              Working tree
+            </SelectItem>
+          ) : null}
+          {isCommitIdRef(value) && !options.includes(value) ? (
+            <SelectItem key={`${label}-${value}`} value={value}>
+              {`Commit ${value.slice(0, 7)}`}
+            </SelectItem>
+          ) : null}
           {options.map((branch) => (
             <SelectItem key={`${label}-${branch}`} value={branch}>
               {branch}
@@ -97,6 +121,25 @@ function BranchSelectField({
   );
 }
 
+function RefCommitLabel({ repoPath, gitRef }: { repoPath: string; gitRef: string }) {
+  const { data: refInfo } = useResolveRefInfoQuery(
+    repoPath && gitRef && !isWorktreeRef(gitRef) ? { repoPath, ref: gitRef } : skipToken,
+  );
+
+  if (!refInfo) {
+    return <div className="min-w-0" />;
+  }
+
+  return (
+    <div
+      className="text-muted-foreground min-w-0 truncate text-[10px]"
+      title={`${refInfo.commitId}\n${refInfo.summary}\n${refInfo.author}`}
+    >
+      <span className="font-mono">{refInfo.shortId}</span> {refInfo.summary}
+    </div>
+  );
+}
+
 type ReviewDiffPaneProps = {
   activeRepo: string;
   reviewBaseRef: string;
@@ -112,8 +155,13 @@ function ReviewDiffPane({
   readyForDiff,
   branchFiles,
 }: ReviewDiffPaneProps) {
+  const dispatch = useAppDispatch();
   const reviewActivePath = useAppSelector((state) => state.sourceControl.reviewActivePath);
   const diffFocusTarget = useAppSelector((state) => state.sourceControl.diffFocusTarget);
+  const isReviewed = useAppSelector((state) =>
+    isFileReviewed(state.reviewedFiles, activeRepo, reviewActivePath),
+  );
+  const [expandedReviewedPath, setExpandedReviewedPath] = useState("");
   const selectedReviewFile = branchFiles.find((file) => file.path === reviewActivePath);
   const previewSelection = useThrottledDiffSelection(
     reviewActivePath
@@ -166,6 +214,27 @@ function ReviewDiffPane({
           <div className="text-muted-foreground p-3 text-sm">Select a file to view diff.</div>
         ) : !oldFile && !newFile ? (
           <div className="text-muted-foreground p-3 text-sm">No diff content.</div>
+        ) : isReviewed && expandedReviewedPath !== reviewActivePath ? (
+          <div className="border-border/70 bg-surface-toolbar flex items-center gap-3 border-b px-3 py-2 text-sm">
+            <span className="min-w-0 flex-1 truncate">{reviewActivePath}</span>
+            <span className="text-muted-foreground text-xs">Viewed</span>
+            <Button
+              size="xs"
+              variant="ghost"
+              onClick={() => {
+                dispatch(setFileReviewedAction(activeRepo, reviewActivePath, false));
+              }}
+            >
+              Unmark
+            </Button>
+            <Button
+              size="xs"
+              variant="secondary"
+              onClick={() => setExpandedReviewedPath(reviewActivePath)}
+            >
+              Show diff
+            </Button>
+          </div>
         ) : (
           <div className="flex h-full min-h-0 min-w-0 flex-col">
             <DiffWorkspace
@@ -174,7 +243,7 @@ function ReviewDiffPane({
               activePath={previewPath}
               commentContext={context}
               canComment
-              fileViewerRevision={reviewHeadRef}
+              fileViewerRevision={isWorktreeRef(reviewHeadRef) ? null : reviewHeadRef}
               lspJumpContextKind="review"
               focusedLineNumber={focusedLineNumber}
               focusedLineIndex={focusedLineIndex}
@@ -187,11 +256,66 @@ function ReviewDiffPane({
   );
 }
 
+type ReviewRangeFieldProps = {
+  branches: readonly string[];
+  activeBranch: string;
+  defaultBase: string;
+  onApply: (range: ReviewRange) => void;
+};
+
+function ReviewRangeField({ branches, activeBranch, defaultBase, onApply }: ReviewRangeFieldProps) {
+  const [value, setValue] = useState("");
+  const [hint, setHint] = useState("");
+
+  // Input that does not parse only shows a hint; it never starts a lookup that is bound to fail.
+  const load = () => {
+    const resolved = resolveReviewRangeInput(value, branches, activeBranch, defaultBase);
+    if (!resolved.ok) {
+      setHint(resolved.hint);
+      return;
+    }
+
+    setHint("");
+    onApply({ baseRef: resolved.baseRef, headRef: resolved.headRef });
+  };
+
+  return (
+    <form
+      className="mt-2"
+      onSubmit={(event) => {
+        event.preventDefault();
+        load();
+      }}
+    >
+      <div className="flex items-center gap-1.5">
+        <Input
+          value={value}
+          placeholder="base..head"
+          aria-label="Review range"
+          aria-invalid={hint ? true : undefined}
+          className="h-6 px-2 font-mono text-xs md:text-xs"
+          onChange={(event) => {
+            setValue(event.target.value);
+            setHint("");
+          }}
+        />
+        <Button type="submit" size="xs" variant="outline">
+          Load
+        </Button>
+      </div>
+      {hint ? <div className="text-muted-foreground mt-1 text-[11px]">{hint}</div> : null}
+    </form>
+  );
+}
+
 export function ReviewScreen() {
   const dispatch = useAppDispatch();
   const activeRepo = useAppSelector((state) => state.sourceControl.activeRepo);
   const reviewBaseRef = useAppSelector((state) => state.sourceControl.reviewBaseRef);
   const reviewHeadRef = useAppSelector((state) => state.sourceControl.reviewHeadRef);
+  const configuredReviewRange = useAppSelector(
+    (state) => state.settings.appSettings.sourceControl.defaultReviewRanges[activeRepo],
+  );
 
   const { activeBranch } = useGetGitSnapshotQuery(activeRepo, {
     skip: !activeRepo,
@@ -205,6 +329,10 @@ export function ReviewScreen() {
       branchList: data ?? EMPTY_BRANCHES,
     }),
   });
+  const { data: defaultBranch = null, isLoading: loadingDefaultBranch } = useGetDefaultBranchQuery(
+    activeRepo,
+    { skip: !activeRepo },
+  );
   const readyForDiff = Boolean(activeRepo && reviewBaseRef && reviewHeadRef);
 
   const { branchFiles, hasBranchFilesData } = useGetBranchFilesQuery(
@@ -224,6 +352,9 @@ export function ReviewScreen() {
       dispatch(clearReviewSelection());
       return;
     }
+    if (loadingDefaultBranch) {
+      return;
+    }
     if (branchList.length === 0) {
       if (reviewBaseRef) dispatch(setReviewBaseRef(""));
       if (reviewHeadRef) dispatch(setReviewHeadRef(""));
@@ -231,10 +362,20 @@ export function ReviewScreen() {
       return;
     }
 
-    const hasBase = branchList.includes(reviewBaseRef);
-    const hasHead = branchList.includes(reviewHeadRef);
+    const hasBase = isCommitIdRef(reviewBaseRef) || branchList.includes(reviewBaseRef);
+    const hasHead =
+      isWorktreeRef(reviewHeadRef) ||
+      isCommitIdRef(reviewHeadRef) ||
+      branchList.includes(reviewHeadRef);
 
-    const nextBase = hasBase ? reviewBaseRef : preferredBaseBranch(branchList);
+    // A configured range that no longer resolves falls back to the usual default-branch seeding.
+    const defaultRange =
+      hasBase && hasHead
+        ? null
+        : resolveDefaultReviewRange(configuredReviewRange, branchList, activeBranch);
+    const nextBase = hasBase
+      ? reviewBaseRef
+      : (defaultRange?.baseRef ?? preferredBaseBranch(branchList, defaultBranch));
     if (nextBase !== reviewBaseRef) {
       dispatch(setReviewBaseRef(nextBase));
     }
@@ -242,11 +383,21 @@ export function ReviewScreen() {
     const preferredHead = activeBranch && branchList.includes(activeBranch) ? activeBranch : "";
     const nextHead = hasHead
       ? reviewHeadRef
-      : preferredHead || firstDifferentBranch(branchList, nextBase);
+      : defaultRange?.headRef || preferredHead || firstDifferentBranch(branchList, nextBase);
     if (nextHead !== reviewHeadRef) {
       dispatch(setReviewHeadRef(nextHead));
     }
-  }, [activeBranch, activeRepo, branchList, dispatch, reviewBaseRef, reviewHeadRef]);
+  }, [
+    activeBranch,
+    activeRepo,
+    branchList,
+    configuredReviewRange,
+    defaultBranch,
+    dispatch,
+    loadingDefaultBranch,
+    reviewBaseRef,
+    reviewHeadRef,
+  ]);
 
   return (
     <ResizableSidebarLayout
@@ -284,7 +435,7 @@ export function ReviewScreen() {
                     dispatch(setReviewHeadRef(nextHead));
                     dispatch(setReviewActivePath(""));
                   }}
-                  disabled={!reviewBaseRef || !reviewHeadRef}
+                  disabled={!reviewBaseRef || !reviewHeadRef || isWorktreeRef(reviewHeadRef)}
                   title="Swap branches"
                 >
                   <ArrowRightLeft className="h-3.5 w-3.5" />
@@ -295,12 +446,27 @@ export function ReviewScreen() {
                   value={reviewHeadRef}
                   placeholder="Compare ref"
                   options={branchList}
+                  includeWorktree
                   onChange={(value) => {
                     dispatch(setReviewHeadRef(value));
                     dispatch(setReviewActivePath(""));
                   }}
                 />
               </div>
+              <div className="mt-1 grid grid-cols-2 gap-1.5">
+                <RefCommitLabel repoPath={activeRepo} gitRef={reviewBaseRef} />
+                <RefCommitLabel repoPath={activeRepo} gitRef={reviewHeadRef} />
+              </div>
+              <ReviewRangeField
+                branches={branchList}
+                activeBranch={activeBranch}
+                defaultBase={preferredBaseBranch(branchList, defaultBranch)}
+                onApply={({ baseRef, headRef }) => {
+                  dispatch(setReviewBaseRef(baseRef));
+                  dispatch(setReviewHeadRef(headRef));
+                  dispatch(setReviewActivePath(""));
+                }}
+              />
             </div>
           </div>
 
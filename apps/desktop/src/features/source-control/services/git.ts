@@ -1,13 +1,20 @@
-import { desktop } from "@/platform/desktop";
+import { desktop, type RepoFilesOptions } from "@/platform/desktop";
 
 import type {
   Bucket,
-  DiffFile,
+  CommitStats,
   FileItem,
   FileVersions,
   GitSnapshot,
   HistoryCommit,
+  IndexPreviewEntry,
+  PushStashOptions,
+  ReflogEntry,
+  RefResolution,
+  RepoFile,
   RepoFileItem,
+  StashSelector,
+  WorkingCopySummary,
 } from "../types";
 
 type DiscardFileRequest = {
@@ -19,28 +26,89 @@ export async function getGitSnapshot(repoPath: string) {
   return desktop.getGitSnapshot(repoPath) as Promise<GitSnapshot>;
 }
 
-export async function getRepoFiles(repoPath: string) {
-  return desktop.getRepoFiles(repoPath) as Promise<RepoFileItem[]>;
+export async function getWorkingCopySummary(repoPath: string) {
+  return desktop.getWorkingCopySummary(repoPath) as Promise<WorkingCopySummary>;
 }
 
-export async function getCommitHistory(repoPath: string, limit?: number) {
-  return desktop.getCommitHistory(repoPath, limit) as Promise<HistoryCommit[]>;
+export async function getChangedFileHashes(repoPath: string) {
+  return desktop.getChangedFileHashes(repoPath);
+}
+
+export async function getRepoFiles(repoPath: string, options?: RepoFilesOptions) {
+  return desktop.getRepoFiles(repoPath, options) as Promise<RepoFileItem[]>;
+}
+
+export async function getRecentlyModifiedFiles(repoPath: string, limit?: number) {
+  return desktop.getRecentlyModifiedFiles(repoPath, limit);
+}
+
+export async function getCommitHistory(repoPath: string, limit?: number, skip?: number) {
+  return desktop.getCommitHistory(repoPath, limit, skip) as Promise<HistoryCommit[]>;
+}
+
+export async function blameFile(repoPath: string, relPath: string, revision?: string) {
+  return desktop.blameFile(repoPath, relPath, revision);
+}
+
+export async function getCommitStats(repoPath: string, commitId: string) {
+  return desktop.getCommitStats(repoPath, commitId) as Promise<CommitStats>;
+}
+
+export async function getParentSummary(repoPath: string, commitId: string) {
+  return desktop.getParentSummary(repoPath, commitId);
+}
+
+export async function getReflog(repoPath: string, refName?: string, limit?: number) {
+  return desktop.getReflog(repoPath, refName, limit) as Promise<ReflogEntry[]>;
+}
+
+export async function resolveRefInfo(repoPath: string, ref: string) {
+  return desktop.resolveRefInfo(repoPath, ref) as Promise<RefResolution>;
+}
+
+export async function applyStash(repoPath: string, stash: StashSelector) {
+  return desktop.applyStash(repoPath, stash);
+}
+
+export async function listStashes(repoPath: string) {
+  return desktop.listStashes(repoPath);
+}
+
+export async function pushStash(repoPath: string, options?: PushStashOptions) {
+  return desktop.pushStash(repoPath, options);
+}
+
+export async function popStash(repoPath: string, stash: StashSelector) {
+  return desktop.popStash(repoPath, stash);
+}
+
+export async function addGitignorePattern(repoPath: string, pattern: string) {
+  return desktop.addGitignorePattern(repoPath, pattern);
 }
 
 export async function getBranches(repoPath: string) {
   return desktop.getBranches(repoPath);
 }
 
+export async function getDefaultBranch(repoPath: string) {
+  return desktop.getDefaultBranch(repoPath);
+}
+
 export async function getBranchFiles(repoPath: string, baseRef: string, headRef: string) {
   return desktop.getBranchFiles(repoPath, baseRef, headRef) as Promise<FileItem[]>;
 }
 
-export async function getCommitFiles(repoPath: string, commitId: string) {
-  return desktop.getCommitFiles(repoPath, commitId) as Promise<FileItem[]>;
+export async function getCommitFiles(repoPath: string, commitId: string, parentIndex?: number) {
+  return desktop.getCommitFiles(repoPath, commitId, parentIndex) as Promise<FileItem[]>;
 }
 
-export async function getRepoFile(repoPath: string, relPath: string, revision?: string | null) {
-  return desktop.getRepoFile({ repoPath, relPath, revision }) as Promise<DiffFile | null>;
+export async function getRepoFile(
+  repoPath: string,
+  relPath: string,
+  revision?: string | null,
+  maxBytes?: number,
+) {
+  return desktop.getRepoFile({ repoPath, relPath, revision, maxBytes }) as Promise<RepoFile | null>;
 }
 
 export async function getCommitFileVersions(
@@ -48,12 +116,14 @@ export async function getCommitFileVersions(
   commitId: string,
   relPath: string,
   previousPath?: string,
+  parentIndex?: number,
 ) {
   return desktop.getCommitFileVersions(
     repoPath,
     commitId,
     relPath,
     previousPath,
+    parentIndex,
   ) as Promise<FileVersions>;
 }
 
@@ -101,10 +171,31 @@ export async function discardFile(repoPath: string, relPath: string, bucket: Buc
   await desktop.discardFile(repoPath, relPath, bucket);
 }
 
+export async function previewStageAll(repoPath: string) {
+  return desktop.previewStageAll(repoPath) as Promise<IndexPreviewEntry[]>;
+}
+
+export async function previewUnstageAll(repoPath: string) {
+  return desktop.previewUnstageAll(repoPath) as Promise<IndexPreviewEntry[]>;
+}
+
+export async function checkoutFileFromRef(
+  repoPath: string,
+  relPath: string,
+  ref: string,
+  stage?: boolean,
+) {
+  await desktop.checkoutFileFromRef(repoPath, relPath, ref, stage);
+}
+
 export async function discardFiles(repoPath: string, files: DiscardFileRequest[]) {
   await desktop.discardFiles(repoPath, files);
 }
 
+export async function discardUnstaged(repoPath: string) {
+  await desktop.discardUnstaged(repoPath);
+}
+
 export async function stageAll(repoPath: string) {
   await desktop.stageAll(repoPath);
 }
@@ -113,6 +204,22 @@ export async function unstageAll(repoPath: string) {
   await desktop.unstageAll(repoPath);
 }
 
+export async function stageDirectory(repoPath: string, dirRelPath: string) {
+  await desktop.stageDirectory(repoPath, dirRelPath);
+}
+
+export async function unstageDirectory(repoPath: string, dirRelPath: string) {
+  await desktop.unstageDirectory(repoPath, dirRelPath);
+}
+
 export async function commitStaged(repoPath: string, message: string) {
   return desktop.commitStaged(repoPath, message);
 }
+
+export async function rewordHead(repoPath: string, message: string) {
+  return desktop.rewordHead(repoPath, message);
+}
+
+export async function amendHead(repoPath: string, message?: string) {
+  return desktop.amendHead(repoPath, message);
+}
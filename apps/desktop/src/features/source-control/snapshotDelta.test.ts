@@ -0,0 +1,53 @@
+import { describe, expect, it } from "vitest";
+
+import {
+  diffSnapshots,
+  isFileStale,
+  nextNewSinceLastView,
+} from "@/features/source-control/snapshotDelta";
+
+describe("diffSnapshots", () => {
+  it("reports added, further modified, and removed paths between two snapshots", () => {
+    const delta = diffSnapshots(
+      { "src/a.ts": "aaa", "src/b.ts": "bbb", "src/gone.ts": "ccc" },
+      { "src/a.ts": "aaa", "src/b.ts": "bbb2", "src/new.ts": "ddd" },
+    );
+
+    expect(delta).toEqual({
+      added: ["src/new.ts"],
+      modified: ["src/b.ts"],
+      removed: ["src/gone.ts"],
+    });
+  });
+
+  it("returns an empty delta for identical snapshots", () => {
+    expect(diffSnapshots({ "a.txt": "1" }, { "a.txt": "1" })).toEqual({
+      added: [],
+      modified: [],
+      removed: [],
+    });
+  });
+
+  it("keeps unseen markers across refreshes and drops paths that are no longer changed", () => {
+    expect(
+      nextNewSinceLastView(["a.txt", "b.txt"], {
+        added: ["c.txt"],
+        modified: ["a.txt"],
+        removed: ["b.txt"],
+      }),
+    ).toEqual(["a.txt", "c.txt"]);
+  });
+});
+
+describe("isFileStale", () => {
+  it("flags a displayed diff whose worktree content changed since it loaded", () => {
+    expect(isFileStale("aaa", "aaa")).toBe(false);
+    expect(isFileStale("aaa", "bbb")).toBe(true);
+    expect(isFileStale("aaa", undefined)).toBe(true);
+  });
+
+  it("never flags a diff that loaded before any hash was known", () => {
+    expect(isFileStale(undefined, "bbb")).toBe(false);
+    expect(isFileStale(undefined, undefined)).toBe(false);
+  });
+});
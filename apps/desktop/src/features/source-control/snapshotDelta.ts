@@ -0,0 +1,50 @@
+export type FileHashSnapshot = Record<string, string>;
+
+export type SnapshotDelta = {
+  added: string[];
+  modified: string[];
+  removed: string[];
+};
+
+export function diffSnapshots(prev: FileHashSnapshot, curr: FileHashSnapshot): SnapshotDelta {
+  const added: string[] = [];
+  const modified: string[] = [];
+  const removed: string[] = [];
+
+  for (const [path, hash] of Object.entries(curr)) {
+    const previousHash = prev[path];
+    if (previousHash === undefined) {
+      added.push(path);
+    } else if (previousHash !== hash) {
+      modified.push(path);
+    }
+  }
+
+  for (const path of Object.keys(prev)) {
+    if (curr[path] === undefined) {
+      removed.push(path);
+    }
+  }
+
+  return { added: added.sort(), modified: modified.sort(), removed: removed.sort() };
+}
+
+export function nextNewSinceLastView(
+  current: ReadonlyArray<string>,
+  delta: SnapshotDelta,
+): string[] {
+  const removed = new Set(delta.removed);
+  const next = new Set(current.filter((path) => !removed.has(path)));
+
+  for (const path of [...delta.added, ...delta.modified]) {
+    next.add(path);
+  }
+
+  return [...next].sort();
+}
+
+// `storedHash` is the worktree hash recorded when a diff was loaded. A file that dropped out of
+// the changed set (reverted or deleted) is stale too; an unknown stored hash never is.
+export function isFileStale(storedHash: string | undefined, currentHash: string | undefined) {
+  return storedHash !== undefined && storedHash !== currentHash;
+}
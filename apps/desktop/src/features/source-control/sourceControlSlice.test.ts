@@ -1,11 +1,15 @@
 import { describe, expect, it } from "vitest";
 
 import {
+  moveRepo,
   openFileViewer,
   openSymbolPeek,
   setSymbolPeekActiveIndex,
+  setActiveRepo,
+  setRepos,
   setSymbolPeekQuery,
   sourceControlReducer,
+  togglePinnedPath,
 } from "@/features/source-control/sourceControlSlice";
 
 describe("sourceControlSlice symbol peek", () => {
@@ -145,3 +149,35 @@ describe("sourceControlSlice symbol peek", () => {
     });
   });
 });
+
+describe("sourceControlSlice pinned paths", () => {
+  it("pins per repo in pin order and drops the repo entry when the last pin is removed", () => {
+    let state = sourceControlReducer(
+      undefined,
+      togglePinnedPath({ repoPath: "/repo", path: "c.ts" }),
+    );
+    state = sourceControlReducer(state, togglePinnedPath({ repoPath: "/repo", path: "a.ts" }));
+
+    expect(state.pinnedPaths).toEqual({ "/repo": ["c.ts", "a.ts"] });
+
+    state = sourceControlReducer(state, togglePinnedPath({ repoPath: "/repo", path: "c.ts" }));
+    state = sourceControlReducer(state, togglePinnedPath({ repoPath: "/repo", path: "a.ts" }));
+
+    expect(state.pinnedPaths).toEqual({});
+  });
+});
+
+describe("sourceControlSlice repo order", () => {
+  it("moves a repo tab and keeps the active repo active", () => {
+    let state = sourceControlReducer(undefined, setRepos(["/a", "/b", "/c"]));
+    state = sourceControlReducer(state, setActiveRepo("/b"));
+    state = sourceControlReducer(state, moveRepo({ from: 2, to: 0 }));
+
+    expect(state.repos).toEqual(["/c", "/a", "/b"]);
+    expect(state.activeRepo).toBe("/b");
+
+    state = sourceControlReducer(state, moveRepo({ from: 0, to: 5 }));
+
+    expect(state.repos).toEqual(["/c", "/a", "/b"]);
+  });
+});
@@ -13,13 +13,19 @@ import type {
   SelectedFile,
   SymbolPeekState,
 } from "./types";
+import { diffSnapshots, nextNewSinceLastView, type FileHashSnapshot } from "./snapshotDelta";
 
 type SourceControlState = {
   repos: string[];
   activeRepo: string;
   recentRepos: string[];
+  // Open repos from the saved session whose paths were missing or unreadable at restore.
+  unavailableRepos: string[];
   historyFilter: string;
+  historyAuthorFilter: string;
   historyCommitId: string;
+  historyParentIndex: number;
+  historyRangeStartId: string;
   historyNavTarget: HistoryNavTarget;
   collapseStaged: boolean;
   collapseUnstaged: boolean;
@@ -28,6 +34,9 @@ type SourceControlState = {
   activePath: string;
   repoTreeActivePath: string;
   diffStyle: DiffStyle;
+  diffWrap: boolean;
+  diffChangesOnly: boolean;
+  diffClusterPreview: boolean;
   commitMessage: string;
   lastCommitId: string;
   runningAction: RunningAction;
@@ -39,14 +48,21 @@ type SourceControlState = {
   diffFocusTarget: DiffFocusTarget | null;
   fileViewerTarget: FileViewerTarget | null;
   symbolPeek: SymbolPeekState | null;
+  lastViewedFileHashes: FileHashSnapshot | null;
+  newSinceLastView: string[];
+  pinnedPaths: Record<string, string[]>;
 };
 
 const initialState: SourceControlState = {
   repos: [],
   activeRepo: "",
   recentRepos: [],
+  unavailableRepos: [],
   historyFilter: "",
+  historyAuthorFilter: "",
   historyCommitId: "",
+  historyParentIndex: 0,
+  historyRangeStartId: "",
   historyNavTarget: "commits",
   collapseStaged: false,
   collapseUnstaged: false,
@@ -55,6 +71,9 @@ const initialState: SourceControlState = {
   activePath: "",
   repoTreeActivePath: "",
   diffStyle: "split",
+  diffWrap: false,
+  diffChangesOnly: false,
+  diffClusterPreview: false,
   commitMessage: "",
   lastCommitId: "",
   runningAction: "",
@@ -66,6 +85,9 @@ const initialState: SourceControlState = {
   diffFocusTarget: null,
   fileViewerTarget: null,
   symbolPeek: null,
+  lastViewedFileHashes: null,
+  newSinceLastView: [],
+  pinnedPaths: {},
 };
 
 const sourceControlSlice = createSlice({
@@ -85,6 +107,14 @@ const sourceControlSlice = createSlice({
         state.repos.push(action.payload);
       }
     },
+    // The active repo is tracked by path, so it stays active wherever its tab moves.
+    moveRepo(state, action: PayloadAction<{ from: number; to: number }>) {
+      const { from, to } = action.payload;
+      if (from === to || !state.repos[from] || to < 0 || to >= state.repos.length) return;
+
+      const [repo] = state.repos.splice(from, 1);
+      state.repos.splice(to, 0, repo!);
+    },
     removeRepo(state, action: PayloadAction<string>) {
       state.repos = state.repos.filter((repo) => repo !== action.payload);
       if (state.activeRepo === action.payload) {
@@ -99,14 +129,36 @@ const sourceControlSlice = createSlice({
     setRecentRepos(state, action: PayloadAction<string[]>) {
       state.recentRepos = action.payload;
     },
+    setUnavailableRepos(state, action: PayloadAction<string[]>) {
+      state.unavailableRepos = action.payload;
+    },
+    dismissUnavailableRepo(state, action: PayloadAction<string>) {
+      state.unavailableRepos = state.unavailableRepos.filter((repo) => repo !== action.payload);
+    },
     setHistoryFilter(state, action: PayloadAction<string>) {
       if (state.historyFilter !== action.payload) {
         state.historyFilter = action.payload;
       }
     },
+    setHistoryAuthorFilter(state, action: PayloadAction<string>) {
+      if (state.historyAuthorFilter !== action.payload) {
+        state.historyAuthorFilter = action.payload;
+      }
+    },
     setHistoryCommitId(state, action: PayloadAction<string>) {
       if (state.historyCommitId !== action.payload) {
         state.historyCommitId = action.payload;
+        state.historyParentIndex = 0;
+      }
+    },
+    setHistoryParentIndex(state, action: PayloadAction<number>) {
+      if (state.historyParentIndex !== action.payload) {
+        state.historyParentIndex = action.payload;
+      }
+    },
+    setHistoryRangeStartId(state, action: PayloadAction<string>) {
+      if (state.historyRangeStartId !== action.payload) {
+        state.historyRangeStartId = action.payload;
       }
     },
     setHistoryNavTarget(state, action: PayloadAction<HistoryNavTarget>) {
@@ -144,6 +196,21 @@ const sourceControlSlice = createSlice({
         state.diffStyle = action.payload;
       }
     },
+    setDiffWrap(state, action: PayloadAction<boolean>) {
+      if (state.diffWrap !== action.payload) {
+        state.diffWrap = action.payload;
+      }
+    },
+    setDiffChangesOnly(state, action: PayloadAction<boolean>) {
+      if (state.diffChangesOnly !== action.payload) {
+        state.diffChangesOnly = action.payload;
+      }
+    },
+    setDiffClusterPreview(state, action: PayloadAction<boolean>) {
+      if (state.diffClusterPreview !== action.payload) {
+        state.diffClusterPreview = action.payload;
+      }
+    },
     setCommitMessage(state, action: PayloadAction<string>) {
       if (state.commitMessage !== action.payload) {
         state.commitMessage = action.payload;
@@ -161,7 +228,10 @@ const sourceControlSlice = createSlice({
     },
     resetRepoViewState(state) {
       state.historyFilter = "";
+      state.historyAuthorFilter = "";
       state.historyCommitId = "";
+      state.historyParentIndex = 0;
+      state.historyRangeStartId = "";
       state.historyNavTarget = "commits";
       state.activeBucket = "unstaged";
       state.changesSidebarMode = "changes";
@@ -178,6 +248,20 @@ const sourceControlSlice = createSlice({
       state.diffFocusTarget = null;
       state.fileViewerTarget = null;
       state.symbolPeek = null;
+      state.lastViewedFileHashes = null;
+      state.newSinceLastView = [];
+    },
+    recordChangedFileHashes(state, action: PayloadAction<FileHashSnapshot>) {
+      if (state.lastViewedFileHashes) {
+        const delta = diffSnapshots(state.lastViewedFileHashes, action.payload);
+        state.newSinceLastView = nextNewSinceLastView(state.newSinceLastView, delta);
+      }
+      state.lastViewedFileHashes = action.payload;
+    },
+    markFileViewed(state, action: PayloadAction<string>) {
+      if (state.newSinceLastView.includes(action.payload)) {
+        state.newSinceLastView = state.newSinceLastView.filter((path) => path !== action.payload);
+      }
     },
     clearDiffSelection(state) {
       if (state.activePath !== "") {
@@ -194,6 +278,9 @@ const sourceControlSlice = createSlice({
       if (state.historyCommitId !== "") {
         state.historyCommitId = "";
       }
+      if (state.historyParentIndex !== 0) {
+        state.historyParentIndex = 0;
+      }
       if (state.historyNavTarget !== "commits") {
         state.historyNavTarget = "commits";
       }
@@ -228,6 +315,19 @@ const sourceControlSlice = createSlice({
         state.reviewHeadRef = action.payload;
       }
     },
+    togglePinnedPath(state, action: PayloadAction<{ repoPath: string; path: string }>) {
+      const { repoPath, path } = action.payload;
+      const pinned = state.pinnedPaths[repoPath] ?? [];
+      const nextPinned = pinned.includes(path)
+        ? pinned.filter((pinnedPath) => pinnedPath !== path)
+        : [...pinned, path];
+
+      if (nextPinned.length > 0) {
+        state.pinnedPaths[repoPath] = nextPinned;
+      } else {
+        delete state.pinnedPaths[repoPath];
+      }
+    },
     setReviewActivePath(state, action: PayloadAction<string>) {
       if (state.reviewActivePath !== action.payload) {
         state.reviewActivePath = action.payload;
@@ -296,9 +396,13 @@ export const {
   clearDiffFocusTarget,
   clearDiffSelection,
   closeFileViewer,
+  dismissUnavailableRepo,
   clearHistorySelection,
   clearReviewSelection,
   hydrateWorkspaceSession,
+  markFileViewed,
+  recordChangedFileHashes,
+  moveRepo,
   removeRepo,
   resetRepoViewState,
   setActiveBucket,
@@ -308,16 +412,23 @@ export const {
   setCollapseStaged,
   setCollapseUnstaged,
   setChangesSidebarMode,
+  setDiffChangesOnly,
+  setDiffClusterPreview,
   setDiffStyle,
+  setDiffWrap,
+  setHistoryAuthorFilter,
   setHistoryCommitId,
   setHistoryFilter,
   setHistoryNavTarget,
+  setHistoryParentIndex,
+  setHistoryRangeStartId,
   setLastCommitId,
   setRecentRepos,
   setRepoTreeActivePath,
   setSelectedFiles,
   setSelectionAnchor,
   setRunningAction,
+  setUnavailableRepos,
   setRepos,
   setReviewActivePath,
   setReviewBaseRef,
@@ -328,6 +439,7 @@ export const {
   closeSymbolPeek,
   setSymbolPeekActiveIndex,
   setSymbolPeekQuery,
+  togglePinnedPath,
 } = sourceControlSlice.actions;
 
 export const sourceControlReducer = sourceControlSlice.reducer;
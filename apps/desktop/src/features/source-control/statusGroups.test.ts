@@ -0,0 +1,31 @@
+import { describe, expect, it } from "vitest";
+
+import type { Bucket } from "@/features/source-control/types";
+import { groupFilesByBucket } from "./statusGroups";
+
+describe("groupFilesByBucket", () => {
+  it("partitions files into staged, unstaged, and untracked sections", () => {
+    const files: Array<{ path: string; bucket: Bucket }> = [
+      { path: "src/b.ts", bucket: "unstaged" },
+      { path: "notes.md", bucket: "untracked" },
+      { path: "src/a.ts", bucket: "unstaged" },
+      { path: "src/new.ts", bucket: "staged" },
+      { path: "README.md", bucket: "staged" },
+    ];
+
+    const groups = groupFilesByBucket(files);
+
+    expect(groups.map((group) => [group.bucket, group.files.map((file) => file.path)])).toEqual([
+      ["staged", ["README.md", "src/new.ts"]],
+      ["unstaged", ["src/a.ts", "src/b.ts"]],
+      ["untracked", ["notes.md"]],
+    ]);
+  });
+
+  it("leaves out empty buckets", () => {
+    expect(groupFilesByBucket([{ path: "a.ts", bucket: "untracked" as const }])).toEqual([
+      { bucket: "untracked", files: [{ path: "a.ts", bucket: "untracked" }] },
+    ]);
+    expect(groupFilesByBucket([])).toEqual([]);
+  });
+});
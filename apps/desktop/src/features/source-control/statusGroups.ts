@@ -0,0 +1,29 @@
+import type { Bucket } from "@/features/source-control/types";
+
+export type BucketGroup<TFile> = {
+  bucket: Bucket;
+  files: TFile[];
+};
+
+const BUCKET_ORDER: readonly Bucket[] = ["staged", "unstaged", "untracked"];
+
+const SORT_LOCALE_OPTIONS: Intl.CollatorOptions = { numeric: true, sensitivity: "base" };
+
+// Status mode lists staged files, then unstaged, then untracked, each flat and sorted by path.
+export function groupFilesByBucket<TFile extends { path: string; bucket: Bucket }>(
+  files: ReadonlyArray<TFile>,
+): Array<BucketGroup<TFile>> {
+  return BUCKET_ORDER.flatMap((bucket) => {
+    const groupFiles = files.filter((file) => file.bucket === bucket);
+    if (groupFiles.length === 0) return [];
+
+    return [
+      {
+        bucket,
+        files: groupFiles.toSorted((left, right) =>
+          left.path.localeCompare(right.path, undefined, SORT_LOCALE_OPTIONS),
+        ),
+      },
+    ];
+  });
+}
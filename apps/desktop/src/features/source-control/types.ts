@@ -1,15 +1,28 @@
-import type { DiffHunkActionAnnotation } from "@/features/source-control/hunkOperations";
+import type {
+  DiffHunkActionAnnotation,
+  DiffLinesActionAnnotation,
+} from "@/features/source-control/hunkOperations";
 import type {
   AppSettings as ContractAppSettings,
   Bucket as ContractBucket,
+  CommitStats as ContractCommitStats,
   DiffFile as ContractDiffFile,
   FileItem as ContractFileItem,
   FileStatus as ContractFileStatus,
   FileVersions as ContractFileVersions,
   GitSnapshot as ContractGitSnapshot,
   HistoryCommit as ContractHistoryCommit,
+  IndexPreviewEntry as ContractIndexPreviewEntry,
+  ReflogEntry as ContractReflogEntry,
+  RefResolution as ContractRefResolution,
+  StashSelector as ContractStashSelector,
+  StashTarget as ContractStashTarget,
+  StashEntry as ContractStashEntry,
+  PushStashOptions as ContractPushStashOptions,
+  WorkingCopySummary as ContractWorkingCopySummary,
   LspDiagnostic as ContractLspDiagnostic,
   LspLocation as ContractLspLocation,
+  RepoFile as ContractRepoFile,
   RepoFileItem as ContractRepoFileItem,
   PullRequestReviewThread,
   GitProviderId,
@@ -108,6 +121,22 @@ export type ChangesSidebarMode = "changes" | "files" | "pull-requests" | "pull-r
 
 export type HistoryCommit = ContractHistoryCommit;
 
+export type ReflogEntry = ContractReflogEntry;
+
+export type CommitStats = ContractCommitStats;
+
+export type RefResolution = ContractRefResolution;
+
+export type StashSelector = ContractStashSelector;
+
+export type StashTarget = ContractStashTarget;
+
+export type StashEntry = ContractStashEntry;
+
+export type PushStashOptions = ContractPushStashOptions;
+
+export type IndexPreviewEntry = ContractIndexPreviewEntry;
+
 export type LspDiagnostic = ContractLspDiagnostic;
 
 export type SelectionRange = {
@@ -184,12 +213,17 @@ export type DiffAnnotationItem =
   | DiagnosticAnnotation
   | PullRequestThreadAnnotation
   | PullRequestAnchorAnnotation
-  | DiffHunkActionAnnotation;
+  | DiffHunkActionAnnotation
+  | DiffLinesActionAnnotation;
 
 export type GitSnapshot = ContractGitSnapshot;
 
+export type WorkingCopySummary = ContractWorkingCopySummary;
+
 export type DiffFile = ContractDiffFile;
 
+export type RepoFile = ContractRepoFile;
+
 export type FileVersions = ContractFileVersions;
 
 export type RunningAction =
@@ -202,7 +236,17 @@ export type RunningAction =
   | "unstage-hunk"
   | "discard-hunk"
   | "discard-changes"
+  | "discard-unstaged"
   | "commit"
+  | "reword"
+  | "amend"
+  | "stash-push"
+  | `stash:apply:${string}`
+  | `stash:pop:${string}`
   | `file:stage:${string}`
   | `file:unstage:${string}`
-  | `file:discard:${string}`;
+  | `file:discard:${string}`
+  | `file:restore:${string}`
+  | `file:ignore:${string}`
+  | `folder:stage:${string}`
+  | `folder:unstage:${string}`;
@@ -1,6 +1,12 @@
 import { describe, expect, it } from "vitest";
 
-import { createAppSettings, DEFAULT_APP_SETTINGS } from "@/platform/desktop/appSettings";
+import {
+  createAppSettings,
+  DEFAULT_APP_SETTINGS,
+  DEFAULT_COLLAPSED_PATHS,
+  DEFAULT_HISTORY_PAGE_SIZE,
+  DEFAULT_MAX_HIGHLIGHT_BYTES,
+} from "@/platform/desktop/appSettings";
 
 describe("appSettings helpers", () => {
   it("returns defaults for missing or invalid settings", () => {
@@ -21,10 +27,91 @@ describe("appSettings helpers", () => {
       version: 1,
       sourceControl: {
         fileTreeRenderMode: "list",
+        protectedPaths: [],
+        stageCommentedFilesOnCopy: false,
+        historyPageSize: DEFAULT_HISTORY_PAGE_SIZE,
+        defaultReviewRanges: {},
+        historyDateFormat: "",
+      },
+      diffView: {
+        maxHighlightBytes: DEFAULT_MAX_HIGHLIGHT_BYTES,
+        maxLineLength: 0,
+        collapsedPaths: DEFAULT_COLLAPSED_PATHS,
       },
       lsp: {
         servers: {},
       },
     });
   });
+
+  it("accepts the status grouping render mode", () => {
+    expect(
+      createAppSettings({ sourceControl: { fileTreeRenderMode: "status" } }).sourceControl,
+    ).toEqual({
+      fileTreeRenderMode: "status",
+      protectedPaths: [],
+      stageCommentedFilesOnCopy: false,
+      historyPageSize: DEFAULT_HISTORY_PAGE_SIZE,
+      defaultReviewRanges: {},
+      historyDateFormat: "",
+    });
+    expect(
+      createAppSettings({ sourceControl: { fileTreeRenderMode: "grid" } }).sourceControl,
+    ).toEqual({
+      fileTreeRenderMode: "tree",
+      protectedPaths: [],
+      stageCommentedFilesOnCopy: false,
+      historyPageSize: DEFAULT_HISTORY_PAGE_SIZE,
+      defaultReviewRanges: {},
+      historyDateFormat: "",
+    });
+  });
+
+  it("keeps non-empty protected path patterns", () => {
+    expect(
+      createAppSettings({ sourceControl: { protectedPaths: ["./dist/", "", 4, "**/generated"] } })
+        .sourceControl.protectedPaths,
+    ).toEqual(["dist", "**/generated"]);
+  });
+
+  it("keeps non-empty default review ranges", () => {
+    expect(
+      createAppSettings({
+        sourceControl: { defaultReviewRanges: { "/repo": " origin/main..HEAD ", "/other": "", x: 4 } },
+      }).sourceControl.defaultReviewRanges,
+    ).toEqual({ "/repo": "origin/main..HEAD" });
+  });
+
+  it("drops history date formats with unknown directives", () => {
+    expect(
+      createAppSettings({ sourceControl: { historyDateFormat: "%d.%m.%Y" } }).sourceControl
+        .historyDateFormat,
+    ).toBe("%d.%m.%Y");
+    expect(
+      createAppSettings({ sourceControl: { historyDateFormat: "%Q" } }).sourceControl
+        .historyDateFormat,
+    ).toBe("");
+  });
+
+  it("falls back to the default history page size for invalid values", () => {
+    expect(
+      createAppSettings({ sourceControl: { historyPageSize: 0 } }).sourceControl.historyPageSize,
+    ).toBe(DEFAULT_HISTORY_PAGE_SIZE);
+    expect(
+      createAppSettings({ sourceControl: { historyPageSize: 50.5 } }).sourceControl.historyPageSize,
+    ).toBe(50);
+  });
+
+  it("falls back to the default highlight limit for invalid values", () => {
+    expect(createAppSettings({ diffView: { maxHighlightBytes: -5 } }).diffView).toEqual({
+      maxHighlightBytes: DEFAULT_MAX_HIGHLIGHT_BYTES,
+      maxLineLength: 0,
+      collapsedPaths: DEFAULT_COLLAPSED_PATHS,
+    });
+    expect(createAppSettings({ diffView: { maxHighlightBytes: 2048.7 } }).diffView).toEqual({
+      maxHighlightBytes: 2048,
+      maxLineLength: 0,
+      collapsedPaths: DEFAULT_COLLAPSED_PATHS,
+    });
+  });
 });
@@ -1,9 +1,30 @@
-import type { AppSettings, FileTreeRenderMode } from "./contracts";
+import type { AppSettings, DiffViewSettings, FileTreeRenderMode } from "./contracts";
+import { isValidDateFormat } from "./dateFormat";
+import { normalizeProtectedPathPattern } from "./protectedPaths";
+
+export const DEFAULT_MAX_HIGHLIGHT_BYTES = 1_000_000;
+export const DEFAULT_HISTORY_PAGE_SIZE = 200;
+export const DEFAULT_COLLAPSED_PATHS = [
+  "**/Cargo.lock",
+  "**/package-lock.json",
+  "**/pnpm-lock.yaml",
+  "**/yarn.lock",
+];
 
 export const DEFAULT_APP_SETTINGS: AppSettings = {
   version: 1,
   sourceControl: {
     fileTreeRenderMode: "tree",
+    protectedPaths: [],
+    stageCommentedFilesOnCopy: false,
+    historyPageSize: DEFAULT_HISTORY_PAGE_SIZE,
+    defaultReviewRanges: {},
+    historyDateFormat: "",
+  },
+  diffView: {
+    maxHighlightBytes: DEFAULT_MAX_HIGHLIGHT_BYTES,
+    maxLineLength: 0,
+    collapsedPaths: DEFAULT_COLLAPSED_PATHS,
   },
   lsp: {
     servers: {},
@@ -15,7 +36,61 @@ function isObject(value: unknown): value is Record<string, unknown> {
 }
 
 function resolveFileTreeRenderMode(value: unknown): FileTreeRenderMode {
-  return value === "list" ? "list" : "tree";
+  return value === "list" || value === "status" ? value : "tree";
+}
+
+function resolvePathPatterns(value: unknown): string[] {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+
+  return value
+    .filter((entry): entry is string => typeof entry === "string")
+    .map(normalizeProtectedPathPattern)
+    .filter((entry) => entry.length > 0);
+}
+
+function resolveDefaultReviewRanges(value: unknown): Record<string, string> {
+  if (!isObject(value)) {
+    return {};
+  }
+
+  const ranges: Record<string, string> = {};
+  for (const [repoPath, range] of Object.entries(value)) {
+    if (typeof range === "string" && range.trim()) {
+      ranges[repoPath] = range.trim();
+    }
+  }
+
+  return ranges;
+}
+
+function resolveDateFormat(value: unknown) {
+  if (typeof value !== "string" || !isValidDateFormat(value)) {
+    return "";
+  }
+
+  return value;
+}
+
+function resolvePositiveInteger(value: unknown, fallback: number): number {
+  if (typeof value !== "number" || !Number.isFinite(value) || value <= 0) {
+    return fallback;
+  }
+
+  return Math.floor(value);
+}
+
+function resolveDiffViewSettings(value: Record<string, unknown>): DiffViewSettings {
+  return {
+    maxHighlightBytes: resolvePositiveInteger(value.maxHighlightBytes, DEFAULT_MAX_HIGHLIGHT_BYTES),
+    // 0 leaves the line-length lint off.
+    maxLineLength: resolvePositiveInteger(value.maxLineLength, 0),
+    // An explicit empty list turns collapsing off; only a missing list falls back to the defaults.
+    collapsedPaths: Array.isArray(value.collapsedPaths)
+      ? resolvePathPatterns(value.collapsedPaths)
+      : [...DEFAULT_COLLAPSED_PATHS],
+  };
 }
 
 function resolveLspServerCommand(value: unknown) {
@@ -85,6 +160,7 @@ export function createAppSettings(settings?: unknown): AppSettings {
   }
 
   const sourceControl = isObject(settings.sourceControl) ? settings.sourceControl : {};
+  const diffView = isObject(settings.diffView) ? settings.diffView : {};
   const lsp = isObject(settings.lsp) ? settings.lsp : {};
   const servers = resolveLspServerSettings(lsp.servers);
 
@@ -92,7 +168,16 @@ export function createAppSettings(settings?: unknown): AppSettings {
     version: 1,
     sourceControl: {
       fileTreeRenderMode: resolveFileTreeRenderMode(sourceControl.fileTreeRenderMode),
+      protectedPaths: resolvePathPatterns(sourceControl.protectedPaths),
+      stageCommentedFilesOnCopy: sourceControl.stageCommentedFilesOnCopy === true,
+      historyPageSize: resolvePositiveInteger(
+        sourceControl.historyPageSize,
+        DEFAULT_HISTORY_PAGE_SIZE,
+      ),
+      defaultReviewRanges: resolveDefaultReviewRanges(sourceControl.defaultReviewRanges),
+      historyDateFormat: resolveDateFormat(sourceControl.historyDateFormat),
     },
+    diffView: resolveDiffViewSettings(diffView),
     lsp: {
       servers,
     },
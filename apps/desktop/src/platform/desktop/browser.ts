@@ -110,6 +110,8 @@ function browserUnsupportedFeature(method: DesktopApiMethod): string {
   switch (method) {
     case "openPath":
       return "Opening local paths";
+    case "revealInFileManager":
+      return "Revealing files in the file manager";
     case "listProviderConnections":
     case "connectProvider":
     case "disconnectProvider":
@@ -140,12 +142,51 @@ function browserUnsupportedFeature(method: DesktopApiMethod): string {
       return "Pull request review workspaces";
     case "getGitSnapshot":
       return "Git snapshot loading";
+    case "getWorkingCopySummary":
+      return "Working copy summary";
+    case "getChangedFileHashes":
+      return "Changed file tracking";
     case "getRepoFiles":
       return "Repository file listing";
+    case "isPathIgnored":
+      return "Ignored path lookup";
+    case "getRecentlyModifiedFiles":
+      return "Recent file lookup";
     case "getCommitHistory":
       return "Commit history loading";
+    case "getCommitStats":
+      return "Commit stats loading";
+    case "getParentSummary":
+      return "Parent commit lookup";
+    case "getReflog":
+      return "Reflog loading";
+    case "formatCommitPatch":
+      return "Commit patch export";
+    case "blameFile":
+      return "Line blame";
+    case "getWorkingTreeDiffForPaths":
+      return "Selected file diffs";
+    case "diffPathsBetweenRefs":
+      return "Cross-path diffs";
+    case "resolveRefInfo":
+      return "Ref lookup";
+    case "resolveStash":
+    case "findStashByMessage":
+      return "Stash lookup";
+    case "applyStash":
+      return "Applying stashes";
+    case "listStashes":
+      return "Listing stashes";
+    case "pushStash":
+      return "Stashing changes";
+    case "popStash":
+      return "Popping stashes";
+    case "addGitignorePattern":
+      return "Editing .gitignore";
     case "getBranches":
       return "Branch listing";
+    case "getDefaultBranch":
+      return "Default branch lookup";
     case "getBranchFiles":
       return "Branch file listing";
     case "getCommitFiles":
@@ -164,13 +205,30 @@ function browserUnsupportedFeature(method: DesktopApiMethod): string {
       return "Staging all files";
     case "unstageAll":
       return "Unstaging all files";
+    case "stageDirectory":
+      return "Staging folders";
+    case "unstageDirectory":
+      return "Unstaging folders";
     case "discardFile":
     case "discardFiles":
       return "Discarding file changes";
     case "discardAll":
       return "Discarding all changes";
+    case "discardUnstaged":
+      return "Discarding unstaged changes";
+    case "previewStageAll":
+    case "previewUnstageAll":
+      return "Staging previews";
+    case "checkoutFileFromRef":
+      return "Restoring files from a ref";
     case "commitStaged":
       return "Creating commits";
+    case "rewordHead":
+      return "Rewording commits";
+    case "amendHead":
+      return "Amending commits";
+    case "launchDifftool":
+      return "External diff tools";
     default:
       return "Desktop runtime";
   }
@@ -197,6 +255,9 @@ const browserDesktopApiCore = createDesktopApiWithDefaults({
     async getAppSettingsPath() {
       return APP_SETTINGS_STORAGE_KEY;
     },
+    async getLaunchBucket() {
+      return "unstaged";
+    },
     async confirm(message: string, _options?: ConfirmOptions) {
       return window.confirm(message);
     },
@@ -295,6 +356,9 @@ const unavailableDesktopApiCore = createDesktopApiWithDefaults({
     async saveAppSettings(settings: AppSettings) {
       return createAppSettings(settings);
     },
+    async getLaunchBucket() {
+      return "unstaged";
+    },
     async listProviderConnections() {
       return [];
     },
@@ -23,19 +23,43 @@ export type DiffFile = {
   contents: string;
 };
 
+export type RepoFile = DiffFile & {
+  // Set when the file was larger than the requested `maxBytes` and only its start was read.
+  truncated?: boolean;
+};
+
 export type FileItem = {
   path: string;
   previousPath: string | null;
   status: FileStatus;
+  /** Rename/copy similarity percentage (0-100) reported by git. */
+  similarity?: number | null;
+};
+
+export type RepoFilesOptions = {
+  includeIgnored?: boolean;
+};
+
+export type IndexPreviewAction = "add" | "remove" | "unstage";
+
+export type IndexPreviewEntry = {
+  path: string;
+  action: IndexPreviewAction;
 };
 
 export type RepoFileItem = {
   path: string;
+  outsideSparseCone?: boolean;
+  ignored?: boolean;
 };
 
 export type FileVersions = {
   oldFile: DiffFile | null;
   newFile: DiffFile | null;
+  /** Git file modes of each side, such as `100755` or `120000`; null when the side is absent. */
+  oldMode?: string | null;
+  newMode?: string | null;
+  outsideSparseCone?: boolean;
 };
 
 export type GitSnapshot = {
@@ -44,6 +68,17 @@ export type GitSnapshot = {
   unstaged: FileItem[];
   staged: FileItem[];
   untracked: FileItem[];
+  /** Submodule roots declared in .gitmodules; git reports each as a single gitlink path. */
+  submodulePaths?: string[];
+};
+
+export type WorkingCopySummary = {
+  // null while HEAD is detached.
+  branch: string | null;
+  upstream: string | null;
+  ahead: number;
+  behind: number;
+  dirtyCount: number;
 };
 
 export type HistoryCommit = {
@@ -52,6 +87,52 @@ export type HistoryCommit = {
   summary: string;
   author: string;
   relativeTime: string;
+  // Author date in seconds since the epoch.
+  timestamp?: number;
+  parentIds?: string[];
+};
+
+export type ReflogEntry = {
+  selector: string;
+  oldId: string | null;
+  newId: string;
+  committer: string;
+  message: string;
+  relativeTime: string;
+};
+
+export type RefResolution = {
+  commitId: string;
+  shortId: string;
+  summary: string;
+  author: string;
+};
+
+/** A stash index (`stash@{n}`) or a substring of its message. */
+export type StashSelector = number | string;
+
+export type StashEntry = {
+  index: number;
+  oid: string;
+  message: string;
+  author: string;
+  relativeTime: string;
+};
+
+export type StashTarget = StashEntry & {
+  /** The commit the stash was taken on top of; diffing from it shows the stashed changes. */
+  baseId: string;
+};
+
+export type PushStashOptions = {
+  message?: string;
+  includeUntracked?: boolean;
+};
+
+export type CommitStats = {
+  insertions: number;
+  deletions: number;
+  files: number;
 };
 
 export type DiscardFileInput = {
@@ -291,7 +372,7 @@ export type WorkspaceSession = {
   recentRepos: string[];
 };
 
-export type FileTreeRenderMode = "tree" | "list";
+export type FileTreeRenderMode = "tree" | "list" | "status";
 
 export type LspServerSettings = {
   command: string;
@@ -303,11 +384,26 @@ export type LspSettings = {
   servers: Record<string, LspServerSettings>;
 };
 
+export type DiffViewSettings = {
+  maxHighlightBytes: number;
+  maxLineLength: number;
+  /** Globs for files whose diffs start collapsed, such as lockfiles. */
+  collapsedPaths: string[];
+};
+
 export type AppSettings = {
   version: 1;
   sourceControl: {
     fileTreeRenderMode: FileTreeRenderMode;
+    protectedPaths: string[];
+    stageCommentedFilesOnCopy: boolean;
+    historyPageSize: number;
+    // Keyed by repo path; each value is a `base..head` range that seeds the Review refs.
+    defaultReviewRanges: Record<string, string>;
+    // strftime-style format for commit dates; empty shows relative times.
+    historyDateFormat: string;
   };
+  diffView: DiffViewSettings;
   lsp: LspSettings;
 };
 
@@ -337,6 +433,7 @@ export type GetRepoFileInput = {
   repoPath: string;
   relPath: string;
   revision?: string | null;
+  maxBytes?: number;
 };
 
 export type LspDiagnosticSeverity = "error" | "warning" | "information" | "hint";
@@ -381,9 +478,11 @@ export type DesktopApi = {
   loadAppSettings(): Promise<AppSettings>;
   saveAppSettings(settings: AppSettings): Promise<AppSettings>;
   getAppSettingsPath(): Promise<string>;
+  getLaunchBucket(): Promise<Bucket>;
   confirm(message: string, options?: ConfirmOptions): Promise<boolean>;
   checkAppExists(appName: string): Promise<boolean>;
   openPath(path: string, appName?: string | null): Promise<void>;
+  revealInFileManager(repoPath: string, filePath?: string | null): Promise<void>;
   listProviderConnections(): Promise<ProviderConnection[]>;
   connectProvider(input: ConnectProviderInput): Promise<ProviderConnection>;
   disconnectProvider(providerId: GitProviderId): Promise<void>;
@@ -408,16 +507,47 @@ export type DesktopApi = {
     input: PreparePullRequestWorkspaceInput,
   ): Promise<PreparedPullRequestWorkspace>;
   getGitSnapshot(repoPath: string): Promise<GitSnapshot>;
-  getRepoFiles(repoPath: string): Promise<RepoFileItem[]>;
-  getCommitHistory(repoPath: string, limit?: number): Promise<HistoryCommit[]>;
+  getWorkingCopySummary(repoPath: string): Promise<WorkingCopySummary>;
+  getChangedFileHashes(repoPath: string): Promise<Record<string, string>>;
+  getRepoFiles(repoPath: string, options?: RepoFilesOptions): Promise<RepoFileItem[]>;
+  isPathIgnored(repoPath: string, relPath: string): Promise<boolean>;
+  getRecentlyModifiedFiles(repoPath: string, limit?: number): Promise<string[]>;
+  getCommitHistory(repoPath: string, limit?: number, skip?: number): Promise<HistoryCommit[]>;
+  getCommitStats(repoPath: string, commitId: string): Promise<CommitStats>;
+  getParentSummary(repoPath: string, commitId: string): Promise<string | null>;
+  getReflog(repoPath: string, refName?: string, limit?: number): Promise<ReflogEntry[]>;
+  formatCommitPatch(repoPath: string, commitId: string): Promise<string>;
+  blameFile(repoPath: string, relPath: string, revision?: string): Promise<string[]>;
+  getWorkingTreeDiffForPaths(
+    repoPath: string,
+    staged: boolean,
+    relPaths: string[],
+  ): Promise<string>;
+  resolveRefInfo(repoPath: string, ref: string): Promise<RefResolution>;
+  resolveStash(repoPath: string, stash: StashSelector): Promise<StashTarget>;
+  findStashByMessage(repoPath: string, needle: string): Promise<number | null>;
+  applyStash(repoPath: string, stash: StashSelector): Promise<void>;
+  listStashes(repoPath: string): Promise<StashEntry[]>;
+  pushStash(repoPath: string, options?: PushStashOptions): Promise<void>;
+  popStash(repoPath: string, stash: StashSelector): Promise<void>;
+  addGitignorePattern(repoPath: string, pattern: string): Promise<boolean>;
+  diffPathsBetweenRefs(
+    repoPath: string,
+    oldRef: string,
+    oldPath: string,
+    newRef: string,
+    newPath: string,
+  ): Promise<string>;
   getBranches(repoPath: string): Promise<string[]>;
+  getDefaultBranch(repoPath: string): Promise<string | null>;
   getBranchFiles(repoPath: string, baseRef: string, headRef: string): Promise<FileItem[]>;
-  getCommitFiles(repoPath: string, commitId: string): Promise<FileItem[]>;
+  getCommitFiles(repoPath: string, commitId: string, parentIndex?: number): Promise<FileItem[]>;
   getCommitFileVersions(
     repoPath: string,
     commitId: string,
     relPath: string,
     previousPath?: string,
+    parentIndex?: number,
   ): Promise<FileVersions>;
   getFileVersions(repoPath: string, relPath: string, bucket: Bucket): Promise<FileVersions>;
   getBranchFileVersions(
@@ -433,11 +563,25 @@ export type DesktopApi = {
   updateWorktreeFileContents(repoPath: string, relPath: string, contents: string): Promise<void>;
   stageAll(repoPath: string): Promise<void>;
   unstageAll(repoPath: string): Promise<void>;
+  stageDirectory(repoPath: string, dirRelPath: string): Promise<void>;
+  unstageDirectory(repoPath: string, dirRelPath: string): Promise<void>;
   discardFile(repoPath: string, relPath: string, bucket: Bucket): Promise<void>;
   discardFiles(repoPath: string, files: DiscardFileInput[]): Promise<void>;
   discardAll(repoPath: string): Promise<void>;
+  discardUnstaged(repoPath: string): Promise<void>;
+  previewStageAll(repoPath: string): Promise<IndexPreviewEntry[]>;
+  previewUnstageAll(repoPath: string): Promise<IndexPreviewEntry[]>;
+  checkoutFileFromRef(
+    repoPath: string,
+    relPath: string,
+    ref: string,
+    stage?: boolean,
+  ): Promise<void>;
   commitStaged(repoPath: string, message: string): Promise<string>;
-  getRepoFile(input: GetRepoFileInput): Promise<DiffFile | null>;
+  rewordHead(repoPath: string, message: string): Promise<string>;
+  amendHead(repoPath: string, message?: string): Promise<string>;
+  launchDifftool(repoPath: string, relPath: string, bucket: Bucket): Promise<void>;
+  getRepoFile(input: GetRepoFileInput): Promise<RepoFile | null>;
   syncLspDocument(input: SyncLspDocumentInput): Promise<void>;
   closeLspDocument(input: CloseLspDocumentInput): Promise<void>;
   getLspHover(input: GetLspHoverInput): Promise<LspHoverResult | null>;
@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+
+import { formatTimestamp, isValidDateFormat } from "@/platform/desktop/dateFormat";
+
+// Built from local fields so the expectations hold in any time zone.
+const timestamp = new Date(2024, 2, 5, 14, 7, 9).getTime() / 1000;
+
+describe("formatTimestamp", () => {
+  it("renders the same timestamp with different format strings", () => {
+    expect(formatTimestamp(timestamp, "%Y-%m-%d %H:%M")).toBe("2024-03-05 14:07");
+    expect(formatTimestamp(timestamp, "%e %b %y, %I:%M:%S %p")).toBe("5 Mar 24, 02:07:09 PM");
+  });
+});
+
+describe("isValidDateFormat", () => {
+  it("rejects unknown or dangling directives", () => {
+    expect(isValidDateFormat("%d/%m/%Y 100%%")).toBe(true);
+    expect(isValidDateFormat("%Q")).toBe(false);
+    expect(isValidDateFormat("%Y-%")).toBe(false);
+  });
+});
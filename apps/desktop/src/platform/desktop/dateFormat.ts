@@ -0,0 +1,59 @@
+const MONTH_NAMES = [
+  "Jan",
+  "Feb",
+  "Mar",
+  "Apr",
+  "May",
+  "Jun",
+  "Jul",
+  "Aug",
+  "Sep",
+  "Oct",
+  "Nov",
+  "Dec",
+];
+const WEEKDAY_NAMES = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+function pad(value: number, width = 2) {
+  return String(value).padStart(width, "0");
+}
+
+// A strftime subset; every directive renders in local time.
+const DATE_FORMAT_DIRECTIVES: Record<string, (date: Date) => string> = {
+  Y: (date) => String(date.getFullYear()),
+  y: (date) => pad(date.getFullYear() % 100),
+  m: (date) => pad(date.getMonth() + 1),
+  d: (date) => pad(date.getDate()),
+  e: (date) => String(date.getDate()),
+  H: (date) => pad(date.getHours()),
+  I: (date) => pad(date.getHours() % 12 || 12),
+  M: (date) => pad(date.getMinutes()),
+  S: (date) => pad(date.getSeconds()),
+  p: (date) => (date.getHours() < 12 ? "AM" : "PM"),
+  b: (date) => MONTH_NAMES[date.getMonth()]!,
+  a: (date) => WEEKDAY_NAMES[date.getDay()]!,
+  "%": () => "%",
+};
+
+export function isValidDateFormat(format: string) {
+  for (let index = 0; index < format.length; index += 1) {
+    if (format[index] !== "%") continue;
+
+    const directive = format[index + 1];
+    if (directive === undefined || !(directive in DATE_FORMAT_DIRECTIVES)) {
+      return false;
+    }
+    index += 1;
+  }
+
+  return true;
+}
+
+// `timestamp` is in seconds since the epoch, matching git's `%at`.
+export function formatTimestamp(timestamp: number, format: string) {
+  const date = new Date(timestamp * 1000);
+  return format.replace(/%(.)/g, (match, directive: string) => {
+    const render = DATE_FORMAT_DIRECTIVES[directive];
+    return render ? render(date) : match;
+  });
+}
@@ -9,9 +9,11 @@ export const DESKTOP_API_METHODS = [
   "loadAppSettings",
   "saveAppSettings",
   "getAppSettingsPath",
+  "getLaunchBucket",
   "confirm",
   "checkAppExists",
   "openPath",
+  "revealInFileManager",
   "listProviderConnections",
   "connectProvider",
   "disconnectProvider",
@@ -28,9 +30,29 @@ export const DESKTOP_API_METHODS = [
   "preparePullRequestCompareRefs",
   "preparePullRequestWorkspace",
   "getGitSnapshot",
+  "getWorkingCopySummary",
+  "getChangedFileHashes",
   "getRepoFiles",
+  "isPathIgnored",
+  "getRecentlyModifiedFiles",
   "getCommitHistory",
+  "getCommitStats",
+  "getParentSummary",
+  "getReflog",
+  "formatCommitPatch",
+  "blameFile",
+  "getWorkingTreeDiffForPaths",
+  "diffPathsBetweenRefs",
+  "resolveRefInfo",
+  "resolveStash",
+  "findStashByMessage",
+  "applyStash",
+  "listStashes",
+  "pushStash",
+  "popStash",
+  "addGitignorePattern",
   "getBranches",
+  "getDefaultBranch",
   "getBranchFiles",
   "getCommitFiles",
   "getCommitFileVersions",
@@ -42,10 +64,19 @@ export const DESKTOP_API_METHODS = [
   "updateWorktreeFileContents",
   "stageAll",
   "unstageAll",
+  "stageDirectory",
+  "unstageDirectory",
   "discardFile",
   "discardFiles",
   "discardAll",
+  "discardUnstaged",
+  "previewStageAll",
+  "previewUnstageAll",
+  "checkoutFileFromRef",
   "commitStaged",
+  "rewordHead",
+  "amendHead",
+  "launchDifftool",
   "getRepoFile",
   "syncLspDocument",
   "closeLspDocument",
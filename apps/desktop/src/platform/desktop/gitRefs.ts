@@ -0,0 +1,11 @@
+// `:` is not allowed in git ref names, so this can never collide with a real branch or tag.
+export const WORKTREE_REF = ":worktree";
+
+export function isWorktreeRef(ref: string) {
+  return ref === WORKTREE_REF;
+}
+
+// Full object ids let review compare arbitrary commits, such as two ends of a reflog entry.
+export function isCommitIdRef(ref: string) {
+  return /^[0-9a-f]{40}$/.test(ref);
+}
@@ -66,11 +66,23 @@ export type {
   FileStatus,
   FileVersions,
   GitProviderId,
+  RepoFile,
   RepoFileItem,
+  RepoFilesOptions,
   GetRepoFileInput,
   GitSnapshot,
+  WorkingCopySummary,
   HostedRepoRef,
   HistoryCommit,
+  CommitStats,
+  RefResolution,
+  ReflogEntry,
+  StashSelector,
+  StashTarget,
+  StashEntry,
+  PushStashOptions,
+  IndexPreviewAction,
+  IndexPreviewEntry,
   LspDiagnostic,
   LspDiagnosticSeverity,
   LspDiagnosticsEvent,
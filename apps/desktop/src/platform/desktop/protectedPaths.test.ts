@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+
+import { isProtectedPath, protectedPathExcludes } from "@/platform/desktop/protectedPaths";
+
+describe("protected paths", () => {
+  it("matches directories and glob patterns the way git pathspecs do", () => {
+    expect(isProtectedPath("dist/app.js", ["dist"])).toBe(true);
+    expect(isProtectedPath("distance.ts", ["dist"])).toBe(false);
+    expect(isProtectedPath("packages/ui/dist/index.js", ["packages/*/dist"])).toBe(true);
+    expect(isProtectedPath("packages/ui/src/dist/index.js", ["packages/*/dist"])).toBe(false);
+    expect(isProtectedPath("a/b/generated/schema.ts", ["**/generated"])).toBe(true);
+    expect(isProtectedPath("nested/pnpm-lock.yaml", ["*.yaml"])).toBe(false);
+  });
+
+  it("turns patterns into exclude pathspecs", () => {
+    expect(protectedPathExcludes(["./dist/", "  ", "**/generated"])).toEqual([
+      ":(exclude,glob)dist",
+      ":(exclude,glob)**/generated",
+    ]);
+  });
+});
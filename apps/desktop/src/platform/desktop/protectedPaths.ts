@@ -0,0 +1,57 @@
+// Protected paths use git's `:(glob)` pathspec rules so the renderer hides exactly what the backend
+// refuses: `*` and `?` stay inside one path segment, `**` spans directories, and a pattern that
+// names a directory covers everything below it.
+function globToRegExp(pattern: string) {
+  let source = "";
+
+  for (let index = 0; index < pattern.length; index += 1) {
+    const char = pattern[index]!;
+
+    const atSegmentStart = index === 0 || pattern[index - 1] === "/";
+    if (char === "*" && pattern[index + 1] === "*" && atSegmentStart) {
+      if (pattern[index + 2] === "/") {
+        source += "(?:.*/)?";
+        index += 2;
+        continue;
+      }
+      if (index + 2 === pattern.length) {
+        source += ".*";
+        index += 1;
+        continue;
+      }
+    }
+
+    if (char === "*") {
+      source += "[^/]*";
+    } else if (char === "?") {
+      source += "[^/]";
+    } else {
+      source += char.replace(/[.+^${}()|[\]\\]/g, "\\$&");
+    }
+  }
+
+  return new RegExp(`^${source}(?:/.*)?$`);
+}
+
+export function normalizeProtectedPathPattern(pattern: string) {
+  return pattern.trim().replace(/^\.?\/+/, "").replace(/\/+$/, "");
+}
+
+export function matchesPathPatterns(relPath: string, patterns: readonly string[]) {
+  const normalizedPath = relPath.replace(/\\/g, "/");
+  return patterns.some((pattern) => {
+    const normalizedPattern = normalizeProtectedPathPattern(pattern);
+    return normalizedPattern.length > 0 && globToRegExp(normalizedPattern).test(normalizedPath);
+  });
+}
+
+export function isProtectedPath(relPath: string, patterns: readonly string[]) {
+  return matchesPathPatterns(relPath, patterns);
+}
+
+export function protectedPathExcludes(patterns: readonly string[]) {
+  return patterns
+    .map(normalizeProtectedPathPattern)
+    .filter((pattern) => pattern.length > 0)
+    .map((pattern) => `:(exclude,glob)${pattern}`);
+}